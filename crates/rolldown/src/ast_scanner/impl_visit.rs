@@ -12,7 +12,9 @@ use rolldown_common::{
   EcmaModuleAstUsage, ImportKind, ImportRecordMeta, StmtInfoMeta, ThisExprReplaceKind,
 };
 use rolldown_ecmascript::ToSourceString;
+use rolldown_ecmascript_utils::ExpressionExt;
 use rolldown_error::BuildDiagnostic;
+use rolldown_rstr::Rstr;
 use rolldown_std_utils::OptionExt;
 
 use super::{side_effect_detector::SideEffectDetector, AstScanner};
@@ -39,6 +41,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_program(&mut self, program: &ast::Program<'ast>) {
+    self.scan_no_side_effects_annotations(program);
     for (idx, stmt) in program.body.iter().enumerate() {
       self.current_stmt_info.stmt_idx = Some(idx);
       self.current_stmt_info.side_effect = SideEffectDetector::new(
@@ -60,6 +63,12 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
       self.result.stmt_infos.add_stmt_info(std::mem::take(&mut self.current_stmt_info));
     }
     self.result.hashbang_range = program.hashbang.as_ref().map(GetSpan::span);
+    for directive in &program.directives {
+      let directive = Rstr::from(directive.directive.as_str());
+      if !self.result.directives.contains(&directive) {
+        self.result.directives.push(directive);
+      }
+    }
     self.result.dynamic_import_rec_exports_usage =
       std::mem::take(&mut self.dynamic_import_usage_info.dynamic_import_exports_usage);
     if self.result.has_eval {
@@ -95,6 +104,9 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_for_of_statement(&mut self, it: &ast::ForOfStatement<'ast>) {
+    if it.r#await && self.is_top_level() {
+      self.ast_usage.insert(EcmaModuleAstUsage::TopLevelAwait);
+    }
     if it.r#await && self.is_top_level() && !self.options.format.keep_esm_import_export_syntax() {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.id.resource_id().clone(),
@@ -111,6 +123,9 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_await_expression(&mut self, it: &ast::AwaitExpression<'ast>) {
+    if self.is_top_level() {
+      self.ast_usage.insert(EcmaModuleAstUsage::TopLevelAwait);
+    }
     if !self.options.format.keep_esm_import_export_syntax() && self.is_top_level() {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.id.resource_id().clone(),
@@ -150,6 +165,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
           ImportRecordMeta::empty()
         },
       );
+      self.scan_import_expression_attributes(import_rec_idx, expr);
       self.init_dynamic_import_binding_usage_info(import_rec_idx);
       self.result.imports.insert(expr.span, import_rec_idx);
     }
@@ -196,6 +212,20 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
     walk::walk_new_expression(self, it);
   }
 
+  fn visit_static_member_expression(&mut self, it: &ast::StaticMemberExpression<'ast>) {
+    if it.object.is_import_meta() {
+      self.result.import_meta_references.push((it.span, Some(it.property.name.as_str().into())));
+      return;
+    }
+    walk::walk_static_member_expression(self, it);
+  }
+
+  fn visit_meta_property(&mut self, it: &ast::MetaProperty<'ast>) {
+    if it.meta.name == "import" && it.property.name == "meta" {
+      self.result.import_meta_references.push((it.span, None));
+    }
+  }
+
   fn visit_this_expression(&mut self, it: &ast::ThisExpression) {
     if !self.is_this_nested() {
       self.top_level_this_expr_set.insert(it.span);
@@ -319,6 +349,9 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
             // TODO: esbuild track has_eval for each scope, this could reduce bailout range, and may
             // improve treeshaking performance. https://github.com/evanw/esbuild/blob/360d47230813e67d0312ad754cad2b6ee09b151b/internal/js_ast/js_ast.go#L1288-L1291
             self.result.has_eval = true;
+            if let Some(scope_id) = self.scope_stack.iter().rev().find_map(|scope| *scope) {
+              self.result.direct_eval_scopes.insert(scope_id);
+            }
             self.result.warnings.push(
               BuildDiagnostic::eval(self.id.to_string(), self.source.clone(), ident_ref.span)
                 .with_severity_warning(),
@@ -0,0 +1,64 @@
+use oxc::ast::ast::{Expression, ImportExpression, ObjectPropertyKind, WithClause};
+use rolldown_common::{ImportRecordIdx, ModuleType};
+
+use super::AstScanner;
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Handle `with { type: '...' }` of `import`/`export ... from` declarations
+  pub fn scan_import_attributes(
+    &mut self,
+    rec_idx: ImportRecordIdx,
+    with_clause: Option<&WithClause>,
+  ) {
+    let Some(with_clause) = with_clause else { return };
+    let ty = with_clause
+      .with_entries
+      .iter()
+      .find(|attribute| attribute.key.as_atom() == "type")
+      .map(|attribute| attribute.value.value.as_str());
+    if let Some(ty) = ty {
+      self.set_import_attribute_type(rec_idx, ty);
+    }
+  }
+
+  /// Handle `import('...', { with: { type: '...' } })`
+  pub fn scan_import_expression_attributes(
+    &mut self,
+    rec_idx: ImportRecordIdx,
+    expr: &ImportExpression<'ast>,
+  ) {
+    let Some(Expression::ObjectExpression(options)) = expr.arguments.first() else { return };
+    let ty = options
+      .properties
+      .iter()
+      .filter_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop) => Some(prop),
+        ObjectPropertyKind::SpreadProperty(_) => None,
+      })
+      .find(|prop| prop.key.is_specific_static_name("with"))
+      .and_then(|prop| match &prop.value {
+        Expression::ObjectExpression(attributes) => Some(attributes),
+        _ => None,
+      })
+      .and_then(|attributes| {
+        attributes.properties.iter().find_map(|prop| match prop {
+          ObjectPropertyKind::ObjectProperty(prop) if prop.key.is_specific_static_name("type") => {
+            match &prop.value {
+              Expression::StringLiteral(ty) => Some(ty.value.as_str()),
+              _ => None,
+            }
+          }
+          _ => None,
+        })
+      });
+    if let Some(ty) = ty {
+      self.set_import_attribute_type(rec_idx, ty);
+    }
+  }
+
+  fn set_import_attribute_type(&mut self, rec_idx: ImportRecordIdx, ty: &str) {
+    let rec = &mut self.result.import_records[rec_idx];
+    rec.import_attribute_type = Some(ty.into());
+    rec.asserted_module_type = ModuleType::from_import_attribute_type(ty);
+  }
+}
@@ -1,7 +1,9 @@
 pub mod dynamic_import;
 pub mod impl_visit;
 mod import_assign_analyzer;
+mod import_attributes;
 mod new_url;
+mod no_side_effects_annotation;
 pub mod side_effect_detector;
 
 use arcstr::ArcStr;
@@ -55,6 +57,8 @@ pub struct ScanResult {
   pub warnings: Vec<BuildDiagnostic>,
   pub errors: Vec<BuildDiagnostic>,
   pub has_eval: bool,
+  /// Scopes that call `eval` directly.
+  pub direct_eval_scopes: FxHashSet<ScopeId>,
   pub ast_usage: EcmaModuleAstUsage,
   pub symbol_ref_db: SymbolRefDbForModule,
   /// https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/js_parser/js_parser_lower_class.go#L2277-L2283
@@ -66,12 +70,16 @@ pub struct ScanResult {
   /// level rather than module level, or a syntax error will be raised if there are multi modules
   /// has hashbang. Storing the span of hashbang used for hashbang codegen in chunk level
   pub hashbang_range: Option<Span>,
+  /// Like hashbang, directives only work at the beginning of a file, so they are generated in chunk level.
+  pub directives: Vec<Rstr>,
   pub has_star_exports: bool,
   /// we don't know the ImportRecord related ModuleIdx yet, so use ImportRecordIdx as key
   /// temporarily
   pub dynamic_import_rec_exports_usage: FxHashMap<ImportRecordIdx, DynamicImportExportsUsage>,
   /// `new URL('...', import.meta.url)`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
+  /// `import.meta.xxx` -> `Some("xxx")`, bare `import.meta` -> `None`
+  pub import_meta_references: Vec<(Span, Option<Rstr>)>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
 }
 
@@ -146,14 +154,17 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       exports_kind: ExportsKind::None,
       warnings: Vec::new(),
       has_eval: false,
+      direct_eval_scopes: FxHashSet::default(),
       errors: Vec::new(),
       ast_usage: EcmaModuleAstUsage::empty(),
       symbol_ref_db,
       self_referenced_class_decl_symbol_ids: FxHashSet::default(),
       hashbang_range: None,
+      directives: vec![],
       has_star_exports: false,
       dynamic_import_rec_exports_usage: FxHashMap::default(),
       new_url_references: FxHashMap::default(),
+      import_meta_references: vec![],
       this_expr_replace_map: FxHashMap::default(),
     };
 
@@ -472,6 +483,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.scan_import_attributes(id, decl.with_clause.as_deref());
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
       self.add_star_re_export(exported.name().as_str(), id, decl.span);
@@ -495,6 +507,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
           ImportRecordMeta::empty()
         },
       );
+      self.scan_import_attributes(record_id, decl.with_clause.as_deref());
       decl.specifiers.iter().for_each(|spec| {
         self.add_re_export(
           spec.exported.name().as_str(),
@@ -587,6 +600,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.scan_import_attributes(rec_id, decl.with_clause.as_deref());
     self.result.imports.insert(decl.span, rec_id);
     // // `import '...'` or `import {} from '...'`
     if decl.specifiers.as_ref().map_or(true, |s| s.is_empty()) {
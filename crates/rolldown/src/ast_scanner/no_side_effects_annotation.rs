@@ -0,0 +1,107 @@
+use oxc::{
+  ast::ast::{
+    BindingIdentifier, BindingPatternKind, Declaration, ExportDefaultDeclarationKind, Expression,
+    Program, Statement, VariableDeclaration,
+  },
+  semantic::SymbolId,
+  span::GetSpan,
+};
+use rolldown_common::{SymbolRef, SymbolRefFlags};
+
+use super::{side_effect_detector::SideEffectDetector, AstScanner};
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Mark top-level functions annotated with `@__NO_SIDE_EFFECTS__` before the statements are analyzed, so
+  /// calls to them are side effect free even if they come before the declaration.
+  pub fn scan_no_side_effects_annotations(&mut self, program: &Program<'ast>) {
+    if !self.options.treeshake.annotations() {
+      return;
+    }
+    let detector = SideEffectDetector::new(
+      self.scopes,
+      self.source,
+      self.comments,
+      false,
+      self.options.jsx.is_jsx_preserve(),
+      &self.result.symbol_ref_db,
+    );
+    let mut annotated = vec![];
+    for stmt in &program.body {
+      let is_stmt_annotated = detector.has_no_side_effects_annotation(stmt.span());
+      match stmt {
+        Statement::FunctionDeclaration(func) => {
+          if is_stmt_annotated {
+            annotated.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+          }
+        }
+        Statement::VariableDeclaration(decl) => {
+          collect_annotated_declarators(&detector, decl, is_stmt_annotated, &mut annotated);
+        }
+        Statement::ExportNamedDeclaration(export_decl) => match &export_decl.declaration {
+          Some(Declaration::FunctionDeclaration(func)) => {
+            if is_stmt_annotated || detector.has_no_side_effects_annotation(func.span) {
+              annotated.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+            }
+          }
+          Some(Declaration::VariableDeclaration(decl)) => {
+            let is_annotated =
+              is_stmt_annotated || detector.has_no_side_effects_annotation(decl.span);
+            collect_annotated_declarators(&detector, decl, is_annotated, &mut annotated);
+          }
+          _ => {}
+        },
+        Statement::ExportDefaultDeclaration(export_decl) => {
+          let is_annotated = is_stmt_annotated
+            || detector.has_no_side_effects_annotation(export_decl.declaration.span());
+          if !is_annotated {
+            continue;
+          }
+          match &export_decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+              annotated.push(
+                func
+                  .id
+                  .as_ref()
+                  .map_or(self.result.default_export_ref.symbol, BindingIdentifier::symbol_id),
+              );
+            }
+            ExportDefaultDeclarationKind::ArrowFunctionExpression(_) => {
+              annotated.push(self.result.default_export_ref.symbol);
+            }
+            _ => {}
+          }
+        }
+        _ => {}
+      }
+    }
+
+    for symbol_id in annotated {
+      let symbol_ref: SymbolRef = (self.idx, symbol_id).into();
+      symbol_ref
+        .flags_mut(&mut self.result.symbol_ref_db)
+        .insert(SymbolRefFlags::SIDE_EFFECT_FREE_FUNCTION);
+    }
+  }
+}
+
+/// `/* @__NO_SIDE_EFFECTS__ */ const a = () => {}` or `const a = /* @__NO_SIDE_EFFECTS__ */ () => {}`
+fn collect_annotated_declarators(
+  detector: &SideEffectDetector,
+  decl: &VariableDeclaration,
+  is_decl_annotated: bool,
+  annotated: &mut Vec<SymbolId>,
+) {
+  for declarator in &decl.declarations {
+    let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+      (&declarator.id.kind, &declarator.init)
+    else {
+      continue;
+    };
+    if !matches!(init, Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)) {
+      continue;
+    }
+    if is_decl_annotated || detector.has_no_side_effects_annotation(init.span()) {
+      annotated.push(id.symbol_id());
+    }
+  }
+}
@@ -30,3 +30,24 @@ impl SideEffectDetector<'_> {
     })
   }
 }
+
+static NO_SIDE_EFFECTS_COMMENTS: LazyLock<DoubleArrayAhoCorasick<usize>> = LazyLock::new(|| {
+  let patterns = vec!["@__NO_SIDE_EFFECTS__", "#__NO_SIDE_EFFECTS__"];
+
+  DoubleArrayAhoCorasick::new(patterns).unwrap()
+});
+
+impl SideEffectDetector<'_> {
+  /// Comments containing @__NO_SIDE_EFFECTS__ or #__NO_SIDE_EFFECTS__ mark a function declaration or
+  /// a variable declaration of a function as side effect free, so every call to it is treated as if it
+  /// were annotated with @__PURE__.
+  ///
+  /// <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/no-side-effects-notation-spec.md>
+  pub fn has_no_side_effects_annotation(&self, span: Span) -> bool {
+    let leading_comment = self.leading_comment_for(span);
+
+    leading_comment.map_or(false, |(_comment, comment_text)| {
+      NO_SIDE_EFFECTS_COMMENTS.find_iter(comment_text).next().is_some()
+    })
+  }
+}
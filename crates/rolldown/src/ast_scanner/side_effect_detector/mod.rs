@@ -7,8 +7,7 @@ use oxc::ast::ast::{
   PropertyKey, VariableDeclarationKind,
 };
 use oxc::ast::{match_expression, match_member_expression};
-use oxc::semantic::SymbolTable;
-use rolldown_common::AstScopes;
+use rolldown_common::{AstScopes, SymbolRefDbForModule, SymbolRefFlags};
 use rolldown_utils::global_reference::{
   is_global_ident_ref, is_side_effect_free_member_expr_of_len_three,
   is_side_effect_free_member_expr_of_len_two,
@@ -30,7 +29,7 @@ pub struct SideEffectDetector<'a> {
   pub comments: &'a oxc::allocator::Vec<'a, Comment>,
   pub ignore_annotations: bool,
   pub jsx_preserve: bool,
-  pub symbol_table: &'a SymbolTable,
+  pub symbol_table: &'a SymbolRefDbForModule,
 }
 
 impl<'a> SideEffectDetector<'a> {
@@ -40,7 +39,7 @@ impl<'a> SideEffectDetector<'a> {
     comments: &'a oxc::allocator::Vec<'a, Comment>,
     ignore_annotations: bool,
     jsx_preserve: bool,
-    symbol_table: &'a SymbolTable,
+    symbol_table: &'a SymbolRefDbForModule,
   ) -> Self {
     Self { scope, source, comments, ignore_annotations, jsx_preserve, symbol_table }
   }
@@ -49,6 +48,22 @@ impl<'a> SideEffectDetector<'a> {
     self.scope.is_unresolved(ident_ref.reference_id.get().unwrap(), self.symbol_table)
   }
 
+  /// Whether the callee refers to a function annotated with `@__NO_SIDE_EFFECTS__`, either declared in this
+  /// module or imported from another one.
+  fn is_side_effect_free_function(&self, callee: &Expression) -> bool {
+    let Expression::Identifier(ident_ref) = callee.without_parentheses() else {
+      return false;
+    };
+    let Some(symbol_id) = self.symbol_table.references[ident_ref.reference_id()].symbol_id() else {
+      return false;
+    };
+    self
+      .symbol_table
+      .flags
+      .get(&symbol_id)
+      .is_some_and(|flags| flags.contains(SymbolRefFlags::SIDE_EFFECT_FREE_FUNCTION))
+  }
+
   fn detect_side_effect_of_property_key(&self, key: &PropertyKey, is_computed: bool) -> bool {
     match key {
       PropertyKey::StaticIdentifier(_) | PropertyKey::PrivateIdentifier(_) => false,
@@ -150,7 +165,9 @@ impl<'a> SideEffectDetector<'a> {
   }
 
   fn detect_side_effect_of_call_expr(&mut self, expr: &CallExpression) -> bool {
-    let is_pure = !self.ignore_annotations && self.is_pure_function_or_constructor_call(expr.span);
+    let is_pure = !self.ignore_annotations
+      && (self.is_pure_function_or_constructor_call(expr.span)
+        || self.is_side_effect_free_function(&expr.callee));
     if is_pure {
       expr.arguments.iter().any(|arg| match arg {
         Argument::SpreadElement(_) => true,
@@ -564,7 +581,7 @@ impl<'a> SideEffectDetector<'a> {
 #[cfg(test)]
 mod test {
   use oxc::span::SourceType;
-  use rolldown_common::AstScopes;
+  use rolldown_common::{AstScopes, ModuleIdx, SymbolRefDbForModule};
   use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 
   use crate::ast_scanner::side_effect_detector::SideEffectDetector;
@@ -575,7 +592,11 @@ mod test {
     let (symbol_table, ast_scope) = {
       let semantic = EcmaAst::make_semantic(ast.program());
       let (symbol_table, scope) = semantic.into_symbol_table_and_scope_tree();
-      (symbol_table, AstScopes::new(scope))
+      let root_scope_id = scope.root_scope_id();
+      (
+        SymbolRefDbForModule::new(symbol_table, ModuleIdx::new(0), root_scope_id),
+        AstScopes::new(scope),
+      )
     };
 
     let has_side_effect = ast.program().body.iter().any(|stmt| {
@@ -3,18 +3,19 @@ use crate::{
   bundler_builder::BundlerBuilder,
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
   types::bundle_output::BundleOutput,
+  utils::{check_size_budgets::check_size_budgets, filter_logs::filter_logs},
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
 
-use rolldown_common::{NormalizedBundlerOptions, SharedFileEmitter};
+use rolldown_common::{NormalizedBundlerOptions, SharedFileEmitter, SizeBudgetSeverity};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{
   HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver, __inner::SharedPluginable,
 };
+use rolldown_tracing::TracingGuard;
 use std::sync::Arc;
-use tracing_chrome::FlushGuard;
 
 pub struct Bundler {
   pub closed: bool,
@@ -24,7 +25,9 @@ pub struct Bundler {
   pub(crate) file_emitter: SharedFileEmitter,
   pub(crate) plugin_driver: SharedPluginDriver,
   pub(crate) warnings: Vec<BuildDiagnostic>,
-  pub(crate) _log_guard: Option<FlushGuard>,
+  /// The raw options and plugins, if the `options` and `output_options` hooks of plugins haven't run yet.
+  pub(crate) pending_options_hooks: Option<(BundlerOptions, Vec<SharedPluginable>)>,
+  pub(crate) _log_guard: Option<TracingGuard>,
 }
 
 impl Bundler {
@@ -32,6 +35,7 @@ impl Bundler {
     BundlerBuilder::default().with_options(options).build()
   }
 
+  /// The `options` and `output_options` hooks of `plugins` run before the first build.
   pub fn with_plugins(options: BundlerOptions, plugins: Vec<SharedPluginable>) -> Self {
     BundlerBuilder::default().with_options(options).with_plugins(plugins).build()
   }
@@ -50,12 +54,13 @@ impl Bundler {
     let scan_stage_output = self.scan().await?;
 
     self.bundle_up(scan_stage_output, /* is_write */ false).await.map(|mut output| {
-      output.warnings.append(&mut self.warnings);
+      output.plugin_timings = self.plugin_driver.take_plugin_timings();
       output
     })
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
+  /// The logs of plugins in the `close_bundle` hook are kept in [Bundler::take_warnings].
   pub async fn close(&mut self) -> Result<()> {
     if self.closed {
       return Ok(());
@@ -64,11 +69,25 @@ impl Bundler {
     self.closed = true;
     self.plugin_driver.close_bundle().await?;
 
+    let mut logs = std::mem::take(&mut self.warnings);
+    logs.extend(self.plugin_driver.take_logs());
+    self.warnings = filter_logs(&self.options, logs).await?;
     Ok(())
   }
 
+  /// Takes the warnings that aren't reported by a build, e.g. the logs of plugins in the
+  /// `close_bundle` hook.
+  pub fn take_warnings(&mut self) -> Vec<BuildDiagnostic> {
+    std::mem::take(&mut self.warnings)
+  }
+
   pub async fn scan(&mut self) -> BuildResult<ScanStageOutput> {
-    let scan_stage_output = match ScanStage::new(
+    self.apply_options_hooks().await?;
+
+    // Drop the logs of plugins left by a failed build.
+    self.plugin_driver.take_logs();
+
+    let mut scan_stage_output = match ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
       self.fs,
@@ -90,6 +109,12 @@ impl Bundler {
 
     self.plugin_driver.build_end(None).await?;
 
+    let mut logs = std::mem::take(&mut scan_stage_output.warnings);
+    // Warnings of normalizing options are reported by the first build.
+    logs.append(&mut self.warnings);
+    logs.extend(self.plugin_driver.take_logs());
+    scan_stage_output.warnings = filter_logs(&self.options, logs).await?;
+
     Ok(scan_stage_output)
   }
 
@@ -120,7 +145,8 @@ impl Bundler {
 
     self.plugin_driver.write_bundle(&mut output.assets, &self.options).await?;
 
-    output.warnings.append(&mut self.warnings);
+    output.warnings.extend(filter_logs(&self.options, self.plugin_driver.take_logs()).await?);
+    output.plugin_timings = self.plugin_driver.take_plugin_timings();
 
     Ok(output)
   }
@@ -128,7 +154,7 @@ impl Bundler {
   #[allow(clippy::missing_transmute_annotations)]
   async fn bundle_up(
     &mut self,
-    scan_stage_output: ScanStageOutput,
+    mut scan_stage_output: ScanStageOutput,
     is_write: bool,
   ) -> BuildResult<BundleOutput> {
     if self.closed {
@@ -140,6 +166,8 @@ impl Bundler {
       );
     }
 
+    // Logs of the scan stage are already filtered.
+    let scan_warnings = std::mem::take(&mut scan_stage_output.warnings);
     let mut link_stage_output = LinkStage::new(scan_stage_output, &self.options).link();
 
     let bundle_output =
@@ -161,14 +189,50 @@ impl Bundler {
 
     self.plugin_driver.generate_bundle(&mut output.assets, is_write, &self.options).await?;
 
+    if let Some(budgets) = &self.options.checks.size_budgets {
+      let breaches = check_size_budgets(&output.assets, budgets);
+      match budgets.severity.unwrap_or_default() {
+        SizeBudgetSeverity::Error if !breaches.is_empty() => return Err(breaches.into()),
+        SizeBudgetSeverity::Error => {}
+        SizeBudgetSeverity::Warning => {
+          output.warnings.extend(breaches.into_iter().map(BuildDiagnostic::with_severity_warning));
+        }
+      }
+    }
+
+    let mut logs = std::mem::replace(&mut output.warnings, scan_warnings);
+    logs.extend(self.plugin_driver.take_logs());
+    output.warnings.extend(filter_logs(&self.options, logs).await?);
+
     output.watch_files = self.plugin_driver.watch_files.iter().map(|f| f.clone()).collect();
 
     Ok(output)
   }
 
+  /// If the bundler is built by [`BundlerBuilder::build`] with plugins, these are the options before the
+  /// `options` and `output_options` hooks of plugins until the first build, and may be replaced by them.
+  /// Use [`BundlerBuilder::build_async`] to run the hooks up front.
   pub fn options(&self) -> &NormalizedBundlerOptions {
     &self.options
   }
+
+  /// Runs the `options` and `output_options` hooks skipped by [`BundlerBuilder::build`], and rebuilds the
+  /// bundler with the options returned by them.
+  async fn apply_options_hooks(&mut self) -> BuildResult<()> {
+    let Some((mut raw_options, plugins)) = self.pending_options_hooks.take() else {
+      return Ok(());
+    };
+    if BundlerBuilder::run_options_hooks(&plugins, &mut raw_options).await? {
+      let Bundler { options, resolver, file_emitter, plugin_driver, warnings, .. } =
+        BundlerBuilder::build_with_options(raw_options, plugins);
+      self.options = options;
+      self.resolver = resolver;
+      self.file_emitter = file_emitter;
+      self.plugin_driver = plugin_driver;
+      self.warnings = warnings;
+    }
+    Ok(())
+  }
 }
 
 fn _test_bundler() {
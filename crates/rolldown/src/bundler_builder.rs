@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use rolldown_common::FileEmitter;
-use rolldown_fs::OsFileSystem;
+use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{PluginDriver, __inner::SharedPluginable};
 use rolldown_resolver::Resolver;
 
@@ -20,11 +21,50 @@ pub struct BundlerBuilder {
 }
 
 impl BundlerBuilder {
-  pub fn build(mut self) -> Bundler {
+  /// The `options` and `output_options` hooks of plugins are async, so they run before the first build.
+  /// Use [`BundlerBuilder::build_async`] to run them right away.
+  pub fn build(self) -> Bundler {
+    let pending_options_hooks =
+      (!self.plugins.is_empty()).then(|| (self.options.clone(), self.plugins.clone()));
+    let mut bundler = Self::build_with_options(self.options, self.plugins);
+    bundler.pending_options_hooks = pending_options_hooks;
+    bundler
+  }
+
+  /// Runs the `options` and `output_options` hooks of plugins before building the bundler.
+  pub async fn build_async(mut self) -> BuildResult<Bundler> {
+    Self::run_options_hooks(&self.plugins, &mut self.options).await?;
+    Ok(Self::build_with_options(self.options, self.plugins))
+  }
+
+  /// Returns whether any plugin replaced the options.
+  pub(crate) async fn run_options_hooks(
+    plugins: &[SharedPluginable],
+    options: &mut BundlerOptions,
+  ) -> BuildResult<bool> {
+    if plugins.is_empty() {
+      return Ok(false);
+    }
+    let options_replaced =
+      PluginDriver::options(plugins, options).await.map_err(BuildDiagnostic::unhandleable_error)?;
+    let mut output_options = options.output_options();
+    let output_options_replaced = PluginDriver::output_options(plugins, &mut output_options)
+      .await
+      .map_err(BuildDiagnostic::unhandleable_error)?;
+    if output_options_replaced {
+      options.set_output_options(output_options);
+    }
+    Ok(options_replaced || output_options_replaced)
+  }
+
+  pub(crate) fn build_with_options(
+    raw_options: BundlerOptions,
+    mut plugins: Vec<SharedPluginable>,
+  ) -> Bundler {
     let maybe_guard = rolldown_tracing::try_init_tracing();
 
     let NormalizeOptionsReturn { options, resolve_options, warnings } =
-      normalize_options(self.options);
+      normalize_options(raw_options);
 
     let resolver: SharedResolver =
       Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
@@ -33,16 +73,19 @@ impl BundlerBuilder {
 
     let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
 
-    apply_inner_plugins(&mut self.plugins);
+    apply_inner_plugins(&mut plugins);
+
+    let fs: Arc<dyn FileSystem> = Arc::new(OsFileSystem);
 
     Bundler {
       closed: false,
-      plugin_driver: PluginDriver::new_shared(self.plugins, &resolver, &file_emitter, &options),
+      plugin_driver: PluginDriver::new_shared(plugins, &resolver, &fs, &file_emitter, &options),
       file_emitter,
       resolver,
       options,
       fs: OsFileSystem,
       warnings,
+      pending_options_hooks: None,
       _log_guard: maybe_guard,
     }
   }
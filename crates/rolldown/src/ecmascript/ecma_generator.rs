@@ -15,7 +15,10 @@ use rolldown_plugin::HookAddonArgs;
 use rolldown_sourcemap::Source;
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
-use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+use rolldown_utils::{
+  indexmap::FxIndexSet,
+  rayon::{IntoParallelRefIterator, ParallelIterator},
+};
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
@@ -61,6 +64,20 @@ impl Generator for EcmaGenerator {
       ctx.chunk.pre_rendered_chunk.as_ref().expect("Should have pre-rendered chunk"),
       ctx.chunk_graph,
     );
+    let legal_comments = if ctx.options.legal_comments.is_extracted() {
+      let mut legal_comments = FxIndexSet::default();
+      for (module_idx, _, _) in &rendered_module_sources {
+        if let Some(module) = ctx.link_output.module_table.modules[*module_idx].as_normal() {
+          legal_comments.extend(
+            module.legal_comments.iter().map(|span| span.source_text(module.source.as_str())),
+          );
+        }
+      }
+      legal_comments.into_iter().map(ToString::to_string).collect()
+    } else {
+      vec![]
+    };
+
     let hashbang = match ctx.chunk.user_defined_entry_module(&ctx.link_output.module_table) {
       Some(normal_module) => {
         let source = &normal_module.source;
@@ -216,7 +233,7 @@ impl Generator for EcmaGenerator {
         origin_chunk: ctx.chunk_idx,
         content: content.into(),
         map,
-        kind: InstantiationKind::from(EcmaAssetMeta { rendered_chunk }),
+        kind: InstantiationKind::from(EcmaAssetMeta { rendered_chunk, legal_comments }),
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
         preliminary_filename: ctx
@@ -1,11 +1,14 @@
 use arcstr::ArcStr;
-use oxc::semantic::{ScopeTree, SymbolTable};
+use oxc::{
+  semantic::{ScopeTree, SymbolTable},
+  span::Span,
+};
 use oxc_index::IndexVec;
 use rolldown_common::{
   dynamic_import_usage::DynamicImportExportsUsage,
   side_effects::{DeterminedSideEffects, HookSideEffects},
-  AstScopes, EcmaView, EcmaViewMeta, ImportRecordIdx, ModuleDefFormat, ModuleId, ModuleIdx,
-  ModuleType, RawImportRecord, SymbolRef, SymbolRefDbForModule, TreeshakeOptions,
+  AstScopes, EcmaView, EcmaViewMeta, ImportRecordIdx, LegalComments, ModuleDefFormat, ModuleId,
+  ModuleIdx, ModuleType, RawImportRecord, SymbolRef, SymbolRefDbForModule, TreeshakeOptions,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildResult;
@@ -21,6 +24,7 @@ use crate::{
   SharedOptions,
 };
 
+#[tracing::instrument(level = "trace", skip_all, fields(module_id = %id))]
 fn scan_ast(
   module_idx: ModuleIdx,
   id: &ArcStr,
@@ -53,6 +57,23 @@ fn scan_ast(
 
   Ok((ast_scopes, scan_result, namespace_object_ref))
 }
+
+/// Remove legal comments from the ast, so they could be emitted at chunk level or dropped.
+fn extract_legal_comments(ast: &mut EcmaAst) -> Vec<Span> {
+  let source = ast.source().clone();
+  let mut legal_comments = vec![];
+  ast.program.with_mut(|fields| {
+    fields.program.comments.retain(|comment| {
+      let is_legal = comment.is_legal(&source);
+      if is_legal {
+        legal_comments.push(comment.span);
+      }
+      !is_legal
+    });
+  });
+  legal_comments
+}
+
 pub struct CreateEcmaViewReturn {
   pub view: EcmaView,
   pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
@@ -71,12 +92,14 @@ pub async fn create_ecma_view<'any>(
 
   let parse_result = parse_to_ecma_ast(
     ctx.plugin_driver,
+    ctx.fs,
     ctx.resolved_id.id.as_path(),
     &stable_id,
     ctx.options,
     &ctx.module_type,
     args.source.clone(),
     ctx.replace_global_define_config.as_ref(),
+    ctx.tsconfig_compiler_options.as_deref(),
     ctx.is_user_defined_entry,
   )?;
 
@@ -104,14 +127,17 @@ pub async fn create_ecma_view<'any>(
     exports_kind,
     warnings: scan_warnings,
     has_eval,
+    direct_eval_scopes,
     errors,
     ast_usage,
     symbol_ref_db,
     self_referenced_class_decl_symbol_ids,
     hashbang_range,
+    directives,
     has_star_exports,
     dynamic_import_rec_exports_usage: dynamic_import_exports_usage,
     new_url_references: new_url_imports,
+    import_meta_references,
     this_expr_replace_map,
   } = scan_result;
   if !errors.is_empty() {
@@ -119,15 +145,37 @@ pub async fn create_ecma_view<'any>(
   }
   ctx.warnings.extend(scan_warnings);
 
+  let legal_comments = match ctx.options.legal_comments {
+    LegalComments::Inline => vec![],
+    LegalComments::None => {
+      extract_legal_comments(&mut ast);
+      vec![]
+    }
+    LegalComments::Eof | LegalComments::Linked | LegalComments::External => {
+      extract_legal_comments(&mut ast)
+    }
+  };
+
   // The side effects priority is:
   // 1. Hook side effects
   // 2. Package.json side effects
   // 3. Analyzed side effects
   // We should skip the `check_side_effects_for` if the hook side effects is not `None`.
   let lazy_check_side_effects = || {
-    if matches!(ctx.module_type, ModuleType::Css) {
+    match &ctx.module_type {
       // CSS modules are considered to have side effects by default
-      return DeterminedSideEffects::Analyzed(true);
+      ModuleType::Css => return DeterminedSideEffects::Analyzed(true),
+      ModuleType::Custom(custom_type) => {
+        let side_effects = ctx
+          .options
+          .custom_module_types
+          .get(custom_type)
+          .and_then(|handler| handler.side_effects());
+        if let Some(side_effects) = side_effects {
+          return DeterminedSideEffects::Analyzed(side_effects);
+        }
+      }
+      _ => {}
     }
     ctx
       .resolved_id
@@ -201,6 +249,9 @@ pub async fn create_ecma_view<'any>(
     ast_usage,
     self_referenced_class_decl_symbol_ids,
     hashbang_range,
+    directives,
+    legal_comments,
+    direct_eval_scopes,
     meta: {
       let mut meta = EcmaViewMeta::default();
       meta.set_included(false);
@@ -211,6 +262,7 @@ pub async fn create_ecma_view<'any>(
     },
     mutations: vec![],
     new_url_references: new_url_imports,
+    import_meta_references,
     this_expr_replace_map,
   };
 
@@ -7,7 +7,8 @@ use crate::{
   ecmascript::ecma_generator::RenderedModuleSources,
   types::generator::GenerateContext,
   utils::chunk::{
-    determine_use_strict::determine_use_strict, render_chunk_exports::render_chunk_exports,
+    determine_use_strict::determine_use_strict, render_chunk_directives::render_chunk_directives,
+    render_chunk_exports::render_chunk_exports,
   },
 };
 use rolldown_common::OutputExports;
@@ -41,6 +42,10 @@ pub fn render_cjs<'code>(
     source_joiner.append_source("\"use strict\";");
   }
 
+  if let Some(directives) = render_chunk_directives(ctx) {
+    source_joiner.append_source(directives);
+  }
+
   if let Some(intro) = intro {
     source_joiner.append_source(intro);
   }
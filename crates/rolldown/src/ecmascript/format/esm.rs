@@ -1,13 +1,17 @@
 use arcstr::ArcStr;
 use itertools::Itertools;
-use rolldown_common::{ExportsKind, Specifier};
+use rolldown_common::{ExportsKind, ModuleIdx, NamedImport, Specifier};
+use rolldown_rstr::Rstr;
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::{concat_string, ecmascript::is_validate_identifier_name};
 
 use crate::{
   ecmascript::ecma_generator::RenderedModuleSources,
   types::generator::GenerateContext,
-  utils::chunk::render_chunk_exports::{render_chunk_exports, render_wrapped_entry_chunk},
+  utils::chunk::{
+    render_chunk_directives::render_chunk_directives,
+    render_chunk_exports::{render_chunk_exports, render_wrapped_entry_chunk},
+  },
 };
 
 pub fn render_esm<'code>(
@@ -29,6 +33,10 @@ pub fn render_esm<'code>(
     source_joiner.append_source(banner);
   }
 
+  if let Some(directives) = render_chunk_directives(ctx) {
+    source_joiner.append_source(directives);
+  }
+
   if let Some(intro) = intro {
     source_joiner.append_source(intro);
   }
@@ -112,6 +120,7 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
       &default_alias,
       // TODO: filename relative to importee
       &ctx.chunk.import_path_for(importee_chunk).into(),
+      None,
     ));
   });
   // render external imports
@@ -119,66 +128,121 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
     let importee = &ctx.link_output.module_table.modules[*importee_id]
       .as_external()
       .expect("Should be external module here");
-    let mut has_importee_imported = false;
-    let mut default_alias = vec![];
-    let specifiers = named_imports
-      .iter()
-      .filter_map(|item| {
-        let canonical_ref = &ctx.link_output.symbol_db.canonical_ref_for(item.imported_as);
-        if !ctx.link_output.used_symbol_refs.contains(canonical_ref) {
-          return None;
-        };
-        let alias = &ctx.chunk.canonical_names[canonical_ref];
-        match &item.imported {
-          Specifier::Star => {
-            has_importee_imported = true;
-            s.push_str("import * as ");
-            s.push_str(alias);
-            s.push_str(" from \"");
-            s.push_str(&importee.name);
-            s.push_str("\";\n");
-            None
-          }
-          Specifier::Literal(imported) => {
-            if alias == imported {
-              Some(alias.as_str().into())
-            } else {
-              if imported.as_str() == "default" {
-                default_alias.push(alias.as_str().into());
-                return None;
-              }
-              let imported = if is_validate_identifier_name(imported) {
-                imported.clone()
+    // Imports of the same external module are merged into one declaration for each `type` import attribute,
+    // since the module is loaded differently for each of them.
+    for import_attribute_type in external_import_attribute_types(ctx, *importee_id) {
+      let with_clause = render_with_clause(import_attribute_type);
+      let mut has_importee_imported = false;
+      let mut default_alias = vec![];
+      let specifiers = named_imports
+        .iter()
+        .filter(|item| named_import_attribute_type(ctx, item) == import_attribute_type)
+        .filter_map(|item| {
+          let canonical_ref = &ctx.link_output.symbol_db.canonical_ref_for(item.imported_as);
+          if !ctx.link_output.used_symbol_refs.contains(canonical_ref) {
+            return None;
+          };
+          let alias = &ctx.chunk.canonical_names[canonical_ref];
+          match &item.imported {
+            Specifier::Star => {
+              has_importee_imported = true;
+              s.push_str("import * as ");
+              s.push_str(alias);
+              s.push_str(" from \"");
+              s.push_str(&importee.name);
+              s.push('"');
+              s.push_str(&with_clause);
+              s.push_str(";\n");
+              None
+            }
+            Specifier::Literal(imported) => {
+              if alias == imported {
+                Some(alias.as_str().into())
               } else {
-                format!("'{imported}'").into()
-              };
-              Some(concat_string!(imported, " as ", alias))
+                if imported.as_str() == "default" {
+                  default_alias.push(alias.as_str().into());
+                  return None;
+                }
+                let imported = if is_validate_identifier_name(imported) {
+                  imported.clone()
+                } else {
+                  format!("'{imported}'").into()
+                };
+                Some(concat_string!(imported, " as ", alias))
+              }
             }
           }
-        }
-      })
-      .sorted_unstable()
-      .dedup()
-      .collect::<Vec<_>>();
-    default_alias.sort_unstable();
-    default_alias.dedup();
-
-    if !specifiers.is_empty()
-      || !default_alias.is_empty()
-      || (importee.side_effects.has_side_effects() && !has_importee_imported)
-    {
-      s.push_str(&create_import_declaration(specifiers, &default_alias, &importee.name));
+        })
+        .sorted_unstable()
+        .dedup()
+        .collect::<Vec<_>>();
+      default_alias.sort_unstable();
+      default_alias.dedup();
+
+      if !specifiers.is_empty()
+        || !default_alias.is_empty()
+        || (importee.side_effects.has_side_effects() && !has_importee_imported)
+      {
+        s.push_str(&create_import_declaration(
+          specifiers,
+          &default_alias,
+          &importee.name,
+          import_attribute_type,
+        ));
+      }
     }
   });
 
   s
 }
 
+/// The distinct `type` import attributes of static imports of the external module in this chunk, in the
+/// order they first appear. `None` stands for imports without the attribute.
+fn external_import_attribute_types<'a>(
+  ctx: &'a GenerateContext<'_>,
+  importee_id: ModuleIdx,
+) -> Vec<Option<&'a Rstr>> {
+  let types = ctx
+    .chunk
+    .modules
+    .iter()
+    .filter_map(|id| ctx.link_output.module_table.modules[*id].as_normal())
+    .flat_map(|module| module.import_records.iter())
+    .filter(|rec| rec.resolved_module == importee_id && rec.kind.is_static())
+    .map(|rec| rec.import_attribute_type.as_ref())
+    .unique()
+    .collect::<Vec<_>>();
+  if types.is_empty() {
+    vec![None]
+  } else {
+    types
+  }
+}
+
+/// The `type` import attribute of the import declaration that `item` comes from.
+fn named_import_attribute_type<'a>(
+  ctx: &'a GenerateContext<'_>,
+  item: &NamedImport,
+) -> Option<&'a Rstr> {
+  ctx.link_output.module_table.modules[item.imported_as.owner].as_normal()?.import_records
+    [item.record_id]
+    .import_attribute_type
+    .as_ref()
+}
+
+fn render_with_clause(import_attribute_type: Option<&Rstr>) -> String {
+  import_attribute_type
+    .map(|ty| concat_string!(" with { type: \"", ty.as_str(), "\" }"))
+    .unwrap_or_default()
+}
+
 fn create_import_declaration(
   mut specifiers: Vec<String>,
   default_alias: &[ArcStr],
   path: &ArcStr,
+  import_attribute_type: Option<&Rstr>,
 ) -> String {
+  let with_clause = render_with_clause(import_attribute_type);
   let mut ret = String::new();
   let first_default_alias = match &default_alias {
     [] => None,
@@ -198,17 +262,23 @@ fn create_import_declaration(
     ret.push_str(&specifiers.join(", "));
     ret.push_str(" } from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(&with_clause);
+    ret.push_str(";\n");
   } else if let Some(first_default_alias) = first_default_alias {
     ret.push_str("import ");
     ret.push_str(first_default_alias);
     ret.push_str(" from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(&with_clause);
+    ret.push_str(";\n");
   } else {
     ret.push_str("import \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(&with_clause);
+    ret.push_str(";\n");
   }
   ret
 }
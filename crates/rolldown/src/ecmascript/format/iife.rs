@@ -32,7 +32,7 @@ use crate::{
   types::generator::GenerateContext,
   utils::chunk::{
     determine_export_mode::determine_export_mode, determine_use_strict::determine_use_strict,
-    render_chunk_exports::render_chunk_exports,
+    render_chunk_directives::render_chunk_directives, render_chunk_exports::render_chunk_exports,
   },
 };
 use arcstr::ArcStr;
@@ -68,6 +68,10 @@ pub async fn render_iife<'code>(
     source_joiner.append_source(banner);
   }
 
+  if let Some(directives) = render_chunk_directives(ctx) {
+    source_joiner.append_source(directives);
+  }
+
   // iife wrapper start
 
   // Analyze the export information of the chunk.
@@ -13,6 +13,7 @@ use crate::{
     determine_export_mode::determine_export_mode,
     determine_use_strict::determine_use_strict,
     namespace_marker::render_namespace_markers,
+    render_chunk_directives::render_chunk_directives,
     render_chunk_exports::{
       get_chunk_export_names, render_chunk_exports, render_wrapped_entry_chunk,
     },
@@ -40,6 +41,10 @@ pub async fn render_umd<'code>(
     source_joiner.append_source(banner);
   }
 
+  if let Some(directives) = render_chunk_directives(ctx) {
+    source_joiner.append_source(directives);
+  }
+
   // umd wrapper start
 
   // Analyze the export information of the chunk.
@@ -1,4 +1,4 @@
-use oxc::semantic::SymbolId;
+use oxc::{semantic::SymbolId, span::Span};
 use rolldown_common::{
   IndexModules, ModuleIdx, NormalModule, RuntimeModuleBrief, SymbolRef, SymbolRefDb,
 };
@@ -25,4 +25,7 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub options: &'me SharedOptions,
   pub cur_stmt_index: usize,
   pub keep_name_statement_to_insert: Vec<(usize, SymbolId, Rstr, Rstr)>,
+  /// Code returned by the `resolve_file_url`, `resolve_import_meta` and `render_dynamic_import` hooks,
+  /// keyed by the `Span` of the replaced expression.
+  pub plugin_replacements: Option<&'me FxHashMap<Span, String>>,
 }
@@ -20,6 +20,8 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
     // we don't want oxc to generate hashbang statement in module level since we already handle
     // them in chunk level
     program.hashbang.take();
+    // Same for directives, they are rendered at the top of the chunk.
+    program.directives.clear();
 
     let is_namespace_referenced = matches!(self.ctx.module.exports_kind, ExportsKind::Esm)
       && self.ctx.module.stmt_infos[StmtInfoIdx::new(0)].is_included;
@@ -212,10 +214,16 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
     }
 
     // TODO: perf it
-    for (stmt_index, _symbol_id, original_name, new_name) in
-      self.ctx.keep_name_statement_to_insert.iter().rev()
-    {
-      it.insert(*stmt_index, self.snippet.keep_name_call_expr_stmt(original_name, new_name));
+    if !self.ctx.keep_name_statement_to_insert.is_empty() {
+      let helper_name = self.canonical_name_for_runtime("__name");
+      for (stmt_index, _symbol_id, original_name, new_name) in
+        self.ctx.keep_name_statement_to_insert.iter().rev()
+      {
+        it.insert(
+          *stmt_index,
+          self.snippet.keep_name_call_expr_stmt(helper_name, original_name, new_name),
+        );
+      }
     }
     self.ctx.cur_stmt_index = previous_stmt_index;
     self.ctx.keep_name_statement_to_insert = previous_keep_name_statement;
@@ -244,6 +252,10 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   }
 
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    if let Some(new_expr) = self.try_rewrite_expr_with_plugin_replacement(expr) {
+      *expr = new_expr;
+      return;
+    }
     match expr {
       ast::Expression::CallExpression(call_expr) => {
         if let Some(new_expr) = self.try_rewrite_global_require_call(call_expr) {
@@ -332,7 +344,12 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   fn visit_import_expression(&mut self, expr: &mut ast::ImportExpression<'ast>) {
     // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
     match &mut expr.source {
-      ast::Expression::StringLiteral(str) if expr.arguments.len() == 0 => {
+      ast::Expression::StringLiteral(str)
+        if expr.arguments.len() == 0
+          || self.ctx.module.import_records[self.ctx.module.imports[&expr.span]]
+            .import_attribute_type
+            .is_some() =>
+      {
         let rec_id = self.ctx.module.imports[&expr.span];
         let rec = &self.ctx.module.import_records[rec_id];
         let importee_id = rec.resolved_module;
@@ -348,6 +365,8 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
             let import_path = importer_chunk.import_path_for(importee_chunk);
 
             str.value = self.snippet.atom(&import_path);
+            // The importee is rendered as a js chunk, so its import attributes no longer apply.
+            expr.arguments.clear();
           }
           Module::External(importee) => {
             if str.value != importee.name {
@@ -5,10 +5,11 @@ use oxc::{
       self, BindingIdentifier, ClassElement, Expression, IdentifierReference, ImportExpression,
       MemberExpression, Statement, VariableDeclarationKind,
     },
-    Comment, NONE,
+    Comment, VisitMut, NONE,
   },
+  parser::Parser,
   semantic::SymbolId,
-  span::{Atom, GetSpan, SPAN},
+  span::{Atom, GetSpan, SourceType, Span, SPAN},
 };
 use rolldown_common::{
   AstScopes, ExportsKind, ImportRecordIdx, ImportRecordMeta, Module, ModuleType, OutputFormat,
@@ -373,6 +374,21 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     ret
   }
 
+  /// Replace the expression with the code returned by plugins, e.g. by the `resolve_import_meta` hook.
+  pub fn try_rewrite_expr_with_plugin_replacement(
+    &self,
+    expr: &Expression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    let code = self.ctx.plugin_replacements?.get(&expr.span())?;
+    // The code has been validated in the generate stage.
+    let mut new_expr = Parser::new(self.alloc, self.alloc.alloc_str(code), SourceType::mjs())
+      .parse_expression()
+      .ok()?;
+    // Spans of the parsed code don't point to the original source.
+    ClearSpans.visit_expression(&mut new_expr);
+    Some(new_expr)
+  }
+
   // Handle `import.meta.xxx` expression
   pub fn try_rewrite_import_meta_prop_expr(
     &self,
@@ -625,8 +641,9 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
         let rewrite_ast = match &self.ctx.modules[rec.resolved_module] {
           Module::Normal(importee) => {
             match importee.module_type {
-              ModuleType::Json => {
+              ModuleType::Json | ModuleType::Yaml | ModuleType::Toml => {
                 // Nodejs treats json files as an esm module with a default export and rolldown follows this behavior.
+                // Yaml and toml files are loaded as json, so they are treated the same.
                 // And to make sure the runtime behavior is correct, we need to rewrite `require('xxx.json')` to `require('xxx.json').default` to align with the runtime behavior of nodejs.
 
                 // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports).default)`
@@ -1017,7 +1034,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     symbol_binding_id: Option<&BindingIdentifier<'ast>>,
     name_binding_id: Option<&BindingIdentifier<'ast>>,
   ) -> Option<()> {
-    if !self.ctx.options.keep_names {
+    if !self.ctx.options.minify.keep_fnames {
       return None;
     }
     let (_, original_name, _) = self.get_conflicted_info(name_binding_id.as_ref()?)?;
@@ -1038,11 +1055,20 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     &mut self,
     id: Option<&BindingIdentifier<'ast>>,
   ) -> Option<ClassElement<'ast>> {
-    if !self.ctx.options.keep_names {
+    if !self.ctx.options.minify.keep_classnames {
       return None;
     }
     let (_, original_name, _) = self.get_conflicted_info(id.as_ref()?)?;
     let original_name: Rstr = original_name.into();
-    Some(self.snippet.static_block_keep_name_helper(&original_name))
+    let helper_name = self.canonical_name_for_runtime("__name");
+    Some(self.snippet.static_block_keep_name_helper(helper_name, &original_name))
+  }
+}
+
+struct ClearSpans;
+
+impl VisitMut<'_> for ClearSpans {
+  fn visit_span(&mut self, span: &mut Span) {
+    *span = SPAN;
   }
 }
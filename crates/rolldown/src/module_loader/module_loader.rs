@@ -10,14 +10,16 @@ use oxc_index::IndexVec;
 use rolldown_common::dynamic_import_usage::DynamicImportExportsUsage;
 use rolldown_common::side_effects::{DeterminedSideEffects, HookSideEffects};
 use rolldown_common::{
-  EcmaRelated, EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx,
-  ImporterRecord, Module, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleSideEffects,
-  ModuleTable, ModuleType, NormalModuleTaskResult, ResolvedId, RuntimeModuleBrief,
-  RuntimeModuleTaskResult, SymbolRefDb, SymbolRefDbForModule, TreeshakeOptions, RUNTIME_MODULE_ID,
+  EcmaRelated, EmittedChunk, EntryPoint, EntryPointKind, ExternalModule, ImportKind,
+  ImportRecordIdx, ImporterRecord, Module, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg,
+  ModuleSideEffects, ModuleTable, ModuleType, NormalModuleTaskResult, ResolvedId,
+  RuntimeModuleBrief, RuntimeModuleTaskResult, SymbolRefDb, SymbolRefDbForModule, TreeshakeOptions,
+  RUNTIME_MODULE_ID,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
+use rolldown_resolver::ResolveError;
 use rolldown_utils::ecmascript::legitimize_identifier_name;
 use rolldown_utils::indexmap::FxIndexSet;
 use rolldown_utils::rayon::{IntoParallelIterator, ParallelIterator};
@@ -25,7 +27,7 @@ use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::Arc;
 
-use crate::{SharedOptions, SharedResolver};
+use crate::{utils::resolve_id::resolve_id, SharedOptions, SharedResolver};
 
 pub struct IntermediateNormalModules {
   pub modules: IndexVec<ModuleIdx, Option<Module>>,
@@ -55,6 +57,9 @@ pub struct ModuleLoader {
   tx: tokio::sync::mpsc::Sender<ModuleLoaderMsg>,
   rx: tokio::sync::mpsc::Receiver<ModuleLoaderMsg>,
   visited: FxHashMap<ArcStr, ModuleIdx>,
+  /// Module types asserted by importers of modules that were already visited. They are checked against
+  /// the loaded module type once all modules are loaded.
+  visited_type_assertions: Vec<(ModuleIdx, ModuleType, ModuleTaskOwner)>,
   runtime_id: ModuleIdx,
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
@@ -124,6 +129,7 @@ impl ModuleLoader {
       intermediate_normal_modules,
       symbol_ref_db: SymbolRefDb::default(),
       visited: FxHashMap::from_iter([(RUNTIME_MODULE_ID.into(), runtime_id)]),
+      visited_type_assertions: Vec::new(),
     })
   }
 
@@ -135,7 +141,13 @@ impl ModuleLoader {
     assert_module_type: Option<ModuleType>,
   ) -> ModuleIdx {
     match self.visited.entry(resolved_id.id.clone()) {
-      std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
+      std::collections::hash_map::Entry::Occupied(visited) => {
+        let idx = *visited.get();
+        if let (Some(asserted), Some(owner)) = (assert_module_type, owner) {
+          self.visited_type_assertions.push((idx, asserted, owner));
+        }
+        idx
+      }
       std::collections::hash_map::Entry::Vacant(not_visited) => {
         let idx = self.intermediate_normal_modules.alloc_ecma_module_idx();
 
@@ -211,6 +223,79 @@ impl ModuleLoader {
     }
   }
 
+  /// Adds chunks emitted by plugins as user-defined entries. Emitting the same module multiple times only
+  /// creates one entry point.
+  async fn add_emitted_entries(
+    &mut self,
+    entry_points: &mut Vec<EntryPoint>,
+    user_defined_entry_ids: &mut FxHashSet<ModuleIdx>,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) {
+    loop {
+      // Resolving the emitted chunks might emit more chunks.
+      let chunks = self.shared_context.plugin_driver.file_emitter().take_pending_chunks();
+      if chunks.is_empty() {
+        break;
+      }
+      for (reference_id, chunk) in chunks {
+        match self.resolve_emitted_chunk(&chunk).await {
+          Ok(resolved_id) => {
+            self
+              .shared_context
+              .plugin_driver
+              .file_emitter()
+              .set_chunk_module_id(&reference_id, resolved_id.id.clone());
+            let id = self.try_spawn_new_task(resolved_id, None, true, None);
+            user_defined_entry_ids.insert(id);
+            match entry_points.iter_mut().find(|entry| entry.id == id) {
+              Some(entry) => {
+                entry.name = entry.name.take().or(chunk.name);
+                entry.file_name = entry.file_name.take().or(chunk.file_name);
+              }
+              None => entry_points.push(EntryPoint {
+                name: chunk.name,
+                id,
+                kind: EntryPointKind::UserDefined,
+                file_name: chunk.file_name,
+              }),
+            }
+          }
+          Err(err) => errors.push(err),
+        }
+      }
+    }
+  }
+
+  async fn resolve_emitted_chunk(
+    &self,
+    chunk: &EmittedChunk,
+  ) -> Result<ResolvedId, BuildDiagnostic> {
+    let resolved_id = resolve_id(
+      &self.shared_context.resolver,
+      &self.shared_context.plugin_driver,
+      &chunk.id,
+      chunk.importer.as_deref(),
+      true,
+      ImportKind::Import,
+      None,
+      Arc::default(),
+      true,
+    )
+    .await
+    .map_err(BuildDiagnostic::unhandleable_error)?;
+
+    match resolved_id {
+      Ok(resolved_id) if resolved_id.is_external => {
+        Err(BuildDiagnostic::entry_cannot_be_external(resolved_id.id.to_string()))
+      }
+      Ok(resolved_id) => Ok(resolved_id),
+      Err(ResolveError::NotFound(_)) => {
+        Err(BuildDiagnostic::unresolved_entry(&chunk.id, None, None))
+      }
+      Err(err) => Err(BuildDiagnostic::unresolved_entry(&chunk.id, Some(err), None)),
+    }
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
@@ -238,12 +323,16 @@ impl ModuleLoader {
         name,
         id: self.try_spawn_new_task(info, None, true, None),
         kind: EntryPointKind::UserDefined,
+        file_name: None,
       })
       .inspect(|e| {
         user_defined_entry_ids.insert(e.id);
       })
       .collect::<Vec<_>>();
 
+    // Chunks emitted in the `build_start` hook or while resolving user-defined entries
+    self.add_emitted_entries(&mut entry_points, &mut user_defined_entry_ids, &mut errors).await;
+
     let mut dynamic_import_entry_ids = FxHashSet::default();
     let mut dynamic_import_exports_usage_pairs = vec![];
 
@@ -357,6 +446,21 @@ impl ModuleLoader {
           self.remaining -= 1;
         }
       }
+      // Hooks of a module task emit chunks before the task is done, so they are always added here.
+      self.add_emitted_entries(&mut entry_points, &mut user_defined_entry_ids, &mut errors).await;
+    }
+    self.shared_context.plugin_driver.file_emitter().finish_chunk_emission();
+
+    for (idx, asserted, owner) in std::mem::take(&mut self.visited_type_assertions) {
+      if let Some(Module::Normal(module)) = &self.intermediate_normal_modules.modules[idx] {
+        if module.module_type != asserted {
+          errors.push(owner.import_attribute_type_conflict(
+            &module.id,
+            &asserted,
+            &module.module_type,
+          ));
+        }
+      }
     }
 
     if !errors.is_empty() {
@@ -390,6 +494,10 @@ impl ModuleLoader {
 
         if let Some(module) = module.as_normal_mut() {
           let idx = ModuleIdx::from(id);
+          // Modules emitted as chunks might be loaded as a dependency before being emitted.
+          if user_defined_entry_ids.contains(&idx) {
+            module.is_user_defined_entry = true;
+          }
           // Note: (Compat to rollup)
           // The `dynamic_importers/importers` should be added after `module_parsed` hook.
           let importers = std::mem::take(&mut self.intermediate_normal_modules.importers[idx]);
@@ -421,13 +529,17 @@ impl ModuleLoader {
     });
     // if `inline_dynamic_imports` is set to be true, here we should not put dynamic imports to entries
     if !self.options.inline_dynamic_imports {
-      let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
+      let mut dynamic_import_entry_ids = dynamic_import_entry_ids
+        .into_iter()
+        .filter(|id| !user_defined_entry_ids.contains(id))
+        .collect::<Vec<_>>();
       dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_id());
 
       entry_points.extend(dynamic_import_entry_ids.into_iter().map(|id| EntryPoint {
         name: None,
         id,
         kind: EntryPointKind::DynamicImport,
+        file_name: None,
       }));
     }
 
@@ -29,7 +29,10 @@ use crate::{
   css::create_css_view,
   ecmascript::ecma_module_view_factory::{create_ecma_view, CreateEcmaViewReturn},
   types::module_factory::{CreateModuleContext, CreateModuleViewArgs},
-  utils::{load_source::load_source, transform_source::transform_source},
+  utils::{
+    load_source::{load_source, ModuleTypeConflict},
+    transform_source::transform_source,
+  },
   SharedOptions, SharedResolver,
 };
 
@@ -43,6 +46,23 @@ impl ModuleTaskOwner {
   pub fn new(source: ArcStr, importer_id: Rstr, importee_span: Span) -> Self {
     ModuleTaskOwner { source, importer_id, importee_span }
   }
+
+  /// The owner imports `importee` as module type `asserted` via import attributes, but it's loaded as `loaded`.
+  pub fn import_attribute_type_conflict(
+    &self,
+    importee: &str,
+    asserted: &ModuleType,
+    loaded: &ModuleType,
+  ) -> BuildDiagnostic {
+    BuildDiagnostic::import_attribute_type_conflict(
+      self.importer_id.to_string(),
+      self.source.clone(),
+      self.importee_span,
+      importee.to_string(),
+      asserted.to_string(),
+      loaded.to_string(),
+    )
+  }
 }
 
 pub struct ModuleTask {
@@ -123,6 +143,17 @@ impl ModuleTask {
     .await;
 
     let (source, mut module_type) = result.map_err(|err| {
+      let err = match (err.downcast::<ModuleTypeConflict>(), &self.owner) {
+        (Ok(conflict), Some(owner)) => {
+          return owner.import_attribute_type_conflict(
+            &self.resolved_id.id,
+            &conflict.asserted,
+            &conflict.loaded,
+          );
+        }
+        (Ok(conflict), None) => anyhow::Error::new(conflict),
+        (Err(err), _) => err,
+      };
       BuildDiagnostic::unloadable_dependency(
         self.resolved_id.debug_id(self.ctx.options.cwd.as_path()).into(),
         self.owner.as_ref().map(|owner| UnloadableDependencyContext {
@@ -156,14 +187,17 @@ impl ModuleTask {
     };
 
     // TODO: module type should be able to updated by transform hook, for now we don't impl it.
-    if let ModuleType::Custom(_) = module_type {
-      // TODO: should provide some diagnostics for user how they should handle the module type.
-      // e.g.
-      // sass -> recommended npm install `sass` etc
-      Err(anyhow::anyhow!(
-        "`{:?}` is not specified module type,  rolldown can't handle this asset correctly. Please use the load/transform hook to transform the resource",
-        self.resolved_id.id
-      ))?;
+    if let ModuleType::Custom(custom_type) = &module_type {
+      // Custom module types with a registered handler are turned into ECMAScript while parsing.
+      if self.ctx.options.custom_module_types.get(custom_type).is_none() {
+        // TODO: should provide some diagnostics for user how they should handle the module type.
+        // e.g.
+        // sass -> recommended npm install `sass` etc
+        Err(anyhow::anyhow!(
+          "`{:?}` is not specified module type,  rolldown can't handle this asset correctly. Please use the load/transform hook to transform the resource",
+          self.resolved_id.id
+        ))?;
+      }
     };
 
     let asset_view = if matches!(module_type, ModuleType::Asset) {
@@ -190,15 +224,24 @@ impl ModuleTask {
 
     let mut warnings = vec![];
 
+    let tsconfig_compiler_options =
+      if matches!(module_type, ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx) {
+        self.ctx.resolver.tsconfig_compiler_options(self.resolved_id.id.as_path())
+      } else {
+        None
+      };
+
     let ret = create_ecma_view(
       &mut CreateModuleContext {
         module_index: self.module_idx,
         plugin_driver: &self.ctx.plugin_driver,
+        fs: self.ctx.fs,
         resolved_id: &self.resolved_id,
         options: &self.ctx.options,
         warnings: &mut warnings,
         module_type: module_type.clone(),
         replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
+        tsconfig_compiler_options,
         is_user_defined_entry: self.is_user_defined_entry,
       },
       CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects },
@@ -319,6 +362,7 @@ impl ModuleTask {
     .await
   }
 
+  #[allow(clippy::too_many_lines)]
   pub async fn resolve_dependencies(
     &mut self,
     dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
@@ -352,6 +396,21 @@ impl ModuleTask {
 
       match resolved_id {
         Ok(info) => {
+          if info.ignored {
+            let dep = &dependencies[idx];
+            warnings.push(
+              BuildDiagnostic::ignored_by_browser_field(
+                source.clone(),
+                self.resolved_id.id.clone(),
+                if dep.is_unspanned() || is_css_module {
+                  DiagnosableArcstr::String(concat_string!("'", specifier.as_str(), "'").into())
+                } else {
+                  DiagnosableArcstr::Span(dep.state.span)
+                },
+              )
+              .with_severity_warning(),
+            );
+          }
           ret.push(info);
         }
         Err(e) => {
@@ -371,6 +430,7 @@ impl ModuleTask {
                   },
                   "Module not found.".into(),
                   Some("UNRESOLVED_IMPORT"),
+                  None,
                 ));
               } else {
                 warnings.push(
@@ -384,6 +444,7 @@ impl ModuleTask {
                     },
                     "Module not found, treating it as an external dependency".into(),
                     Some("UNRESOLVED_IMPORT"),
+                    None,
                   )
                   .with_severity_warning(),
                 );
@@ -400,6 +461,14 @@ impl ModuleTask {
             }
             e => {
               let reason = rolldown_resolver::error::oxc_resolve_error_to_reason(e);
+              let help = match e {
+                ResolveError::PackagePathNotExported(subpath, package_json_path) => self
+                  .ctx
+                  .resolver
+                  .package_exports_hint(subpath, package_json_path, dep.kind)
+                  .map(|hint| hint.to_help_message()),
+                _ => None,
+              };
               build_errors.push(BuildDiagnostic::resolve_error(
                 source.clone(),
                 self.resolved_id.id.clone(),
@@ -410,6 +479,7 @@ impl ModuleTask {
                 },
                 reason,
                 None,
+                help,
               ));
             }
           };
@@ -52,6 +52,7 @@ impl RuntimeModuleTask {
     }
   }
 
+  #[allow(clippy::too_many_lines)]
   fn run_inner(&mut self) -> BuildResult<()> {
     let source = if self.options.is_esm_format_with_node_platform() {
       arcstr::literal!(concat!(
@@ -82,14 +83,17 @@ impl RuntimeModuleTask {
       exports_kind: _,
       warnings: _,
       has_eval,
+      direct_eval_scopes,
       errors: _,
       ast_usage,
       symbol_ref_db,
       self_referenced_class_decl_symbol_ids: _,
       hashbang_range: _,
+      directives: _,
       has_star_exports,
       dynamic_import_rec_exports_usage: _,
       new_url_references,
+      import_meta_references: _,
       this_expr_replace_map: _,
     } = scan_result;
 
@@ -128,6 +132,9 @@ impl RuntimeModuleTask {
         ast_usage,
         self_referenced_class_decl_symbol_ids: FxHashSet::default(),
         hashbang_range: None,
+        directives: vec![],
+        legal_comments: vec![],
+        direct_eval_scopes,
         meta: {
           let mut meta = EcmaViewMeta::default();
           meta.set_included(false);
@@ -138,6 +145,7 @@ impl RuntimeModuleTask {
         },
         mutations: vec![],
         new_url_references,
+        import_meta_references: vec![],
         this_expr_replace_map: FxHashMap::default(),
       },
       css_view: None,
@@ -56,3 +56,12 @@ export var __toBinary = /* @__PURE__ */ (() => {
     return bytes
   }
 })()
+export var __decorateClass = (decorators, target, key, kind) => {
+  var result = kind > 1 ? void 0 : kind ? __getOwnPropDesc(target, key) : target
+  for (var i = decorators.length - 1, decorator; i >= 0; i--)
+    if (decorator = decorators[i])
+      result = (kind ? decorator(target, key, result) : decorator(result)) || result
+  if (kind && result) __defProp(target, key, result)
+  return result
+}
+export var __decorateParam = (index, decorator) => (target, key) => decorator(target, key, index)
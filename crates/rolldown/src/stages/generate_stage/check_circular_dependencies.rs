@@ -0,0 +1,72 @@
+use rolldown_common::{
+  CircularDependencyCheck, CircularDependencyMode, EcmaModuleAstUsage, ModuleIdx, WrapKind,
+};
+use rolldown_error::{BuildDiagnostic, CircularDependencyImport};
+use rustc_hash::FxHashSet;
+
+use super::GenerateStage;
+use crate::chunk_graph::ChunkGraph;
+
+impl GenerateStage<'_> {
+  /// Report the cycles found while sorting modules. This happens after code splitting, since whether a cycle
+  /// is unsafe depends on the chunks its modules are placed in.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn check_circular_dependencies(&mut self, chunk_graph: &ChunkGraph) {
+    let Some(options) =
+      self.options.checks.circular_dependency.as_ref().and_then(CircularDependencyCheck::options)
+    else {
+      return;
+    };
+    let mode = options.mode.unwrap_or_default();
+
+    for cycle in std::mem::take(&mut self.link_output.circular_dependencies) {
+      let diagnostic = self.circular_dependency_diagnostic(&cycle);
+      let is_error = match mode {
+        CircularDependencyMode::Warn => false,
+        CircularDependencyMode::ErrorOnUnsafe => self.is_unsafe_cycle(&cycle, chunk_graph),
+        CircularDependencyMode::Error => true,
+      };
+      if is_error {
+        self.link_output.errors.push(diagnostic);
+      } else {
+        self.link_output.warnings.push(diagnostic.with_severity_warning());
+      }
+    }
+  }
+
+  fn circular_dependency_diagnostic(&self, cycle: &[ModuleIdx]) -> BuildDiagnostic {
+    let modules = &self.link_output.module_table.modules;
+    let paths = cycle.iter().chain(cycle.first()).map(|id| modules[*id].id().to_string()).collect();
+    let imports = cycle
+      .iter()
+      .zip(cycle.iter().cycle().skip(1))
+      .filter_map(|(importer, importee)| {
+        let importer = modules[*importer].as_normal()?;
+        let rec = importer.import_records.iter().find(|rec| {
+          rec.kind.is_static() && rec.resolved_module == *importee && !rec.is_unspanned()
+        })?;
+        Some(CircularDependencyImport {
+          filename: importer.stable_id.to_string(),
+          source: importer.source.clone(),
+          span: rec.span,
+          importee: modules[*importee].id().to_string(),
+        })
+      })
+      .collect();
+    BuildDiagnostic::circular_dependency(paths, imports)
+  }
+
+  /// A cycle is unsafe if it crosses chunk boundaries, or contains modules that are wrapped or use top-level
+  /// await. The execution order of such modules could differ from the order in the source.
+  fn is_unsafe_cycle(&self, cycle: &[ModuleIdx], chunk_graph: &ChunkGraph) -> bool {
+    let chunks =
+      cycle.iter().filter_map(|id| chunk_graph.module_to_chunk[*id]).collect::<FxHashSet<_>>();
+    chunks.len() > 1
+      || cycle.iter().any(|id| {
+        !matches!(self.link_output.metas[*id].wrap_kind, WrapKind::None)
+          || self.link_output.module_table.modules[*id]
+            .as_normal()
+            .is_some_and(|module| module.ast_usage.contains(EcmaModuleAstUsage::TopLevelAwait))
+      })
+  }
+}
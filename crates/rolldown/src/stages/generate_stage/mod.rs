@@ -1,7 +1,10 @@
 use std::collections::hash_map::Entry;
 
 use arcstr::ArcStr;
-use oxc::ast::VisitMut;
+use oxc::{
+  ast::VisitMut,
+  minifier::{CompressOptions, Compressor},
+};
 use oxc_index::IndexVec;
 use rolldown_ecmascript_utils::AstSnippet;
 use rolldown_error::BuildResult;
@@ -41,10 +44,11 @@ use crate::{
   BundleOutput, SharedOptions,
 };
 
+mod check_circular_dependencies;
 mod code_splitting;
 mod compute_cross_chunk_links;
-mod minify_assets;
 mod render_chunk_to_assets;
+mod resolve_plugin_replacements;
 
 pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
@@ -69,12 +73,14 @@ impl<'a> GenerateStage<'a> {
     if chunk_graph.chunk_table.len() > 1 {
       validate_options_for_multi_chunk_output(self.options)?;
     }
+    self.check_circular_dependencies(&chunk_graph);
 
     self.compute_cross_chunk_links(&mut chunk_graph);
 
     let index_chunk_id_to_name =
       self.generate_chunk_name_and_preliminary_filenames(&mut chunk_graph).await?;
     self.patch_asset_modules(&chunk_graph);
+    let plugin_replacements = self.resolve_plugin_replacements(&chunk_graph).await?;
 
     chunk_graph.chunk_table.par_iter_mut().for_each(|chunk| {
       deconflict_chunk_symbols(
@@ -82,6 +88,7 @@ impl<'a> GenerateStage<'a> {
         self.link_output,
         self.options.format,
         &index_chunk_id_to_name,
+        self.options.minify.mangle,
       );
     });
 
@@ -115,6 +122,7 @@ impl<'a> GenerateStage<'a> {
               options: self.options,
               cur_stmt_index: 0,
               keep_name_statement_to_insert: Vec::new(),
+              plugin_replacements: plugin_replacements.get(&module.idx),
             },
             ast,
           );
@@ -137,6 +145,20 @@ impl<'a> GenerateStage<'a> {
             finalizer.visit_program(oxc_program);
           });
         }
+        // Compressing the finalized module keeps the rendered chunk from being parsed again. The
+        // compressor only rewrites to syntax that is available in ES5, so `options.target` is
+        // respected by lowering the modules in the transform step. Legal comments were extracted from
+        // the module when it was scanned and are appended to the rendered chunk, so the compressor
+        // can't drop them.
+        if self.options.minify.compress {
+          ast.program.with_mut(|fields| {
+            let options = CompressOptions {
+              drop_console: self.options.minify.drop_console,
+              drop_debugger: self.options.minify.drop_debugger,
+            };
+            Compressor::new(fields.allocator, options).build(fields.program);
+          });
+        }
       });
 
     self.render_chunk_to_assets(&mut chunk_graph).await
@@ -290,6 +312,14 @@ impl<'a> GenerateStage<'a> {
           .absolutize_with(self.options.cwd.join(&self.options.out_dir))
           .expect_into_string(),
       );
+      if let ChunkKind::EntryPoint { module, .. } = chunk.kind {
+        // Plugins may reference emitted chunks before the final filenames are known, e.g. in
+        // `resolve_file_url`. Hash placeholders are replaced along with the ones in the code.
+        self
+          .plugin_driver
+          .file_emitter()
+          .set_chunk_file_name(modules[module].id(), &preliminary_filename.as_str().into());
+      }
       chunk.preliminary_filename = Some(preliminary_filename);
       chunk.css_preliminary_filename = Some(css_preliminary_filename);
     }
@@ -1,10 +1,11 @@
 use std::path::Path;
 
+use arcstr::ArcStr;
 use futures::future::try_join_all;
 use oxc_index::{index_vec, IndexVec};
 use rolldown_common::{
-  Asset, InstantiationKind, ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk,
-  SourceMapType,
+  Asset, ChunkKind, InstantiationKind, LegalComments, ModuleRenderArgs, ModuleRenderOutput, Output,
+  OutputAsset, OutputChunk, SourceMapType, StrOrBytes,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_utils::{
@@ -43,16 +44,40 @@ impl<'a> GenerateStage<'a> {
 
     render_chunks(self.plugin_driver, &mut instantiated_chunks, self.options).await?;
 
+    // Legal comments are appended before hashing, so the hash of the chunk covers them.
+    for chunk in &mut instantiated_chunks {
+      if let (InstantiationKind::Ecma(ecma_meta), StrOrBytes::Str(code)) =
+        (&chunk.kind, &mut chunk.content)
+      {
+        if !ecma_meta.legal_comments.is_empty() {
+          self.append_legal_comments(
+            code,
+            &ecma_meta.rendered_chunk.filename,
+            &ecma_meta.legal_comments,
+          );
+        }
+      }
+    }
+
     augment_chunk_hash(self.plugin_driver, &mut instantiated_chunks).await?;
 
-    let mut assets = finalize_assets(
+    let assets = finalize_assets(
       chunk_graph,
       instantiated_chunks,
       &index_chunk_to_assets,
       self.options.hash_characters,
     );
 
-    self.minify_assets(&mut assets)?;
+    // Plugins get the filenames of emitted chunks via `PluginContext::get_file_name`.
+    let file_emitter = self.plugin_driver.file_emitter();
+    for asset in &assets {
+      if let (ChunkKind::EntryPoint { module, .. }, InstantiationKind::Ecma(_)) =
+        (&chunk_graph.chunk_table[asset.origin_chunk].kind, &asset.meta)
+      {
+        let module_id = self.link_output.module_table.modules[*module].id();
+        file_emitter.set_chunk_file_name(module_id, &ArcStr::from(asset.filename.as_str()));
+      }
+    }
 
     let mut output = Vec::with_capacity(assets.len());
     let mut output_assets = vec![];
@@ -69,6 +94,18 @@ impl<'a> GenerateStage<'a> {
       if let InstantiationKind::Ecma(ecma_meta) = rendered_chunk {
         let mut code = code.try_into_string()?;
         let rendered_chunk = ecma_meta.rendered_chunk;
+        if !ecma_meta.legal_comments.is_empty()
+          && matches!(self.options.legal_comments, LegalComments::Linked | LegalComments::External)
+        {
+          let mut source = ecma_meta.legal_comments.join("\n");
+          source.push('\n');
+          output_assets.push(Output::Asset(Box::new(OutputAsset {
+            filename: concat_string!(rendered_chunk.filename, ".LEGAL.txt").into(),
+            source: source.into(),
+            original_file_names: vec![],
+            names: vec![],
+          })));
+        }
         if let Some(map) = map.as_mut() {
           let file_base_name =
             Path::new(rendered_chunk.filename.as_str()).file_name().expect("should have file name");
@@ -197,6 +234,7 @@ impl<'a> GenerateStage<'a> {
       assets: output,
       warnings,
       watch_files: self.plugin_driver.watch_files.iter().map(|f| f.clone()).collect(),
+      plugin_timings: None,
     })
   }
 
@@ -285,6 +323,40 @@ impl<'a> GenerateStage<'a> {
     Ok((index_preliminary_assets, index_chunk_to_assets))
   }
 
+  /// Move the extracted legal comments to the end of the chunk or point to the sibling `.LEGAL.txt`
+  /// file, which is emitted once the filename of the chunk is final.
+  fn append_legal_comments(
+    &self,
+    code: &mut String,
+    chunk_filename: &str,
+    legal_comments: &[String],
+  ) {
+    match self.options.legal_comments {
+      LegalComments::Eof => {
+        if !code.is_empty() && !code.ends_with('\n') {
+          code.push('\n');
+        }
+        for comment in legal_comments {
+          code.push_str(comment);
+          code.push('\n');
+        }
+      }
+      LegalComments::Linked => {
+        if !code.is_empty() && !code.ends_with('\n') {
+          code.push('\n');
+        }
+        // The hash placeholder of `chunk_filename` is replaced along with the rest of the content.
+        let legal_filename = concat_string!(chunk_filename, ".LEGAL.txt");
+        let basename =
+          Path::new(&legal_filename).file_name().expect("should have file name").to_string_lossy();
+        code.push_str("/*! For license information please see ");
+        code.push_str(&basename);
+        code.push_str(" */\n");
+      }
+      LegalComments::External | LegalComments::None | LegalComments::Inline => {}
+    }
+  }
+
   /// Create a IndexVecMap from chunk index to related modules codegen return list.
   /// e.g.
   /// modules of chunk1: [ecma1, ecma2, external1]
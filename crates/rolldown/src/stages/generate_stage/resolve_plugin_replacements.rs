@@ -0,0 +1,163 @@
+use oxc::{allocator::Allocator, parser::Parser, span::SourceType, span::Span};
+use rolldown_common::{ImportKind, Module, ModuleIdx, OutputFormat, Platform};
+use rolldown_error::BuildResult;
+use rolldown_plugin::{
+  HookRenderDynamicImportArgs, HookResolveFileUrlArgs, HookResolveImportMetaArgs,
+};
+use rolldown_std_utils::PathExt;
+use rolldown_utils::concat_string;
+use rustc_hash::FxHashMap;
+use sugar_path::SugarPath;
+
+use crate::chunk_graph::ChunkGraph;
+
+use super::GenerateStage;
+
+/// Replacement code of expressions, keyed by the `Span` of the replaced expression.
+pub type PluginReplacements = FxHashMap<ModuleIdx, FxHashMap<Span, String>>;
+
+impl GenerateStage<'_> {
+  /// Calls the `resolve_file_url`, `resolve_import_meta` and `render_dynamic_import` hooks ahead of module
+  /// finalization, since the hooks are async and finalizers are not.
+  #[allow(clippy::too_many_lines)]
+  pub async fn resolve_plugin_replacements(
+    &self,
+    chunk_graph: &ChunkGraph,
+  ) -> BuildResult<PluginReplacements> {
+    let mut replacements = PluginReplacements::default();
+    if !self.options.format.requires_scope_hoisting() {
+      return Ok(replacements);
+    }
+    let format = self.options.format;
+    let file_emitter = self.plugin_driver.file_emitter();
+    let out_dir = self.options.cwd.join(&self.options.out_dir);
+
+    for module in self.link_output.module_table.modules.iter().filter_map(Module::as_normal) {
+      if !module.meta.is_included() {
+        continue;
+      }
+      let Some(chunk_idx) = chunk_graph.module_to_chunk[module.idx] else {
+        continue;
+      };
+      let chunk = &chunk_graph.chunk_table[chunk_idx];
+      let chunk_id = chunk.preliminary_filename.as_ref().expect("should have filename").as_str();
+      let chunk_dir = chunk
+        .absolute_preliminary_filename
+        .as_ref()
+        .expect("should have filename")
+        .as_path()
+        .parent()
+        .expect("should have parent dir");
+      let mut module_replacements = FxHashMap::default();
+
+      for (span, property) in &module.import_meta_references {
+        let property = property.as_deref();
+        if property == Some("__ROLLDOWN_ASSET_FILENAME") {
+          continue;
+        }
+        if let Some(reference_id) = property.and_then(|p| p.strip_prefix("ROLLUP_FILE_URL_")) {
+          let file_name =
+            file_emitter.try_get_file_name(reference_id).map_err(anyhow::Error::msg)?;
+          let relative_path = out_dir.join(file_name.as_str()).relative(chunk_dir);
+          let relative_path = relative_path.as_path().expect_to_slash();
+          let code = self
+            .plugin_driver
+            .resolve_file_url(&HookResolveFileUrlArgs {
+              reference_id,
+              file_name: &file_name,
+              relative_path: &relative_path,
+              module_id: &module.id,
+              chunk_id,
+              format,
+            })
+            .await?
+            .unwrap_or_else(|| self.default_file_url(&relative_path));
+          module_replacements.insert(*span, code);
+        } else if let Some(code) = self
+          .plugin_driver
+          .resolve_import_meta(&HookResolveImportMetaArgs {
+            property,
+            module_id: &module.id,
+            chunk_id,
+            format,
+          })
+          .await?
+        {
+          module_replacements.insert(*span, code);
+        }
+      }
+
+      if !self.options.inline_dynamic_imports {
+        for (span, rec_idx) in &module.imports {
+          let rec = &module.import_records[*rec_idx];
+          if rec.kind != ImportKind::DynamicImport {
+            continue;
+          }
+          let (target_module_id, target_chunk, import_path) =
+            match &self.link_output.module_table.modules[rec.resolved_module] {
+              Module::Normal(importee) => {
+                // The `import()` is in tree-shaken code
+                let Some(&importee_chunk_idx) =
+                  chunk_graph.entry_module_to_entry_chunk.get(&importee.idx)
+                else {
+                  continue;
+                };
+                let importee_chunk = &chunk_graph.chunk_table[importee_chunk_idx];
+                (importee.id.as_ref(), Some(importee_chunk), chunk.import_path_for(importee_chunk))
+              }
+              Module::External(importee) => {
+                (importee.name.as_str(), None, importee.name.to_string())
+              }
+            };
+          if let Some(output) = self
+            .plugin_driver
+            .render_dynamic_import(&HookRenderDynamicImportArgs {
+              module_id: &module.id,
+              target_module_id,
+              chunk_id,
+              target_chunk_id: target_chunk
+                .and_then(|c| c.preliminary_filename.as_ref())
+                .map(|filename| filename.as_str()),
+              format,
+            })
+            .await?
+          {
+            module_replacements
+              .insert(*span, concat_string!(output.left, "\"", import_path, "\"", output.right));
+          }
+        }
+      }
+
+      for code in module_replacements.values() {
+        let allocator = Allocator::default();
+        if Parser::new(&allocator, code, SourceType::mjs()).parse_expression().is_err() {
+          Err(anyhow::format_err!(
+            "Plugins returned invalid code `{code}` to replace an expression of {}",
+            module.stable_id
+          ))?;
+        }
+      }
+      if !module_replacements.is_empty() {
+        replacements.insert(module.idx, module_replacements);
+      }
+    }
+    Ok(replacements)
+  }
+
+  /// The code of `import.meta.ROLLUP_FILE_URL_<referenceId>` if no plugin resolves it.
+  fn default_file_url(&self, relative_path: &str) -> String {
+    match (self.options.format, self.options.platform) {
+      (OutputFormat::Esm, _) => {
+        concat_string!("new URL(\"", relative_path, "\", import.meta.url).href")
+      }
+      (OutputFormat::Cjs, Platform::Node) => {
+        concat_string!("require(\"url\").pathToFileURL(__dirname + \"/", relative_path, "\").href")
+      }
+      _ => concat_string!(
+        "new URL(\"",
+        relative_path,
+        "\", document.currentScript && document.currentScript.src || document.baseURI).href"
+      ),
+    }
+  }
+}
@@ -31,7 +31,12 @@ impl LinkStage<'_> {
         return;
       }
       let default_symbol_ref = module.default_export_ref;
-      let is_json = matches!(module.module_type, ModuleType::Json);
+      // Yaml and toml are loaded as json. Properties of object literals of lazy exported custom module types
+      // are exported like json too.
+      let is_json = matches!(
+        module.module_type,
+        ModuleType::Json | ModuleType::Yaml | ModuleType::Toml | ModuleType::Custom(_)
+      );
       if !is_json || module.exports_kind == ExportsKind::CommonJs {
         update_module_default_export_info(module, default_symbol_ref, 1.into());
       }
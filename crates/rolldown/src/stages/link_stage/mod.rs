@@ -30,7 +30,9 @@ use super::scan_stage::ScanStageOutput;
 
 mod bind_imports_and_exports;
 mod generate_lazy_export;
+mod side_effect_free_functions;
 mod sort_modules;
+pub(crate) mod splitting_info;
 pub(crate) mod tree_shaking;
 mod wrapping;
 
@@ -47,6 +49,8 @@ pub struct LinkStageOutput {
   pub errors: Vec<BuildDiagnostic>,
   pub used_symbol_refs: FxHashSet<SymbolRef>,
   pub dynamic_import_exports_usage_map: FxHashMap<ModuleIdx, DynamicImportExportsUsage>,
+  /// Cycles found by `checks.circularDependency`. They are reported once the chunk graph is known.
+  pub circular_dependencies: Vec<Box<[ModuleIdx]>>,
 }
 
 #[derive(Debug)]
@@ -61,6 +65,7 @@ pub struct LinkStage<'a> {
   pub errors: Vec<BuildDiagnostic>,
   pub ast_table: IndexEcmaAst,
   pub options: &'a SharedOptions,
+  pub circular_dependencies: Vec<Box<[ModuleIdx]>>,
   pub used_symbol_refs: FxHashSet<SymbolRef>,
   pub dynamic_import_exports_usage_map: FxHashMap<ModuleIdx, DynamicImportExportsUsage>,
 }
@@ -107,6 +112,7 @@ impl<'a> LinkStage<'a> {
       dynamic_import_exports_usage_map: scan_stage_output.dynamic_import_exports_usage_map,
       options,
       used_symbol_refs: FxHashSet::default(),
+      circular_dependencies: vec![],
     }
   }
 
@@ -117,6 +123,7 @@ impl<'a> LinkStage<'a> {
     self.determine_module_exports_kind();
     self.wrap_modules();
     self.generate_lazy_export();
+    self.propagate_side_effect_free_functions();
     self.determine_side_effects();
     self.bind_imports_and_exports();
     self.create_exports_for_ecma_modules();
@@ -137,6 +144,7 @@ impl<'a> LinkStage<'a> {
       ast_table: self.ast_table,
       used_symbol_refs: self.used_symbol_refs,
       dynamic_import_exports_usage_map: self.dynamic_import_exports_usage_map,
+      circular_dependencies: self.circular_dependencies,
     }
   }
 
@@ -227,7 +235,14 @@ impl<'a> LinkStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   fn reference_needed_symbols(&mut self) {
     let symbols = Mutex::new(&mut self.symbols);
-    let keep_names = self.options.keep_names;
+    // Functions and classes whose names are kept are followed by a `__name` helper call.
+    let mut keep_names_meta = StmtInfoMeta::empty();
+    if self.options.minify.keep_fnames {
+      keep_names_meta |= StmtInfoMeta::FnDecl | StmtInfoMeta::FnExpr;
+    }
+    if self.options.minify.keep_classnames {
+      keep_names_meta |= StmtInfoMeta::ClassDecl | StmtInfoMeta::ClassExpr;
+    }
     let record_meta_update_pending_pairs_list = self
       .module_table
       .modules
@@ -434,7 +449,7 @@ impl<'a> LinkStage<'a> {
               }
             }
           });
-          if keep_names && stmt_info.meta.intersects(StmtInfoMeta::KeepNamesType) {
+          if stmt_info.meta.intersects(keep_names_meta) {
             stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__name").into());
           }
         });
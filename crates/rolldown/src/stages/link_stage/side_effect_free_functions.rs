@@ -0,0 +1,127 @@
+use rolldown_common::{
+  side_effects::DeterminedSideEffects, GetLocalDb, IndexModules, Module, ModuleIdx, ModuleType,
+  NamedImport, Specifier, SymbolRef, SymbolRefFlags,
+};
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashSet;
+
+use crate::ast_scanner::side_effect_detector::SideEffectDetector;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Functions annotated with `@__NO_SIDE_EFFECTS__` are only known to the module declaring them after
+  /// scanning. Mark the bindings importing them as well, then re-analyze the statements of the importers that
+  /// were considered side-effectful, since they might only be calling such functions.
+  pub(super) fn propagate_side_effect_free_functions(&mut self) {
+    if !self.options.treeshake.annotations() {
+      return;
+    }
+
+    let modules = &self.module_table.modules;
+    let imported_functions = modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .flat_map(|module| {
+        module.named_imports.iter().filter_map(|(local, named_import)| {
+          let Specifier::Literal(imported) = &named_import.imported else {
+            return None;
+          };
+          let importee = module.import_records[named_import.record_id].resolved_module;
+          let target = resolve_export(modules, importee, imported, &mut FxHashSet::default())?;
+          target
+            .flags(&self.symbols)
+            .is_some_and(|flags| flags.contains(SymbolRefFlags::SIDE_EFFECT_FREE_FUNCTION))
+            .then_some(*local)
+        })
+      })
+      .collect::<Vec<_>>();
+
+    if imported_functions.is_empty() {
+      return;
+    }
+
+    let mut affected_modules = FxHashSet::default();
+    for local in imported_functions {
+      local.flags_mut(&mut self.symbols).insert(SymbolRefFlags::SIDE_EFFECT_FREE_FUNCTION);
+      affected_modules.insert(local.owner);
+    }
+
+    for module_idx in affected_modules {
+      let Module::Normal(module) = &mut self.module_table.modules[module_idx] else {
+        continue;
+      };
+      let (ast, _) = &self.ast_table[module.ecma_ast_idx()];
+      let mut detector = SideEffectDetector::new(
+        &module.ecma_view.scope,
+        ast.source(),
+        ast.comments(),
+        false,
+        self.options.jsx.is_jsx_preserve(),
+        self.symbols.local_db(module_idx),
+      );
+      for stmt_info in module.ecma_view.stmt_infos.iter_mut() {
+        let Some(stmt_idx) = stmt_info.stmt_idx else {
+          continue;
+        };
+        if stmt_info.side_effect {
+          stmt_info.side_effect =
+            detector.detect_side_effect_of_stmt(&ast.program().body[stmt_idx]);
+        }
+      }
+
+      // Only the analyzed side effects are derived from the statements.
+      let has_fixed_side_effects = match &module.module_type {
+        ModuleType::Css => true,
+        ModuleType::Custom(custom_type) => self
+          .options
+          .custom_module_types
+          .get(custom_type)
+          .is_some_and(|handler| handler.side_effects().is_some()),
+        _ => false,
+      };
+      if matches!(module.ecma_view.side_effects, DeterminedSideEffects::Analyzed(true))
+        && !has_fixed_side_effects
+      {
+        module.ecma_view.side_effects = DeterminedSideEffects::Analyzed(
+          module.ecma_view.stmt_infos.iter().any(|stmt_info| stmt_info.side_effect),
+        );
+      }
+    }
+  }
+}
+
+/// Find the symbol declaring the export `name` of `module_idx`, following re-exports and `export *`.
+fn resolve_export<'a>(
+  modules: &'a IndexModules,
+  module_idx: ModuleIdx,
+  name: &'a Rstr,
+  visited: &mut FxHashSet<(ModuleIdx, &'a Rstr)>,
+) -> Option<SymbolRef> {
+  if !visited.insert((module_idx, name)) {
+    return None;
+  }
+  let module = modules[module_idx].as_normal()?;
+  if let Some(local_export) = module.named_exports.get(name) {
+    let local = local_export.referenced;
+    return match module.named_imports.get(&local) {
+      Some(NamedImport { imported: Specifier::Literal(imported), record_id, .. }) => {
+        resolve_export(
+          modules,
+          module.import_records[*record_id].resolved_module,
+          imported,
+          visited,
+        )
+      }
+      Some(_) => None,
+      None => Some(local),
+    };
+  }
+  // `export *` never re-exports `default`
+  if name.as_str() == "default" {
+    return None;
+  }
+  module
+    .star_export_module_ids()
+    .find_map(|importee| resolve_export(modules, importee, name, visited))
+}
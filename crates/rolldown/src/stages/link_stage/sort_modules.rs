@@ -1,7 +1,6 @@
 use std::iter;
 
-use rolldown_common::{Module, ModuleIdx};
-use rolldown_error::BuildDiagnostic;
+use rolldown_common::{CircularDependencyCheck, Module, ModuleIdx};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::LinkStage;
@@ -45,12 +44,14 @@ impl LinkStage<'_> {
 
     let mut sorted_modules = Vec::with_capacity(self.module_table.modules.len());
     let mut next_exec_order = 0;
+    let circular_dependency_options =
+      self.options.checks.circular_dependency.as_ref().and_then(CircularDependencyCheck::options);
     let mut circular_dependencies = FxHashSet::default();
     while let Some(status) = execution_stack.pop() {
       match status {
         Status::ToBeExecuted(id) => {
           if executed_ids.contains(&id) {
-            if self.options.checks.circular_dependency.unwrap_or(false) {
+            if circular_dependency_options.is_some() {
               // Try to check if there is a circular dependency
               if let Some(index) = stack_indexes_of_executing_id.get(&id).copied() {
                 // Executing
@@ -61,9 +62,8 @@ impl LinkStage<'_> {
                     Status::ToBeExecuted(_) => None,
                     Status::WaitForExit(id) => Some(*id),
                   })
-                  .chain(iter::once(id))
-                  .collect::<Box<[_]>>();
-                circular_dependencies.insert(cycles);
+                  .collect::<Vec<_>>();
+                circular_dependencies.insert(normalize_cycle(cycles));
               }
             }
             // It's already executed in other import chain, no need to execute again
@@ -107,17 +107,20 @@ impl LinkStage<'_> {
       }
     }
 
-    if !circular_dependencies.is_empty() {
-      let cycles = circular_dependencies.into_iter().collect::<Vec<_>>();
-      for cycle in cycles {
-        let paths = cycle
+    if let Some(options) = circular_dependency_options {
+      let mut cycles = circular_dependencies
+        .into_iter()
+        .filter(|cycle| {
+          !cycle.iter().any(|id| options.is_ignored(self.module_table.modules[*id].stable_id()))
+        })
+        .collect::<Vec<_>>();
+      cycles.sort_by_cached_key(|cycle| {
+        cycle
           .iter()
-          .copied()
-          .filter_map(|id| self.module_table.modules[id].as_normal())
-          .map(|module| module.id.to_string())
-          .collect::<Vec<_>>();
-        self.warnings.push(BuildDiagnostic::circular_dependency(paths).with_severity_warning());
-      }
+          .map(|id| self.module_table.modules[*id].stable_id().to_string())
+          .collect::<Vec<_>>()
+      });
+      self.circular_dependencies = cycles;
     }
 
     self.sorted_modules = sorted_modules;
@@ -128,3 +131,12 @@ impl LinkStage<'_> {
     );
   }
 }
+
+/// Rotate the cycle to start from its smallest module, so the same cycle found from different modules is
+/// only reported once.
+fn normalize_cycle(mut cycle: Vec<ModuleIdx>) -> Box<[ModuleIdx]> {
+  if let Some(start) = cycle.iter().enumerate().min_by_key(|(_, id)| **id).map(|(index, _)| index) {
+    cycle.rotate_left(start);
+  }
+  cycle.into_boxed_slice()
+}
@@ -0,0 +1,250 @@
+use std::cmp::Reverse;
+
+use arcstr::ArcStr;
+use oxc_index::IndexVec;
+use rolldown_common::{
+  EntryPoint, Module, ModuleIdx, ModuleTable, NormalModule, NormalizedBundlerOptions,
+};
+use rolldown_utils::BitSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::types::linking_metadata::LinkingMetadataVec;
+
+#[derive(Clone)]
+pub struct SplittingInfo {
+  pub bits: BitSet,
+  pub share_count: u32,
+}
+
+pub type IndexSplittingInfo = IndexVec<ModuleIdx, SplittingInfo>;
+
+/// Compute which entries reach each module. Modules rejected by `is_included`, and modules only reachable
+/// through them, aren't reached by any entry.
+pub fn compute_splitting_info(
+  entries: &[EntryPoint],
+  module_table: &ModuleTable,
+  metas: &LinkingMetadataVec,
+  is_included: &dyn Fn(&NormalModule) -> bool,
+) -> IndexSplittingInfo {
+  fn determine_reachable_modules_for_entry(
+    module_id: ModuleIdx,
+    entry_index: u32,
+    module_table: &ModuleTable,
+    metas: &LinkingMetadataVec,
+    is_included: &dyn Fn(&NormalModule) -> bool,
+    index_splitting_info: &mut IndexSplittingInfo,
+  ) {
+    let Module::Normal(module) = &module_table.modules[module_id] else {
+      return;
+    };
+
+    if !is_included(module) {
+      return;
+    }
+
+    if index_splitting_info[module_id].bits.has_bit(entry_index) {
+      return;
+    }
+
+    index_splitting_info[module_id].bits.set_bit(entry_index);
+    index_splitting_info[module_id].share_count += 1;
+
+    metas[module_id].dependencies.iter().copied().for_each(|dep_idx| {
+      determine_reachable_modules_for_entry(
+        dep_idx,
+        entry_index,
+        module_table,
+        metas,
+        is_included,
+        index_splitting_info,
+      );
+    });
+  }
+
+  let entries_len: u32 = entries.len().try_into().expect("Too many entries, u32 overflowed.");
+  let mut index_splitting_info: IndexSplittingInfo = oxc_index::index_vec![SplittingInfo {
+      bits: BitSet::new(entries_len),
+      share_count: 0
+    }; module_table.modules.len()];
+  entries.iter().enumerate().for_each(|(i, entry_point)| {
+    determine_reachable_modules_for_entry(
+      entry_point.id,
+      i.try_into().expect("Too many entries, u32 overflowed."),
+      module_table,
+      metas,
+      is_included,
+      &mut index_splitting_info,
+    );
+  });
+  index_splitting_info
+}
+
+/// A group of modules that `advancedChunks` places in its own chunk.
+pub struct ModuleGroup {
+  pub name: ArcStr,
+  match_group_index: usize,
+  pub modules: FxHashSet<ModuleIdx>,
+  priority: u32,
+  sizes: f64,
+}
+
+impl ModuleGroup {
+  #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+  fn add_module(&mut self, module_idx: ModuleIdx, module_table: &ModuleTable) {
+    if self.modules.insert(module_idx) {
+      self.sizes += module_table.modules[module_idx].size() as f64;
+    }
+  }
+
+  #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+  fn remove_module(&mut self, module_idx: ModuleIdx, module_table: &ModuleTable) {
+    if self.modules.remove(&module_idx) {
+      self.sizes -= module_table.modules[module_idx].size() as f64;
+      self.sizes = f64::max(self.sizes, 0.0);
+    }
+  }
+}
+
+/// Compute the groups of `advancedChunks` in the order their chunks are created. A module belongs to at most
+/// one of the returned groups.
+#[allow(clippy::too_many_lines)] // TODO(hyf0): refactor
+pub fn compute_advanced_chunk_groups(
+  options: &NormalizedBundlerOptions,
+  module_table: &ModuleTable,
+  metas: &LinkingMetadataVec,
+  index_splitting_info: &IndexSplittingInfo,
+  is_included: &dyn Fn(&NormalModule) -> bool,
+) -> Vec<ModuleGroup> {
+  fn add_module_and_dependencies_to_group_recursively(
+    module_group: &mut ModuleGroup,
+    module_idx: ModuleIdx,
+    module_metas: &LinkingMetadataVec,
+    module_table: &ModuleTable,
+    is_included: &dyn Fn(&NormalModule) -> bool,
+    visited: &mut FxHashSet<ModuleIdx>,
+  ) {
+    let is_visited = !visited.insert(module_idx);
+
+    if is_visited {
+      return;
+    }
+
+    let Module::Normal(module) = &module_table.modules[module_idx] else {
+      return;
+    };
+
+    if !is_included(module) {
+      return;
+    }
+
+    module_group.add_module(module_idx, module_table);
+
+    for dep in &module_metas[module_idx].dependencies {
+      add_module_and_dependencies_to_group_recursively(
+        module_group,
+        *dep,
+        module_metas,
+        module_table,
+        is_included,
+        visited,
+      );
+    }
+  }
+
+  oxc_index::define_index_type! {
+    pub struct ModuleGroupIdx = u32;
+  }
+
+  let Some(chunking_options) = &options.advanced_chunks else {
+    return vec![];
+  };
+
+  let Some(match_groups) =
+    chunking_options.groups.as_ref().map(|inner| inner.iter().collect::<Vec<_>>())
+  else {
+    return vec![];
+  };
+
+  if match_groups.is_empty() {
+    return vec![];
+  }
+
+  let mut index_module_groups: IndexVec<ModuleGroupIdx, ModuleGroup> = IndexVec::new();
+  let mut name_to_module_group: FxHashMap<ArcStr, ModuleGroupIdx> = FxHashMap::default();
+
+  for normal_module in module_table.modules.iter().filter_map(Module::as_normal) {
+    if !is_included(normal_module) {
+      continue;
+    }
+
+    let splitting_info = &index_splitting_info[normal_module.idx];
+
+    for (match_group_index, match_group) in match_groups.iter().copied().enumerate() {
+      let is_matched =
+        match_group.test.as_ref().map_or(true, |test| test.matches(&normal_module.id));
+
+      if !is_matched {
+        continue;
+      }
+
+      if let Some(allow_min_share_count) =
+        match_group.min_share_count.map_or(chunking_options.min_share_count, Some)
+      {
+        if splitting_info.share_count < allow_min_share_count {
+          continue;
+        }
+      }
+
+      let group_name = ArcStr::from(&match_group.name);
+
+      let module_group_idx = name_to_module_group.entry(group_name.clone()).or_insert_with(|| {
+        index_module_groups.push(ModuleGroup {
+          modules: FxHashSet::default(),
+          match_group_index,
+          priority: match_group.priority.unwrap_or(0),
+          name: group_name.clone(),
+          sizes: 0.0,
+        })
+      });
+
+      add_module_and_dependencies_to_group_recursively(
+        &mut index_module_groups[*module_group_idx],
+        normal_module.idx,
+        metas,
+        module_table,
+        is_included,
+        &mut FxHashSet::default(),
+      );
+    }
+  }
+
+  let mut module_groups = index_module_groups.raw;
+  module_groups.sort_unstable_by_key(|item| item.match_group_index);
+  module_groups.sort_by_key(|item| Reverse(item.priority));
+  module_groups.reverse();
+  // These two sort ensure higher priority group goes first. If two groups have the same priority, the one with the lower index goes first.
+
+  let mut valid_module_groups = vec![];
+  while let Some(this_module_group) = module_groups.pop() {
+    if this_module_group.modules.is_empty() {
+      continue;
+    }
+
+    if let Some(allow_min_size) = match_groups[this_module_group.match_group_index]
+      .min_size
+      .map_or(chunking_options.min_size, Some)
+    {
+      if this_module_group.sizes < allow_min_size {
+        continue;
+      }
+    }
+
+    this_module_group.modules.iter().copied().for_each(|module_idx| {
+      module_groups.iter_mut().for_each(|group| {
+        group.remove_module(module_idx, module_table);
+      });
+    });
+    valid_module_groups.push(this_module_group);
+  }
+  valid_module_groups
+}
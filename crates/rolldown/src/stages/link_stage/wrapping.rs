@@ -1,12 +1,15 @@
 use oxc_index::IndexVec;
 use rolldown_common::{
-  ExportsKind, IndexModules, Module, ModuleIdx, NormalModule, NormalizedBundlerOptions,
-  RuntimeModuleBrief, StmtInfo, StmtInfoMeta, SymbolRefDb, WrapKind,
+  EntryPoint, ExportsKind, IndexModules, Module, ModuleIdx, ModuleTable, NormalModule,
+  NormalizedBundlerOptions, RuntimeModuleBrief, StmtInfo, StmtInfoMeta, SymbolRefDb, WrapKind,
 };
 
 use crate::types::linking_metadata::{LinkingMetadata, LinkingMetadataVec};
 
-use super::LinkStage;
+use super::{
+  splitting_info::{compute_advanced_chunk_groups, compute_splitting_info},
+  LinkStage,
+};
 
 struct Context<'a> {
   pub visited_modules: &'a mut IndexVec<ModuleIdx, bool>,
@@ -73,12 +76,53 @@ fn has_dynamic_exports_due_to_export_star(
   linking_infos[target].has_dynamic_exports
 }
 
+/// Find the modules that code splitting is going to place in common chunks, i.e. modules reached by more
+/// than one entry (including dynamic entries) and modules grouped by `advancedChunks`.
+///
+/// Wrapping decides what tree shaking includes, so this runs before it and considers every module
+/// included. A module reached by a second entry only through imports that would be tree-shaken is
+/// therefore wrapped too, and calling its wrapper keeps those imports alive.
+fn compute_modules_in_common_chunks(
+  entries: &[EntryPoint],
+  linking_infos: &LinkingMetadataVec,
+  module_table: &ModuleTable,
+  options: &NormalizedBundlerOptions,
+) -> IndexVec<ModuleIdx, bool> {
+  let is_included = |_: &NormalModule| true;
+  let index_splitting_info =
+    compute_splitting_info(entries, module_table, linking_infos, &is_included);
+  let mut in_common_chunk = index_splitting_info
+    .iter()
+    .map(|info| info.share_count > 1)
+    .collect::<IndexVec<ModuleIdx, _>>();
+  compute_advanced_chunk_groups(
+    options,
+    module_table,
+    linking_infos,
+    &index_splitting_info,
+    &is_included,
+  )
+  .iter()
+  .flat_map(|group| group.modules.iter())
+  .for_each(|module_idx| in_common_chunk[*module_idx] = true);
+  in_common_chunk
+}
+
 impl LinkStage<'_> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn wrap_modules(&mut self) {
     let mut visited_modules_for_wrapping =
       oxc_index::index_vec![false; self.module_table.modules.len()];
 
+    let is_strict_execution_order = self.options.experimental.is_strict_execution_order_enabled();
+    // With `lazy_shared_modules`, modules that will land in a common chunk are wrapped, so they are
+    // initialized by their importers on first use instead of when the common chunk is loaded.
+    let in_common_chunk = (!is_strict_execution_order
+      && self.options.experimental.is_lazy_shared_modules_enabled())
+    .then(|| {
+      compute_modules_in_common_chunks(&self.entries, &self.metas, &self.module_table, self.options)
+    });
+
     let mut visited_modules_for_dynamic_exports =
       oxc_index::index_vec![false; self.module_table.modules.len()];
 
@@ -86,7 +130,8 @@ impl LinkStage<'_> {
       let module_id = module.idx;
       let linking_info = &self.metas[module_id];
 
-      let need_to_wrap = self.options.experimental.is_strict_execution_order_enabled()
+      let need_to_wrap = is_strict_execution_order
+        || in_common_chunk.as_ref().is_some_and(|in_common_chunk| in_common_chunk[module_id])
         || matches!(linking_info.wrap_kind, WrapKind::Cjs | WrapKind::Esm);
 
       if need_to_wrap {
@@ -53,6 +53,7 @@ impl ScanStage {
       Err(anyhow::anyhow!("You must supply options.input to rolldown"))?;
     }
 
+    self.plugin_driver.file_emitter().start_chunk_emission();
     self.plugin_driver.build_start(&self.options).await?;
 
     let module_loader = ModuleLoader::new(
@@ -134,10 +135,14 @@ impl ScanStage {
         }
         Err(e) => match e {
           ResolveError::NotFound(_) => {
-            errors.push(BuildDiagnostic::unresolved_entry(args.specifier, None));
+            errors.push(BuildDiagnostic::unresolved_entry(args.specifier, None, None));
           }
-          ResolveError::PackagePathNotExported(..) => {
-            errors.push(BuildDiagnostic::unresolved_entry(args.specifier, Some(e)));
+          ResolveError::PackagePathNotExported(ref subpath, ref package_json_path) => {
+            let help = self
+              .resolver
+              .package_exports_hint(subpath, package_json_path, ImportKind::Import)
+              .map(|hint| hint.to_help_message());
+            errors.push(BuildDiagnostic::unresolved_entry(args.specifier, Some(e), help));
           }
           _ => return Err(e).map_err_to_unhandleable()?,
         },
@@ -1,10 +1,13 @@
 use arcstr::ArcStr;
 use rolldown_common::Output;
 use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::PluginTimings;
 
 #[derive(Default)]
 pub struct BundleOutput {
   pub warnings: Vec<BuildDiagnostic>,
   pub assets: Vec<Output>,
   pub watch_files: Vec<ArcStr>,
+  /// Only available if `plugin_timings` is enabled.
+  pub plugin_timings: Option<PluginTimings>,
 }
@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use oxc::transformer::ReplaceGlobalDefinesConfig;
 use rolldown_common::{
   side_effects::HookSideEffects, ModuleIdx, ModuleType, ResolvedId, StrOrBytes,
 };
 use rolldown_error::BuildDiagnostic;
+use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
+use rolldown_resolver::TsconfigCompilerOptions;
 use rolldown_sourcemap::SourceMap;
 
 use crate::SharedOptions;
@@ -11,11 +15,13 @@ use crate::SharedOptions;
 pub struct CreateModuleContext<'a> {
   pub module_index: ModuleIdx,
   pub plugin_driver: &'a SharedPluginDriver,
+  pub fs: OsFileSystem,
   pub resolved_id: &'a ResolvedId,
   pub options: &'a SharedOptions,
   pub module_type: ModuleType,
   pub warnings: &'a mut Vec<BuildDiagnostic>,
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
+  pub tsconfig_compiler_options: Option<Arc<TsconfigCompilerOptions>>,
   pub is_user_defined_entry: bool,
 }
 
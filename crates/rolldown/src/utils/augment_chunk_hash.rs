@@ -12,7 +12,14 @@ pub async fn augment_chunk_hash<'a>(
 ) -> Result<()> {
   try_join_all(assets.iter_mut().map(|asset| async move {
     if let InstantiationKind::Ecma(ecma_meta) = &asset.kind {
-      let augment_chunk_hash = plugin_driver.augment_chunk_hash(&ecma_meta.rendered_chunk).await?;
+      let mut augment_chunk_hash =
+        plugin_driver.augment_chunk_hash(&ecma_meta.rendered_chunk).await?;
+      // Legal comments are not part of the content yet, but they affect the final output.
+      if !ecma_meta.legal_comments.is_empty() {
+        augment_chunk_hash
+          .get_or_insert_with(String::new)
+          .push_str(&ecma_meta.legal_comments.concat());
+      }
       if let Some(augment_chunk_hash) = augment_chunk_hash {
         asset.augment_chunk_hash = Some(augment_chunk_hash);
       }
@@ -0,0 +1,192 @@
+use std::io::Write;
+
+use rolldown_common::{Output, OutputChunk, SizeBudgetsOptions, SizeLimit};
+use rolldown_error::{BuildDiagnostic, SizeBudgetExceeded};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// How many of the largest modules are reported for a breach.
+const LARGEST_MODULES_COUNT: usize = 5;
+
+/// Check the sizes of the output against `checks.size_budgets` and return a diagnostic for each breach.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn check_size_budgets(assets: &[Output], budgets: &SizeBudgetsOptions) -> Vec<BuildDiagnostic> {
+  let mut sizes = OutputSizes::new(assets);
+  let mut breaches = vec![];
+
+  if let Some(limit) = &budgets.entry {
+    for (idx, chunk) in chunks(assets).filter(|(_, chunk)| chunk.is_entry) {
+      for (measure, size, limit) in sizes.exceeded(&[idx], limit) {
+        breaches.push(SizeBudgetExceeded {
+          filename: chunk.filename.to_string(),
+          initial_load: None,
+          measure,
+          size,
+          limit,
+          largest_modules: largest_modules(&[chunk]),
+        });
+      }
+    }
+  }
+
+  for budget in budgets.chunks.iter().flatten() {
+    for (idx, output) in assets.iter().enumerate() {
+      if !glob_match::glob_match(&budget.pattern, output.filename()) {
+        continue;
+      }
+      for (measure, size, limit) in sizes.exceeded(&[idx], &budget.limit) {
+        let largest_modules = match output {
+          Output::Chunk(chunk) => largest_modules(&[chunk]),
+          Output::Asset(_) => vec![],
+        };
+        breaches.push(SizeBudgetExceeded {
+          filename: output.filename().to_string(),
+          initial_load: None,
+          measure,
+          size,
+          limit,
+          largest_modules,
+        });
+      }
+    }
+  }
+
+  if let Some(limit) = &budgets.initial {
+    let idx_by_filename = chunks(assets)
+      .map(|(idx, chunk)| (chunk.filename.as_str(), idx))
+      .collect::<FxHashMap<_, _>>();
+    for (idx, chunk) in chunks(assets).filter(|(_, chunk)| chunk.is_entry) {
+      let initial_load = initial_load(assets, idx, &idx_by_filename);
+      for (measure, size, limit) in sizes.exceeded(&initial_load, limit) {
+        let initial_chunks =
+          initial_load.iter().filter_map(|idx| as_chunk(&assets[*idx])).collect::<Vec<_>>();
+        breaches.push(SizeBudgetExceeded {
+          filename: chunk.filename.to_string(),
+          initial_load: Some(
+            initial_chunks.iter().map(|chunk| chunk.filename.to_string()).collect(),
+          ),
+          measure,
+          size,
+          limit,
+          largest_modules: largest_modules(&initial_chunks),
+        });
+      }
+    }
+  }
+
+  breaches.into_iter().map(BuildDiagnostic::size_budget_exceeded).collect()
+}
+
+fn as_chunk(output: &Output) -> Option<&OutputChunk> {
+  match output {
+    Output::Chunk(chunk) => Some(chunk),
+    Output::Asset(_) => None,
+  }
+}
+
+fn chunks(assets: &[Output]) -> impl Iterator<Item = (usize, &OutputChunk)> {
+  assets.iter().enumerate().filter_map(|(idx, output)| as_chunk(output).map(|chunk| (idx, chunk)))
+}
+
+/// The entry chunk and all chunks it imports statically, in the order of visiting.
+fn initial_load(
+  assets: &[Output],
+  entry_idx: usize,
+  idx_by_filename: &FxHashMap<&str, usize>,
+) -> Vec<usize> {
+  let mut visited = FxHashSet::from_iter([entry_idx]);
+  let mut initial_load = vec![entry_idx];
+  let mut cursor = 0;
+  while let Some(idx) = initial_load.get(cursor).copied() {
+    cursor += 1;
+    let Some(chunk) = as_chunk(&assets[idx]) else { continue };
+    for import in &chunk.imports {
+      // Imports of external modules are not part of the output.
+      let Some(&imported_idx) = idx_by_filename.get(import.as_str()) else { continue };
+      if visited.insert(imported_idx) {
+        initial_load.push(imported_idx);
+      }
+    }
+  }
+  initial_load
+}
+
+fn largest_modules(chunks: &[&OutputChunk]) -> Vec<(String, usize)> {
+  let mut modules = chunks
+    .iter()
+    .flat_map(|chunk| {
+      chunk.module_ids.iter().filter_map(|id| {
+        let module = &chunk.modules.value[*chunk.modules.key_to_index.get(id)?];
+        Some((id.to_string(), module.rendered_length()))
+      })
+    })
+    .filter(|(_, size)| *size > 0)
+    .collect::<Vec<_>>();
+  modules.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+  modules.truncate(LARGEST_MODULES_COUNT);
+  modules
+}
+
+/// Computes the sizes of the output lazily, so compressed sizes are only computed if they have a limit.
+struct OutputSizes<'a> {
+  assets: &'a [Output],
+  gzip: FxHashMap<usize, u64>,
+  brotli: FxHashMap<usize, u64>,
+}
+
+impl<'a> OutputSizes<'a> {
+  fn new(assets: &'a [Output]) -> Self {
+    Self { assets, gzip: FxHashMap::default(), brotli: FxHashMap::default() }
+  }
+
+  /// Returns `(measure, size, limit)` for each limit exceeded by the total size of the outputs. Compressed
+  /// sizes are computed for each output separately, since each output is loaded as a separate file.
+  fn exceeded(&mut self, outputs: &[usize], limit: &SizeLimit) -> Vec<(&'static str, u64, u64)> {
+    let mut exceeded = vec![];
+    if let Some(limit) = limit.raw {
+      let size = outputs.iter().map(|idx| self.assets[*idx].content_as_bytes().len() as u64).sum();
+      if size > limit {
+        exceeded.push(("raw", size, limit));
+      }
+    }
+    if let Some(limit) = limit.gzip {
+      let size = outputs
+        .iter()
+        .map(|idx| {
+          *self.gzip.entry(*idx).or_insert_with(|| gzip_size(self.assets[*idx].content_as_bytes()))
+        })
+        .sum();
+      if size > limit {
+        exceeded.push(("gzip", size, limit));
+      }
+    }
+    if let Some(limit) = limit.brotli {
+      let size = outputs
+        .iter()
+        .map(|idx| {
+          *self
+            .brotli
+            .entry(*idx)
+            .or_insert_with(|| brotli_size(self.assets[*idx].content_as_bytes()))
+        })
+        .sum();
+      if size > limit {
+        exceeded.push(("brotli", size, limit));
+      }
+    }
+    exceeded
+  }
+}
+
+fn gzip_size(content: &[u8]) -> u64 {
+  let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+  encoder.write_all(content).and_then(|()| encoder.finish()).map_or(0, |out| out.len() as u64)
+}
+
+fn brotli_size(content: &[u8]) -> u64 {
+  let mut out = vec![];
+  let params = brotli::enc::BrotliEncoderParams::default();
+  match brotli::BrotliCompress(&mut &content[..], &mut out, &params) {
+    Ok(_) => out.len() as u64,
+    Err(_) => 0,
+  }
+}
@@ -13,9 +13,10 @@ pub fn deconflict_chunk_symbols(
   link_output: &LinkStageOutput,
   format: OutputFormat,
   index_chunk_id_to_name: &FxHashMap<ChunkIdx, ArcStr>,
+  mangle: bool,
 ) {
   let mut renamer =
-    Renamer::new(&link_output.symbol_db, link_output.module_table.modules.len(), format);
+    Renamer::new(&link_output.symbol_db, link_output.module_table.modules.len(), format, mangle);
 
   if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs) {
     // deconflict iife introduce symbols by external
@@ -57,6 +58,14 @@ pub fn deconflict_chunk_symbols(
       renamer.reserve(name.to_rstr());
     });
 
+  chunk
+    .modules
+    .iter()
+    .copied()
+    .filter_map(|id| link_output.module_table.modules[id].as_normal())
+    .filter(|module| !module.direct_eval_scopes.is_empty())
+    .for_each(|module| renamer.reserve_names_of_direct_eval_scopes(module));
+
   // Though, those symbols in `imports_from_other_chunks` doesn't belong to this chunk, but in the final output, they still behave
   // like declared in this chunk. This is because we need to generate import statements in this chunk to import symbols from other
   // statements. Those `import {...} from './other-chunk.js'` will declared these outside symbols in this chunk, so symbols that
@@ -116,6 +125,8 @@ pub fn deconflict_chunk_symbols(
         });
     });
 
+  renamer.mangle_root_symbols();
+
   // rename non-top-level names
   renamer.rename_non_root_symbol(&chunk.modules, &link_output.module_table.modules);
 
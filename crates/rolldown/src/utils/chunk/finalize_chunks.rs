@@ -63,6 +63,13 @@ pub fn finalize_assets(
     .par_iter()
     .map(|chunk| {
       let mut hash = xxhash_base64_url(chunk.content.as_bytes());
+      // Legal comments moved to a `.LEGAL.txt` file are not part of the content
+      if let InstantiationKind::Ecma(ecma_meta) = &chunk.kind {
+        if !ecma_meta.legal_comments.is_empty() {
+          hash.push_str(&ecma_meta.legal_comments.concat());
+          hash = xxhash_base64_url(hash.as_bytes());
+        }
+      }
       // Hash content that provided by users if it's exist
       if let Some(augment_chunk_hash) = &chunk.augment_chunk_hash {
         hash.push_str(augment_chunk_hash);
@@ -12,6 +12,7 @@ pub mod determine_export_mode;
 pub mod determine_use_strict;
 pub mod finalize_chunks;
 pub mod namespace_marker;
+pub mod render_chunk_directives;
 pub mod render_chunk_exports;
 pub mod validate_options_for_multi_chunk_output;
 
@@ -0,0 +1,15 @@
+use itertools::Itertools;
+use rolldown_utils::concat_string;
+
+use crate::types::generator::GenerateContext;
+
+/// Module-level directives like `"use client"` only work at the top of a file, so they are rendered once
+/// per chunk instead of per module.
+pub fn render_chunk_directives(ctx: &GenerateContext) -> Option<String> {
+  if ctx.chunk.directives.is_empty() {
+    return None;
+  }
+  Some(
+    ctx.chunk.directives.iter().map(|directive| concat_string!("\"", directive, "\";")).join("\n"),
+  )
+}
@@ -0,0 +1,37 @@
+use rolldown_common::NormalizedBundlerOptions;
+use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+
+/// Drop the logs not allowed by `log_level`, and let `on_log` drop the others or change their severity.
+/// Logs promoted to errors fail the build.
+pub async fn filter_logs(
+  options: &NormalizedBundlerOptions,
+  logs: Vec<BuildDiagnostic>,
+) -> BuildResult<Vec<BuildDiagnostic>> {
+  let mut kept = Vec::with_capacity(logs.len());
+  let mut errors = vec![];
+
+  for log in logs {
+    if !options.log_level.allows(log.severity()) {
+      continue;
+    }
+    let severity = match &options.on_log {
+      Some(on_log) => match on_log.call(&log).await? {
+        Some(severity) => severity,
+        None => continue,
+      },
+      None => log.severity(),
+    };
+    let log = log.with_severity(severity);
+    if severity == Severity::Error {
+      errors.push(log);
+    } else {
+      kept.push(log);
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(kept)
+  } else {
+    Err(errors.into())
+  }
+}
@@ -8,6 +8,23 @@ use rolldown_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
+/// The module type returned by the `load` hook differs from the one asserted by the importer, e.g. via
+/// `with { type: 'json' }`.
+#[derive(Debug)]
+pub struct ModuleTypeConflict {
+  pub asserted: ModuleType,
+  pub loaded: ModuleType,
+}
+
+impl std::fmt::Display for ModuleTypeConflict {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "asserted module type `{}`, but loaded as `{}`", self.asserted, self.loaded)
+  }
+}
+
+impl std::error::Error for ModuleTypeConflict {}
+
+#[tracing::instrument(level = "trace", skip_all, fields(module_id = %resolved_id.id))]
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_id: &ResolvedId,
@@ -32,21 +49,19 @@ pub async fn load_source(
     (None, None)
   };
 
-  if let Some(asserted) = asserted_module_type {
-    let is_type_conflicted = match &maybe_module_type {
-      None => false,
-      Some(user_specified_type) if user_specified_type == asserted => false,
-      _ => true,
-    };
-    if is_type_conflicted {
-      // TODO: emit a warning about the type conflict
+  if let (Some(asserted), Some(loaded)) = (asserted_module_type, &maybe_module_type) {
+    if asserted != loaded {
+      Err(ModuleTypeConflict { asserted: asserted.clone(), loaded: loaded.clone() })?;
     }
   }
 
   match (maybe_source, maybe_module_type) {
     (Some(source), Some(module_type)) => Ok((source.into(), module_type)),
     (source, None) => {
-      let guessed = get_module_loader_from_file_extension(&resolved_id.id, &options.module_types);
+      // The type asserted by the importer takes precedence over the type guessed from the extension.
+      let guessed = asserted_module_type
+        .cloned()
+        .or_else(|| get_module_loader_from_file_extension(&resolved_id.id, &options.module_types));
       match (source, guessed) {
         (None, None) => {
           // - Unknown module type,
@@ -71,6 +86,8 @@ pub async fn load_source(
           | ModuleType::Ts
           | ModuleType::Tsx
           | ModuleType::Json
+          | ModuleType::Yaml
+          | ModuleType::Toml
           | ModuleType::Text
           | ModuleType::Empty
           | ModuleType::Css
@@ -120,6 +137,8 @@ fn read_file_by_module_type(
     | ModuleType::Ts
     | ModuleType::Tsx
     | ModuleType::Json
+    | ModuleType::Yaml
+    | ModuleType::Toml
     | ModuleType::Css
     | ModuleType::Empty
     | ModuleType::Custom(_)
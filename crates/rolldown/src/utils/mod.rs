@@ -9,9 +9,11 @@ use super::module_finalizers::scope_hoisting::{
 pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
 pub mod call_expression_ext;
+pub mod check_size_budgets;
 pub mod chunk;
 pub mod ecma_visitors;
 pub mod extract_meaningful_input_name_from_path;
+pub mod filter_logs;
 pub mod load_source;
 pub mod normalize_options;
 pub mod parse_to_ecma_ast;
@@ -2,8 +2,8 @@ use std::path::Path;
 
 use oxc::transformer::InjectGlobalVariablesConfig;
 use rolldown_common::{
-  Comments, GlobalsOutputOption, InjectImport, ModuleType, NormalizedBundlerOptions, OutputFormat,
-  Platform,
+  Comments, GlobalsOutputOption, InjectImport, MinifyOptions, ModuleType, NormalizedBundlerOptions,
+  OutputFormat, Platform,
 };
 use rolldown_error::{BuildDiagnostic, InvalidOptionType};
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -38,6 +38,15 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> Vec<BuildDiagnosti
     _ => {}
   }
 
+  if let Some(resolve) = &raw_options.resolve {
+    if resolve.tsconfig_filename.is_some() && resolve.tsconfig_auto == Some(true) {
+      warnings.push(
+        BuildDiagnostic::invalid_option(InvalidOptionType::TsconfigFilenameWithTsconfigAuto)
+          .with_severity_warning(),
+      );
+    }
+  }
+
   warnings
 }
 
@@ -54,11 +63,16 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     }
   });
 
-  let minify = raw_options.minify.unwrap_or(false);
+  let target = raw_options.target.unwrap_or_default();
+  let minify =
+    MinifyOptions::new(raw_options.minify, raw_options.keep_names.unwrap_or_default(), target);
+  // The minifier never outputs syntax beyond the syntax level of its input, so modules are lowered to
+  // `minify.target` if it's lower than `target`.
+  let target = if minify.is_enabled() { target.min(minify.target) } else { target };
 
   let mut raw_define = raw_options.define.unwrap_or_default();
   if matches!(platform, Platform::Browser) && !raw_define.contains_key("process.env.NODE_ENV") {
-    if minify {
+    if minify.is_enabled() {
       raw_define.insert("process.env.NODE_ENV".to_string(), "'production'".to_string());
     } else {
       raw_define.insert("process.env.NODE_ENV".to_string(), "'development'".to_string());
@@ -81,6 +95,9 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       ("cts".to_string(), ModuleType::Ts),
       ("tsx".to_string(), ModuleType::Tsx),
       ("json".to_string(), ModuleType::Json),
+      ("yaml".to_string(), ModuleType::Yaml),
+      ("yml".to_string(), ModuleType::Yaml),
+      ("toml".to_string(), ModuleType::Toml),
       ("txt".to_string(), ModuleType::Text),
       ("css".to_string(), ModuleType::Css),
     ]
@@ -197,7 +214,10 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     sourcemap_debug_ids: raw_options.sourcemap_debug_ids.unwrap_or(false),
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types: loaders,
+    custom_module_types: raw_options.custom_module_types.unwrap_or_default(),
     experimental,
+    // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
+    profiler_names: raw_options.profiler_names.unwrap_or(!minify.is_enabled()),
     minify,
     define,
     inject: raw_options.inject.unwrap_or_default(),
@@ -207,15 +227,16 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     inline_dynamic_imports,
     advanced_chunks: raw_options.advanced_chunks,
     checks: raw_options.checks.unwrap_or_default(),
-    // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
-    profiler_names: raw_options.profiler_names.unwrap_or(!raw_options.minify.unwrap_or(false)),
     jsx: raw_options.jsx.unwrap_or_default(),
     watch: raw_options.watch.unwrap_or_default(),
     comments: raw_options.comments.unwrap_or(Comments::Preserve),
+    legal_comments: raw_options.legal_comments.unwrap_or_default(),
     drop_labels: FxHashSet::from_iter(raw_options.drop_labels.unwrap_or_default()),
-    target: raw_options.target.unwrap_or_default(),
-    keep_names: raw_options.keep_names.unwrap_or_default(),
+    target,
     polyfill_require: raw_options.polyfill_require.unwrap_or(true),
+    plugin_timings: raw_options.plugin_timings.unwrap_or(false),
+    log_level: raw_options.log_level.unwrap_or_default(),
+    on_log: raw_options.on_log,
   };
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve, warnings }
@@ -3,30 +3,27 @@ use std::path::Path;
 use arcstr::ArcStr;
 use oxc::{
   semantic::{ScopeTree, SymbolTable},
-  span::SourceType as OxcSourceType,
   transformer::ReplaceGlobalDefinesConfig,
 };
-use rolldown_common::{ModuleType, NormalizedBundlerOptions, StrOrBytes, RUNTIME_MODULE_ID};
-use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+use rolldown_common::{
+  CustomModuleContent, CustomModuleTypeArgs, ModuleType, NormalizedBundlerOptions, StrOrBytes,
+  RUNTIME_MODULE_ID,
+};
+use rolldown_ecmascript::{pure_esm_js_oxc_source_type, EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult};
-use rolldown_loader_utils::{binary_to_esm, text_to_string_literal};
+use rolldown_fs::OsFileSystem;
+use rolldown_loader_utils::{
+  binary_to_esm, text_to_string_literal, toml_to_json, yaml_to_json, DataParseError,
+};
 use rolldown_plugin::{HookTransformAstArgs, PluginDriver};
+use rolldown_plugin_import_glob::rewrite_import_glob;
+use rolldown_resolver::TsconfigCompilerOptions;
 use rolldown_utils::mime::guess_mime;
 
 use super::pre_process_ecma_ast::PreProcessEcmaAst;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
-fn pure_esm_js_oxc_source_type() -> OxcSourceType {
-  let pure_esm_js = OxcSourceType::default().with_module(true);
-  debug_assert!(pure_esm_js.is_javascript());
-  debug_assert!(!pure_esm_js.is_jsx());
-  debug_assert!(pure_esm_js.is_module());
-  debug_assert!(pure_esm_js.is_strict());
-
-  pure_esm_js
-}
-
 pub struct ParseToEcmaAstResult {
   pub ast: EcmaAst,
   pub symbol_table: SymbolTable,
@@ -36,18 +33,42 @@ pub struct ParseToEcmaAstResult {
 }
 
 #[allow(clippy::too_many_arguments)]
+#[tracing::instrument(level = "trace", skip_all, fields(module_id = %path.display()))]
 pub fn parse_to_ecma_ast(
   plugin_driver: &PluginDriver,
+  fs: OsFileSystem,
   path: &Path,
   stable_id: &str,
   options: &NormalizedBundlerOptions,
   module_type: &ModuleType,
   source: StrOrBytes,
   replace_global_define_config: Option<&ReplaceGlobalDefinesConfig>,
+  tsconfig_compiler_options: Option<&TsconfigCompilerOptions>,
   is_user_defined_entry: bool,
 ) -> BuildResult<ParseToEcmaAstResult> {
-  let (has_lazy_export, source, parsed_type) =
-    pre_process_source(module_type, source, is_user_defined_entry, path, options)?;
+  let custom_module_type_handler = match module_type {
+    ModuleType::Custom(custom_type) => {
+      options.custom_module_types.get(custom_type).map(|handler| (custom_type, handler))
+    }
+    _ => None,
+  };
+
+  let (has_lazy_export, content, parsed_type) = match custom_module_type_handler {
+    Some((custom_type, handler)) => {
+      let output = handler.to_ecmascript(CustomModuleTypeArgs {
+        module_type: custom_type,
+        id: &path.to_string_lossy(),
+        stable_id,
+        source,
+      })?;
+      (output.lazy_export, output.content, OxcParseType::Js)
+    }
+    None => {
+      let (has_lazy_export, source, parsed_type) =
+        pre_process_source(module_type, source, is_user_defined_entry, path, options)?;
+      (has_lazy_export, CustomModuleContent::Source(source), parsed_type)
+    }
+  };
 
   let oxc_source_type = {
     let default = pure_esm_js_oxc_source_type();
@@ -71,13 +92,25 @@ pub fn parse_to_ecma_ast(
     }
   };
 
-  let source = ArcStr::from(source);
-
-  let mut ecma_ast = match module_type {
-    ModuleType::Json | ModuleType::Dataurl | ModuleType::Base64 | ModuleType::Text => {
-      EcmaCompiler::parse_expr_as_program(stable_id, &source, oxc_source_type)?
+  let mut ecma_ast = match content {
+    CustomModuleContent::Source(source) => {
+      let source = ArcStr::from(source);
+      match module_type {
+        ModuleType::Json
+        | ModuleType::Yaml
+        | ModuleType::Toml
+        | ModuleType::Dataurl
+        | ModuleType::Base64
+        | ModuleType::Text => {
+          EcmaCompiler::parse_expr_as_program(stable_id, &source, oxc_source_type)?
+        }
+        ModuleType::Custom(_) if has_lazy_export => {
+          EcmaCompiler::parse_expr_as_program(stable_id, &source, oxc_source_type)?
+        }
+        _ => EcmaCompiler::parse(stable_id, &source, oxc_source_type)?,
+      }
     }
-    _ => EcmaCompiler::parse(stable_id, &source, oxc_source_type)?,
+    CustomModuleContent::Ast(ast) => ast,
   };
 
   ecma_ast = plugin_driver.transform_ast(HookTransformAstArgs {
@@ -86,12 +119,24 @@ pub fn parse_to_ecma_ast(
     id: stable_id,
   })?;
 
+  // Glob imports are evaluated before scanning, so the matched files become normal dependencies. The
+  // globs left by `ImportGlobPlugin`, which supports more options, are gone already.
+  if ecma_ast.source().contains("import.meta.glob") {
+    let watch_globs = ecma_ast.program.with_mut(|fields| {
+      rewrite_import_glob(fields.allocator, fields.program, &fs, &options.cwd, path, false)
+    });
+    for pattern in watch_globs {
+      plugin_driver.watch_globs.insert(pattern.into());
+    }
+  }
+
   PreProcessEcmaAst::default().build(
     ecma_ast,
     &parsed_type,
     stable_id,
     replace_global_define_config,
     options,
+    tsconfig_compiler_options,
     has_lazy_export,
   )
 }
@@ -122,6 +167,27 @@ fn pre_process_source(
       let content = source.try_into_string()?;
       (content, OxcParseType::Js)
     }
+    ModuleType::Yaml | ModuleType::Toml => {
+      has_lazy_export = true;
+      let content = source.try_into_string()?;
+      let converted = match module_type {
+        ModuleType::Yaml => yaml_to_json(&content),
+        _ => toml_to_json(&content),
+      };
+      match converted {
+        Ok(json) => (json, OxcParseType::Js),
+        Err(DataParseError { message, span }) => {
+          let filename = path.to_string_lossy().into();
+          let diagnostic = match module_type {
+            ModuleType::Yaml => {
+              BuildDiagnostic::yaml_parse(filename, content.into(), span, message.into())
+            }
+            _ => BuildDiagnostic::toml_parse(filename, content.into(), span, message.into()),
+          };
+          return Err(diagnostic)?;
+        }
+      }
+    }
     ModuleType::Text => {
       let content = text_to_string_literal(&source.try_into_string()?)?;
       has_lazy_export = true;
@@ -153,7 +219,7 @@ fn pre_process_source(
     ModuleType::Empty => (String::new(), OxcParseType::Js),
     ModuleType::Custom(custom_type) => {
       // TODO: should provide friendly error message to say that this type is not supported by rolldown.
-      // Users should handle this type in load/transform hooks
+      // Users should handle this type in load/transform hooks, or register a handler in `custom_module_types`.
       return Err(anyhow::format_err!("Unknown module type: {custom_type}"))?;
     }
   };
@@ -6,13 +6,14 @@ use oxc::diagnostics::Severity as OxcSeverity;
 use oxc::minifier::{CompressOptions, Compressor};
 use oxc::semantic::{SemanticBuilder, Stats};
 use oxc::transformer::{
-  ESTarget as OxcESTarget, InjectGlobalVariables, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
-  TransformOptions, Transformer,
+  ESTarget as OxcESTarget, InjectGlobalVariables, JsxRuntime, ReplaceGlobalDefines,
+  ReplaceGlobalDefinesConfig, TransformOptions, Transformer,
 };
 
-use rolldown_common::{ESTarget, Jsx, NormalizedBundlerOptions};
-use rolldown_ecmascript::{EcmaAst, WithMutFields};
+use rolldown_common::{ESTarget, Jsx, NormalizedBundlerOptions, RUNTIME_MODULE_ID};
+use rolldown_ecmascript::{EcmaAst, LowerLegacyDecorators, RemovePureCalls, WithMutFields};
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rolldown_resolver::TsconfigCompilerOptions;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
@@ -31,7 +32,7 @@ pub struct PreProcessEcmaAst {
 
 impl PreProcessEcmaAst {
   // #[allow(clippy::match_same_arms)]: `OxcParseType::Tsx` will have special logic to deal with ts compared to `OxcParseType::Jsx`
-  #[allow(clippy::match_same_arms, clippy::too_many_lines)]
+  #[allow(clippy::match_same_arms, clippy::too_many_lines, clippy::too_many_arguments)]
   pub fn build(
     &mut self,
     mut ast: EcmaAst,
@@ -39,6 +40,7 @@ impl PreProcessEcmaAst {
     path: &str,
     replace_global_define_config: Option<&ReplaceGlobalDefinesConfig>,
     bundle_options: &NormalizedBundlerOptions,
+    tsconfig_compiler_options: Option<&TsconfigCompilerOptions>,
     has_lazy_export: bool,
   ) -> BuildResult<ParseToEcmaAstResult> {
     let mut warning = vec![];
@@ -87,7 +89,12 @@ impl PreProcessEcmaAst {
             Jsx::Preserve => {}
             Jsx::Enable(jsx) => {
               transformer_options.jsx = jsx.clone();
-              if matches!(parse_type, OxcParseType::Tsx | OxcParseType::Jsx) {
+              let preserved_by_tsconfig = tsconfig_compiler_options.is_some_and(|tsconfig| {
+                apply_tsconfig_jsx_options(&mut transformer_options, tsconfig)
+              });
+              if matches!(parse_type, OxcParseType::Tsx | OxcParseType::Jsx)
+                && !preserved_by_tsconfig
+              {
                 transformer_options.jsx.jsx_plugin = true;
               }
             }
@@ -116,6 +123,29 @@ impl PreProcessEcmaAst {
       self.ast_changed = true;
     }
 
+    // The oxc transformer doesn't support legacy decorators yet, so lower them after it.
+    if tsconfig_compiler_options
+      .is_some_and(|tsconfig| tsconfig.experimental_decorators == Some(true))
+    {
+      let ret = ast.program.with_mut(|WithMutFields { allocator, program, .. }| {
+        // The lowering declares new top-level names, which must not collide with the bindings
+        // injected by the transformations above.
+        if self.ast_changed {
+          let semantic_ret = SemanticBuilder::new().with_stats(self.stats).build(program);
+          (symbols, scopes) = semantic_ret.semantic.into_symbol_table_and_scope_tree();
+        }
+        LowerLegacyDecorators::new(allocator, &scopes, RUNTIME_MODULE_ID).build(program)
+      });
+      match ret {
+        Ok(changed) => self.ast_changed |= changed,
+        Err(errors) => {
+          return Err(
+            BuildDiagnostic::from_oxc_diagnostics(errors, &source, path, &Severity::Error).into(),
+          );
+        }
+      }
+    }
+
     ast.program.with_mut(|fields| -> BuildResult<()> {
       let WithMutFields { allocator, program, .. } = fields;
       if !bundle_options.inject.is_empty() {
@@ -135,6 +165,12 @@ impl PreProcessEcmaAst {
         self.ast_changed = true;
       }
 
+      // `pureFuncs` are matched with the names in the source, which are changed by mangling later.
+      if bundle_options.minify.compress && !bundle_options.minify.pure_funcs.is_empty() {
+        RemovePureCalls::new(allocator, &symbols, &bundle_options.minify.pure_funcs).build(program);
+        self.ast_changed = true;
+      }
+
       // avoid DCE for lazy export
       if bundle_options.treeshake.enabled() && !has_lazy_export {
         // Perform dead code elimination.
@@ -151,7 +187,10 @@ impl PreProcessEcmaAst {
     })?;
 
     ast.program.with_mut(|fields| {
-      let mut pre_processor = PreProcessor::new(fields.allocator, bundle_options.keep_names);
+      let mut pre_processor = PreProcessor::new(
+        fields.allocator,
+        bundle_options.minify.keep_fnames || bundle_options.minify.keep_classnames,
+      );
       pre_processor.visit_program(fields.program);
       ast.contains_use_strict = pre_processor.contains_use_strict;
     });
@@ -175,3 +214,37 @@ impl PreProcessEcmaAst {
     Ok(ParseToEcmaAstResult { ast, symbol_table, scope_tree, has_lazy_export, warning })
   }
 }
+
+/// Override the jsx transform options with `compilerOptions` of the nearest tsconfig.
+/// Returns `true` if the tsconfig asks to preserve jsx.
+fn apply_tsconfig_jsx_options(
+  transformer_options: &mut TransformOptions,
+  tsconfig: &TsconfigCompilerOptions,
+) -> bool {
+  let jsx = &mut transformer_options.jsx;
+  match tsconfig.jsx.as_deref() {
+    Some("preserve" | "react-native") => return true,
+    Some("react") => jsx.runtime = JsxRuntime::Classic,
+    Some("react-jsx") => {
+      jsx.runtime = JsxRuntime::Automatic;
+      jsx.development = false;
+    }
+    Some("react-jsxdev") => {
+      jsx.runtime = JsxRuntime::Automatic;
+      jsx.development = true;
+    }
+    _ => {}
+  }
+  if let Some(factory) = &tsconfig.jsx_factory {
+    jsx.pragma = Some(factory.clone());
+    transformer_options.typescript.jsx_pragma = factory.clone().into();
+  }
+  if let Some(fragment_factory) = &tsconfig.jsx_fragment_factory {
+    jsx.pragma_frag = Some(fragment_factory.clone());
+    transformer_options.typescript.jsx_pragma_frag = fragment_factory.clone().into();
+  }
+  if let Some(import_source) = &tsconfig.jsx_import_source {
+    jsx.import_source = Some(import_source.clone());
+  }
+  false
+}
@@ -1,11 +1,14 @@
 use oxc::semantic::ScopeId;
 use oxc::syntax::keyword::{GLOBAL_OBJECTS, RESERVED_KEYWORDS};
 use rolldown_common::{
-  IndexModules, ModuleIdx, NormalModule, OutputFormat, SymbolNameRefToken, SymbolRef, SymbolRefDb,
+  GetLocalDb, IndexModules, ModuleIdx, NormalModule, OutputFormat, SymbolNameRefToken, SymbolRef,
+  SymbolRefDb,
 };
 use rolldown_rstr::{Rstr, ToRstr};
 use rolldown_utils::{
   concat_string,
+  ecmascript::base54,
+  indexmap::FxIndexMap,
   rayon::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator},
 };
 use rustc_hash::FxHashMap;
@@ -35,10 +38,20 @@ pub struct Renamer<'name> {
   canonical_names: FxHashMap<SymbolRef, Rstr>,
   canonical_token_to_name: FxHashMap<SymbolNameRefToken, Rstr>,
   symbol_db: &'name SymbolRefDb,
+  /// Assign the shortest available names instead of the original names.
+  mangle: bool,
+  /// With `mangle`, names of top-level symbols are assigned at once in [Renamer::mangle_root_symbols],
+  /// so that frequently referenced symbols get shorter names. Value is the count of references.
+  pending_root_symbols: FxIndexMap<SymbolRef, usize>,
 }
 
 impl<'name> Renamer<'name> {
-  pub fn new(symbols: &'name SymbolRefDb, _modules_len: usize, format: OutputFormat) -> Self {
+  pub fn new(
+    symbols: &'name SymbolRefDb,
+    _modules_len: usize,
+    format: OutputFormat,
+    mangle: bool,
+  ) -> Self {
     // Port from https://github.com/rollup/rollup/blob/master/src/Chunk.ts#L1377-L1394.
     let mut manual_reserved = match format {
       OutputFormat::Esm | OutputFormat::App => vec![],
@@ -51,6 +64,8 @@ impl<'name> Renamer<'name> {
       canonical_names: FxHashMap::default(),
       canonical_token_to_name: FxHashMap::default(),
       symbol_db: symbols,
+      mangle,
+      pending_root_symbols: FxIndexMap::default(),
       used_canonical_names: manual_reserved
         .iter()
         .chain(RESERVED_KEYWORDS.iter())
@@ -66,6 +81,14 @@ impl<'name> Renamer<'name> {
 
   pub fn add_symbol_in_root_scope(&mut self, symbol_ref: SymbolRef) {
     let canonical_ref = symbol_ref.canonical_ref(self.symbol_db);
+    if self.mangle {
+      if !self.canonical_names.contains_key(&canonical_ref) {
+        let references =
+          self.symbol_db.local_db(symbol_ref.owner).get_resolved_reference_ids(symbol_ref.symbol);
+        *self.pending_root_symbols.entry(canonical_ref).or_default() += references.len();
+      }
+      return;
+    }
     let original_name = canonical_ref.name(self.symbol_db).to_rstr();
     match self.canonical_names.entry(canonical_ref) {
       Entry::Vacant(vacant) => {
@@ -93,6 +116,46 @@ impl<'name> Renamer<'name> {
     }
   }
 
+  /// Assign names to the top-level symbols collected with `mangle`. Names are given in the order of
+  /// reference counts, so the most used symbols get the shortest names.
+  pub fn mangle_root_symbols(&mut self) {
+    let mut pending_root_symbols = std::mem::take(&mut self.pending_root_symbols);
+    // Stable sort keeps the declaration order for symbols with the same count.
+    pending_root_symbols.sort_by(|_, a, _, b| b.cmp(a));
+    let mut next_index = 0;
+    for symbol_ref in pending_root_symbols.into_keys() {
+      let name = loop {
+        let candidate_name = Rstr::new(&base54(next_index));
+        next_index += 1;
+        if let Entry::Vacant(vac) = self.used_canonical_names.entry(candidate_name.clone()) {
+          vac.insert(0);
+          break candidate_name;
+        }
+      };
+      self.canonical_names.insert(symbol_ref, name);
+    }
+  }
+
+  /// With `mangle`, nested scopes of a module that contain a direct `eval` keep their names, so the
+  /// top-level symbols must not take these names.
+  pub fn reserve_names_of_direct_eval_scopes(&mut self, module: &NormalModule) {
+    if !self.mangle {
+      return;
+    }
+    module
+      .scope
+      .get_child_ids(module.scope.root_scope_id())
+      .iter()
+      .filter(|child_scope_id| contains_direct_eval(module, **child_scope_id))
+      .flat_map(|child_scope_id| {
+        std::iter::once(*child_scope_id).chain(module.scope.iter_all_child_ids(*child_scope_id))
+      })
+      .flat_map(|scope_id| module.scope.get_bindings(scope_id).keys())
+      .for_each(|name| {
+        self.used_canonical_names.entry(name.to_rstr()).or_insert(0);
+      });
+  }
+
   pub fn create_conflictless_name(&mut self, hint: &str) -> String {
     let hint = Rstr::new(hint);
     let mut conflictless_name = hint.clone();
@@ -185,20 +248,72 @@ impl<'name> Renamer<'name> {
       stack.pop();
     }
 
+    // Names only need to differ from the ones of ancestor scopes. `next_index` keeps growing from the
+    // root to the leaves, so sibling scopes reuse the same short names.
+    fn mangle_symbols_of_nested_scopes(
+      module: &NormalModule,
+      scope_id: ScopeId,
+      root_used_names: &FxHashMap<Rstr, u32>,
+      mut next_index: usize,
+      canonical_names: &mut FxHashMap<SymbolRef, Rstr>,
+    ) {
+      let mut bindings = module.scope.get_bindings(scope_id).values().copied().collect::<Vec<_>>();
+      bindings.sort_unstable();
+      for symbol_id in bindings {
+        let name = loop {
+          let candidate_name = Rstr::new(&base54(next_index));
+          next_index += 1;
+          if !root_used_names.contains_key(&candidate_name) {
+            break candidate_name;
+          }
+        };
+        canonical_names.entry((module.idx, symbol_id).into()).or_insert(name);
+      }
+      module.scope.get_child_ids(scope_id).iter().for_each(|scope_id| {
+        mangle_symbols_of_nested_scopes(
+          module,
+          *scope_id,
+          root_used_names,
+          next_index,
+          canonical_names,
+        );
+      });
+    }
+
     let copied_scope_iter =
       modules_in_chunk.par_iter().copied().filter_map(|id| modules[id].as_normal()).flat_map(
         |module| {
           let child_scopes: &[ScopeId] = module.scope.get_child_ids(module.scope.root_scope_id());
 
           child_scopes.into_par_iter().map(|child_scope_id| {
-            let mut stack = vec![Cow::Borrowed(&self.used_canonical_names)];
             let mut canonical_names = FxHashMap::default();
-            rename_symbols_of_nested_scopes(
-              module,
-              *child_scope_id,
-              &mut stack,
-              &mut canonical_names,
-            );
+            if self.mangle && !contains_direct_eval(module, *child_scope_id) {
+              mangle_symbols_of_nested_scopes(
+                module,
+                *child_scope_id,
+                &self.used_canonical_names,
+                0,
+                &mut canonical_names,
+              );
+            } else if self.mangle {
+              // Names of scopes that are visible to a direct `eval` can't be changed. Mangled names of
+              // other scopes in the subtree could shadow them, so the whole subtree keeps its names.
+              // These names are reserved in `Renamer::reserve_names_of_direct_eval_scopes`.
+              std::iter::once(*child_scope_id)
+                .chain(module.scope.iter_all_child_ids(*child_scope_id))
+                .flat_map(|scope_id| module.scope.get_bindings(scope_id).iter())
+                .for_each(|(name, symbol_id)| {
+                  canonical_names.insert((module.idx, *symbol_id).into(), name.to_rstr());
+                });
+            } else {
+              let mut stack = vec![Cow::Borrowed(&self.used_canonical_names)];
+              rename_symbols_of_nested_scopes(
+                module,
+                *child_scope_id,
+                &mut stack,
+                &mut canonical_names,
+              );
+            }
             canonical_names
           })
         },
@@ -227,3 +342,10 @@ impl<'name> Renamer<'name> {
     (self.canonical_names, self.canonical_token_to_name)
   }
 }
+
+/// Whether `scope_id` or any of its descendants calls `eval` directly.
+fn contains_direct_eval(module: &NormalModule, scope_id: ScopeId) -> bool {
+  module.direct_eval_scopes.iter().any(|eval_scope_id| {
+    module.scope.ancestors(*eval_scope_id).any(|ancestor| ancestor == scope_id)
+  })
+}
@@ -97,6 +97,9 @@ impl<'ast> VisitMut<'ast> for PreProcessor<'ast> {
 
   fn visit_statements(&mut self, it: &mut oxc::allocator::Vec<'ast, Statement<'ast>>) {
     if self.keep_names {
+      // Nested statements must not see or touch the state of the top level statement that contains them.
+      let outer_stmt_temp_storage = std::mem::take(&mut self.stmt_temp_storage);
+      let outer_need_push_ast = self.need_push_ast;
       let stmts = it.take_in(self.snippet.alloc());
       for mut stmt in stmts {
         walk_mut::walk_statement(self, &mut stmt);
@@ -106,6 +109,8 @@ impl<'ast> VisitMut<'ast> for PreProcessor<'ast> {
           it.extend(self.stmt_temp_storage.drain(..));
         }
       }
+      self.stmt_temp_storage = outer_stmt_temp_storage;
+      self.need_push_ast = outer_need_push_ast;
     } else {
       walk_mut::walk_statements(self, it);
     }
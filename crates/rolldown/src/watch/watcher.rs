@@ -145,7 +145,11 @@ impl WatcherImpl {
     // stop watching files
     // TODO the notify watcher should be dropped, because the stop method is private
     let mut inner = self.notify_watcher.lock().await;
-    for path in self.notify_watch_files.iter() {
+    // Unwatch deeper paths first. Unwatching a recursively watched glob directory also removes the
+    // watches of files inside it, so unwatching them afterwards would fail.
+    let mut paths = self.notify_watch_files.iter().map(|path| path.clone()).collect::<Vec<_>>();
+    paths.sort_unstable_by_key(|path| std::cmp::Reverse(path.len()));
+    for path in paths {
       tracing::debug!(name= "notify close ", path = ?path.as_str());
       inner.unwatch(Path::new(path.as_str()))?;
     }
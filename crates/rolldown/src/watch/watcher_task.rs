@@ -11,12 +11,13 @@ use crate::{Bundler, SharedOptions};
 
 use super::emitter::SharedWatcherEmitter;
 use arcstr::ArcStr;
+use glob_match::glob_match;
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use rolldown_common::{
   BundleEndEventData, BundleEvent, OutputsDiagnostics, WatcherChangeKind, WatcherEvent,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult, ResultExt};
-use rolldown_utils::{dashmap::FxDashSet, pattern_filter};
+use rolldown_utils::{dashmap::FxDashSet, glob_base_dir::glob_base_dir, pattern_filter};
 use sugar_path::SugarPath;
 use tokio::sync::Mutex;
 
@@ -27,6 +28,7 @@ pub struct WatcherTask {
   notify_watcher: Arc<Mutex<RecommendedWatcher>>,
   notify_watch_files: Arc<FxDashSet<ArcStr>>,
   pub watch_files: FxDashSet<ArcStr>,
+  pub watch_globs: FxDashSet<ArcStr>,
 }
 
 impl WatcherTask {
@@ -41,6 +43,7 @@ impl WatcherTask {
       bundler,
       invalidate_flag: AtomicBool::new(true),
       watch_files: FxDashSet::default(),
+      watch_globs: FxDashSet::default(),
       notify_watcher,
       notify_watch_files: notify_watched_files,
     }
@@ -63,6 +66,7 @@ impl WatcherTask {
       let result = bundler.scan().await;
       // FIXME(hyf0): probably should have a more official API/better way to get watch files
       self.watch_files(&bundler.plugin_driver.watch_files, &bundler.options).await?;
+      self.watch_globs(&bundler.plugin_driver.watch_globs).await?;
       match result {
         Ok(scan_stage_output) => {
           if bundler.options.watch.skip_write {
@@ -151,6 +155,32 @@ impl WatcherTask {
     Ok(())
   }
 
+  /// Watch the base directories of glob patterns, so adding or removing a matching file could be noticed.
+  async fn watch_globs(&self, patterns: &Arc<FxDashSet<ArcStr>>) -> BuildResult<()> {
+    let mut notify_watcher = self.notify_watcher.lock().await;
+
+    for pattern in patterns.iter() {
+      if !self.watch_globs.insert(pattern.clone()) {
+        continue;
+      }
+      let base_dir: ArcStr = glob_base_dir(pattern.as_str()).into();
+      if base_dir.is_empty() || self.notify_watch_files.contains(base_dir.as_str()) {
+        continue;
+      }
+      let path = Path::new(base_dir.as_str());
+      if path.exists() {
+        tracing::debug!(name= "notify watch glob", path = ?path);
+        notify_watcher.watch(path, RecursiveMode::Recursive).map_err_to_unhandleable()?;
+        self.notify_watch_files.insert(base_dir);
+      }
+    }
+
+    // The inner mutex should be dropped to avoid deadlock with bundler lock at `Watcher::close`
+    std::mem::drop(notify_watcher);
+
+    Ok(())
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn close(&self) -> anyhow::Result<()> {
     let bundler = self.bundler.lock().await;
@@ -159,8 +189,12 @@ impl WatcherTask {
   }
 
   pub fn invalidate(&self, path: &str) {
-    // invalidate the watcher task if the changed file is in the watch list
-    if self.watch_files.contains(path) {
+    // invalidate the watcher task if the changed file is in the watch list or matches a watched glob,
+    // which means a file is added to or removed from the result of `import.meta.glob`
+    let normalized_path = path.to_slash_lossy();
+    if self.watch_files.contains(path)
+      || self.watch_globs.iter().any(|pattern| glob_match(pattern.as_str(), &normalized_path))
+    {
       self.invalidate_flag.store(true, Ordering::Relaxed);
     }
   }
@@ -0,0 +1,4 @@
+import { b } from './b.js'
+
+export const a = await Promise.resolve('a')
+export const getB = () => b
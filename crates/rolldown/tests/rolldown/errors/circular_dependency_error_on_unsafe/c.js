@@ -0,0 +1,4 @@
+import { d } from './d.js'
+
+export const c = 'c'
+export const getD = () => d
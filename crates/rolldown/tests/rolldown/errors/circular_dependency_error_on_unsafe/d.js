@@ -0,0 +1,4 @@
+import { c } from './c.js'
+
+export const d = 'd'
+export const getC = () => c
@@ -0,0 +1,4 @@
+import data from './data.json' with { type: 'json' }
+import text from './text.js'
+
+console.log(data, text)
@@ -0,0 +1,3 @@
+import data from './data.json' with { type: 'text' }
+
+export default data
@@ -0,0 +1,4 @@
+import { feature } from "test/feature"
+import { utils } from "test/utlis"
+
+console.log(feature, utils)
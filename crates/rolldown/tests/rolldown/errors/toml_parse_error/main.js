@@ -0,0 +1,2 @@
+import config from './config.toml'
+console.log(config)
@@ -0,0 +1,2 @@
+import config from './config.yaml'
+console.log(config)
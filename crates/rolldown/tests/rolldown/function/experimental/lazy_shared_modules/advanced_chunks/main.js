@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import './polyfill.js'
+import { lib } from './vendor/lib.js'
+
+assert.deepStrictEqual(globalThis.executionOrder, ['polyfill', 'lib'])
+assert.strictEqual(lib, 'lib')
@@ -0,0 +1 @@
+globalThis.executionOrder = ['polyfill']
@@ -0,0 +1,3 @@
+import {foo} from './user-lib';
+
+foo();
\ No newline at end of file
@@ -0,0 +1,6 @@
+import assert from 'node:assert';
+import './polyfill';
+import {foo} from './user-lib';
+
+assert.deepStrictEqual(globalThis.executionOrder, ['polyfill', 'user-lib']);
+foo();
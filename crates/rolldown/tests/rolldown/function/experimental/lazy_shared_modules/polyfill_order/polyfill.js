@@ -0,0 +1,2 @@
+globalThis.executionOrder = ['polyfill'];
+Object.somePolyfilledFunction = () => {};
@@ -0,0 +1,6 @@
+Object.somePolyfilledFunction();
+globalThis.executionOrder.push('user-lib');
+
+export async function foo() {
+	return import('./lazy-chunk');
+}
@@ -0,0 +1 @@
+import './unused.js'
@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import { lazy, eager } from './dist/main.js'
+
+assert.deepStrictEqual(Object.keys(lazy), ['./routes/a.js', './routes/b.js'])
+assert.strictEqual((await lazy['./routes/a.js']()).default, 'a')
+assert.deepStrictEqual(eager, {
+  './routes/_private.js': 'private',
+  './routes/a.js': 'a',
+  './routes/b.js': 'b',
+  './routes/nested/c.js': 'c',
+})
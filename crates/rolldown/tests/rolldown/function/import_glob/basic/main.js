@@ -0,0 +1,3 @@
+export const lazy = import.meta.glob(['./routes/*.js', '!./routes/_*.js'])
+
+export const eager = import.meta.glob('./routes/**/*.js', { eager: true, import: 'default' })
@@ -0,0 +1,7 @@
+/*! shared-lib v1.0.0 | MIT */
+
+/**
+ * @license A
+ * Copyright (c) A authors
+ */
+export const a = 'a'
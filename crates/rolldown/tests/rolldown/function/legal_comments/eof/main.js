@@ -0,0 +1,6 @@
+/*! main | MIT */
+import { a } from './a.js'
+import { b } from './b.js'
+
+// a normal comment
+console.log(a, b)
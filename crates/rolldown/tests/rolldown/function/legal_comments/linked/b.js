@@ -0,0 +1,3 @@
+/*! shared-lib v1.0.0 | MIT */
+// @preserve b
+export const b = 'b'
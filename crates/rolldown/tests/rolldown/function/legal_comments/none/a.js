@@ -0,0 +1,4 @@
+/*! Legal comments3 */
+console.log('a')
+
+export const a = 'a'
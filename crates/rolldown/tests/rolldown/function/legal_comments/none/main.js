@@ -0,0 +1,10 @@
+import { a } from './a.js'
+
+//! Legal comments1
+/*! Legal comments2 */
+console.log(a)
+
+/**
+ * @license MIT
+ */
+console.log('main')
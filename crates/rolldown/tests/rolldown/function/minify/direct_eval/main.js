@@ -0,0 +1,19 @@
+import assert from 'node:assert'
+
+function f(a) {
+  return eval('a')
+}
+
+function outer(value) {
+  return function inner(other) {
+    return eval('value + other')
+  }
+}
+
+function mangled(value) {
+  return value
+}
+
+assert.equal(f(1), 1)
+assert.equal(outer(1)(2), 3)
+assert.equal(mangled(1), 1)
@@ -0,0 +1,28 @@
+import assert from 'node:assert'
+
+function debugLog(message) {
+  globalThis.logged = message
+}
+
+function sideEffect() {
+  globalThis.called = true
+  return true
+}
+
+function namedFunction() {
+  return 1
+}
+
+const local = 'local'
+
+debugLog('removed')
+debugLog(local)
+// Reading an undeclared global throws, so the argument is kept.
+assert.throws(() => {
+  debugLog(undeclaredGlobal)
+}, ReferenceError)
+assert.ok(sideEffect())
+console.log('dropped')
+assert.equal(globalThis.logged, undefined)
+assert.equal(globalThis.called, true)
+assert.equal(namedFunction.name, 'namedFunction')
@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+
+class NamedClass {}
+
+function namedFunction() {
+  return NamedClass
+}
+
+assert.equal(namedFunction().name, 'NamedClass')
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+
+const options = globalThis.options
+const value = options?.value ?? 'default'
+
+assert.equal(value, 'default')
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { answer, count } from './dist/main.js'
+import twice from './dist/other.js'
+
+assert.strictEqual(answer, 42)
+assert.strictEqual(count, 2)
+assert.strictEqual(twice(1), 3)
@@ -0,0 +1,7 @@
+import { increase, Counter } from './shared.js'
+
+const counter = new Counter()
+counter.increase()
+
+export const answer = increase(41)
+export const count = counter.increase()
@@ -0,0 +1,5 @@
+import { increase } from './shared.js'
+
+export default function twice(value) {
+  return increase(increase(value))
+}
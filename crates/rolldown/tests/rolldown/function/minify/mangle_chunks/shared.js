@@ -0,0 +1,12 @@
+export function increase(value) {
+  const step = 1
+  return value + step
+}
+
+export class Counter {
+  count = 0
+  increase() {
+    this.count = increase(this.count)
+    return this.count
+  }
+}
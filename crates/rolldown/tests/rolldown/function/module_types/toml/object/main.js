@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { name, server, plugins } from './config.toml'
+
+assert.strictEqual(name, 'rolldown')
+assert.deepStrictEqual(server, { port: 8080, released: '2024-10-01' })
+assert.deepStrictEqual(plugins, [{ name: 'a' }, { name: 'b' }])
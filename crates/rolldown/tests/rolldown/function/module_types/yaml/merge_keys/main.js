@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { development } from './config.yaml'
+
+assert.deepStrictEqual(development, { host: 'localhost', port: 3000 })
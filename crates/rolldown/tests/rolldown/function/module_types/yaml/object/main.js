@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { greeting, plural } from './messages.yaml'
+import list from './list.yml'
+
+assert.strictEqual(greeting, 'Hello')
+assert.deepStrictEqual(plural, { one: 'item', other: 'items' })
+assert.deepStrictEqual(list, [1, 'two', true])
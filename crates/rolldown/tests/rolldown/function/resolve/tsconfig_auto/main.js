@@ -0,0 +1,4 @@
+import { app } from './packages/app/src/index.tsx'
+import { lib } from './packages/lib/src/index.ts'
+
+console.log(app, lib)
@@ -0,0 +1,3 @@
+export function h(tag: string, props: unknown, ...children: unknown[]) {
+  return { tag, props, children }
+}
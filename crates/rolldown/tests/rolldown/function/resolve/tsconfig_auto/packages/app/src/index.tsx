@@ -0,0 +1,4 @@
+import { h } from '@/h'
+import { name } from '@/name'
+
+export const app = <div>{name}</div>
@@ -0,0 +1,3 @@
+import { name } from '@/name'
+
+export const lib: string = name
@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+import Service, { Named } from './service'
+
+assert.deepStrictEqual(Service.calls, [
+  'property name',
+  'param run 0',
+  'method run',
+  'static method create',
+  'param Service 1',
+  'class Service',
+])
+assert.strictEqual(new Service().run(1), 'logged 1')
+assert.strictEqual(Service.create(), 'created')
+assert.strictEqual(Named.tag, 'named')
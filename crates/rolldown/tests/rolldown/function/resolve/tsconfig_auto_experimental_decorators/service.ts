@@ -0,0 +1,55 @@
+const calls: string[] = []
+
+function log(_target: any, key: string, descriptor: PropertyDescriptor) {
+  calls.push(`method ${key}`)
+  const original = descriptor.value
+  descriptor.value = function (...args: any[]) {
+    return `logged ${original.apply(this, args)}`
+  }
+}
+
+function property(_target: any, key: string) {
+  calls.push(`property ${key}`)
+}
+
+function staticMethod(_target: any, key: string) {
+  calls.push(`static method ${key}`)
+}
+
+function param(_target: any, key: string | undefined, index: number) {
+  calls.push(`param ${key ?? 'Service'} ${index}`)
+}
+
+function injectable(cls: any) {
+  calls.push(`class ${cls.name}`)
+  cls.calls = calls
+}
+
+function tag(value: string) {
+  return (cls: any) => {
+    cls.tag = value
+  }
+}
+
+@tag('named')
+export class Named {}
+
+@injectable
+export default class Service {
+  static calls: string[]
+
+  constructor(_label?: string, @param _index?: number) {}
+
+  @property
+  name = 'name'
+
+  @log
+  run(@param value: number) {
+    return value
+  }
+
+  @staticMethod
+  static create() {
+    return 'created'
+  }
+}
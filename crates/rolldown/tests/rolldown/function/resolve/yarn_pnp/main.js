@@ -0,0 +1,9 @@
+import { foo } from 'foo'
+import { bar } from '@scope/bar/lib/bar.js'
+// Only `.zip` archives are unsupported, not paths that happen to contain `.zip`.
+import { zipUtils } from '@scope/x.zip-utils'
+import assert from 'node:assert'
+
+assert.strictEqual(foo, 'foo')
+assert.strictEqual(bar, 'bar')
+assert.strictEqual(zipUtils, 'zipUtils')
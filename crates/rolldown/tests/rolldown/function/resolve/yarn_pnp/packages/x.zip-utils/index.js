@@ -0,0 +1 @@
+export const zipUtils = 'zipUtils'
@@ -1,3 +1,4 @@
 mod errors;
 mod issues;
 mod plugin;
+mod topics;
@@ -1 +1,3 @@
+mod options_hooks;
+mod output_hooks;
 mod plugin_context;
@@ -0,0 +1,3 @@
+import ext from 'ext'
+
+console.log(__MODE__, ext)
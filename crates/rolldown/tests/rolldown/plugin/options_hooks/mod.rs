@@ -0,0 +1,96 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerBuilder, BundlerOptions, InputItem, OutputFormat, OutputOptions};
+use rolldown_common::IsExternal;
+use rolldown_plugin::{HookOptionsReturn, HookOutputOptionsReturn, Plugin};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::indexmap::FxIndexMap;
+
+/// Sets `define` and `external` in the `options` hook, and `format` in the `output_options` hook.
+#[derive(Debug)]
+struct OptionsPlugin;
+
+impl Plugin for OptionsPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "OptionsPlugin".into()
+  }
+
+  async fn options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    Ok(Some(BundlerOptions {
+      define: Some(FxIndexMap::from_iter([("__MODE__".to_string(), "\"production\"".to_string())])),
+      external: Some(IsExternal::from_vec(vec!["ext".to_string()])),
+      ..options.clone()
+    }))
+  }
+
+  async fn output_options(&self, options: &OutputOptions) -> HookOutputOptionsReturn {
+    Ok(Some(OutputOptions { format: Some(OutputFormat::Cjs), ..options.clone() }))
+  }
+}
+
+/// Fails in the `options` hook.
+#[derive(Debug)]
+struct FailingOptionsPlugin;
+
+impl Plugin for FailingOptionsPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "FailingOptionsPlugin".into()
+  }
+
+  async fn options(&self, _options: &BundlerOptions) -> HookOptionsReturn {
+    Err(anyhow::anyhow!("invalid options"))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn options_hooks() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(OptionsPlugin)],
+    )
+    .await;
+}
+
+/// `Bundler::with_plugins` can't run the async hooks, so they run before the first build.
+#[tokio::test(flavor = "multi_thread")]
+async fn options_hooks_before_first_build() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(OptionsPlugin)],
+  );
+  let output = bundler.generate().await.expect("should bundle");
+  let code = output.assets[0].content_as_bytes();
+  let code = std::str::from_utf8(code).unwrap();
+  assert!(code.contains("require(\"ext\")"), "{code}");
+  assert!(code.contains("\"production\""), "{code}");
+}
+
+/// The errors of the hooks are returned when building the bundler, instead of by the first build.
+/// Building on a current thread runtime shouldn't block it.
+#[tokio::test]
+async fn options_hook_error() {
+  let ret = BundlerBuilder::default()
+    .with_options(BundlerOptions { cwd: Some(abs_file_dir!()), ..Default::default() })
+    .with_plugins(vec![Arc::new(FailingOptionsPlugin)])
+    .build_async()
+    .await;
+  let Err(errs) = ret else { panic!("Expected the `options` hook to fail") };
+  assert!(errs.to_string().contains("invalid options"), "{errs}");
+}
@@ -0,0 +1,4 @@
+mod render_chunk_after_minify;
+mod render_dynamic_import;
+mod resolve_file_url;
+mod resolve_import_meta;
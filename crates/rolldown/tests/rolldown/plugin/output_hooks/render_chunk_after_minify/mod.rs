@@ -0,0 +1,49 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookRenderChunkArgs, HookRenderChunkOutput, HookRenderChunkReturn, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct AppendPlugin;
+
+impl Plugin for AppendPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "AppendPlugin".into()
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    Ok(Some(HookRenderChunkOutput {
+      code: format!("{}\nfunction appended(value) {{\n  return value;\n}}\n", args.code),
+      map: None,
+    }))
+  }
+}
+
+/// Modules are minified before chunks are rendered, so the code added by `render_chunk` is kept
+/// as is.
+#[tokio::test(flavor = "multi_thread")]
+async fn render_chunk_after_minify() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        minify: Some(true.into()),
+        ..Default::default()
+      },
+      vec![Arc::new(AppendPlugin)],
+    )
+    .await;
+}
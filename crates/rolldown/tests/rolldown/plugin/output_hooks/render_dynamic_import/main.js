@@ -0,0 +1,2 @@
+import('./foo.js').then(console.log)
+import('external').then(console.log)
@@ -0,0 +1,56 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::IsExternal;
+use rolldown_plugin::{
+  HookRenderDynamicImportArgs, HookRenderDynamicImportOutput, HookRenderDynamicImportReturn,
+  Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Loads chunks with a custom loader, and keeps `import()` of external modules as is.
+#[derive(Debug)]
+struct RenderDynamicImportPlugin;
+
+impl Plugin for RenderDynamicImportPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "RenderDynamicImportPlugin".into()
+  }
+
+  async fn render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderDynamicImportArgs<'_>,
+  ) -> HookRenderDynamicImportReturn {
+    assert_eq!(args.chunk_id, "main.js");
+    let Some(target_chunk_id) = args.target_chunk_id else {
+      assert_eq!(args.target_module_id, "external");
+      return Ok(None);
+    };
+    assert_eq!(target_chunk_id, "foo.js");
+    Ok(Some(HookRenderDynamicImportOutput {
+      left: "loadChunk(".to_string(),
+      right: ", import.meta.url)".to_string(),
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn render_dynamic_import() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        external: Some(IsExternal::from_vec(vec!["external".to_string()])),
+        ..Default::default()
+      },
+      vec![Arc::new(RenderDynamicImportPlugin)],
+    )
+    .await;
+}
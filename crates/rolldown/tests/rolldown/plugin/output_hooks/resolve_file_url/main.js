@@ -0,0 +1 @@
+// The code is generated in the `load` hook
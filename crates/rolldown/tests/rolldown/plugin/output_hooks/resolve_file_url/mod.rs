@@ -0,0 +1,90 @@
+use std::{borrow::Cow, sync::Arc};
+
+use arcstr::ArcStr;
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{EmittedAsset, EmittedChunk};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookInjectionOutputReturn, HookLoadArgs, HookLoadOutput, HookLoadReturn,
+  HookNoopReturn, HookResolveFileUrlArgs, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use tokio::sync::Mutex;
+
+/// References an emitted asset and an emitted chunk via `import.meta.ROLLUP_FILE_URL_<referenceId>`.
+/// The url of the chunk is resolved by the plugin, and the url of the asset falls back to the default.
+#[derive(Debug, Default)]
+struct ResolveFileUrlPlugin {
+  reference_ids: Mutex<Option<(ArcStr, ArcStr)>>,
+}
+
+impl Plugin for ResolveFileUrlPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ResolveFileUrlPlugin".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    let asset = ctx.emit_file(EmittedAsset {
+      name: None,
+      original_file_name: None,
+      file_name: Some("assets/logo.svg".into()),
+      source: "<svg></svg>".to_string().into(),
+    });
+    let chunk = ctx.emit_chunk(EmittedChunk {
+      file_name: Some("worker.js".into()),
+      id: "./worker.js".to_string(),
+      ..Default::default()
+    })?;
+    *self.reference_ids.lock().await = Some((asset, chunk));
+    Ok(())
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with("main.js") {
+      return Ok(None);
+    }
+    let (asset, chunk) = self.reference_ids.lock().await.clone().unwrap();
+    Ok(Some(HookLoadOutput {
+      code: format!(
+        "console.log(import.meta.ROLLUP_FILE_URL_{asset}, import.meta.ROLLUP_FILE_URL_{chunk})"
+      ),
+      ..Default::default()
+    }))
+  }
+
+  async fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    assert_eq!(args.chunk_id, "main.js");
+    if args.file_name == "worker.js" {
+      assert_eq!(args.relative_path, "worker.js");
+      return Ok(Some(format!("\"/static/{}\"", args.file_name)));
+    }
+    assert_eq!(args.relative_path, "assets/logo.svg");
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_file_url() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(ResolveFileUrlPlugin::default())],
+    )
+    .await;
+}
@@ -0,0 +1 @@
+console.log(import.meta.env.MODE, import.meta.url, import.meta)
@@ -0,0 +1,49 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookInjectionOutputReturn, HookResolveImportMetaArgs, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Replaces `import.meta.env` and `import.meta`, and keeps `import.meta.url` as is.
+#[derive(Debug)]
+struct ResolveImportMetaPlugin;
+
+impl Plugin for ResolveImportMetaPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ResolveImportMetaPlugin".into()
+  }
+
+  async fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    assert_eq!(args.chunk_id, "main.js");
+    Ok(match args.property {
+      Some("env") => Some("{ MODE: \"production\" }".to_string()),
+      Some(_) => None,
+      None => Some(format!("{{ chunk: {:?} }}", args.chunk_id)),
+    })
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_import_meta() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(ResolveImportMetaPlugin)],
+    )
+    .await;
+}
@@ -0,0 +1,85 @@
+use std::{borrow::Cow, sync::Arc};
+
+use arcstr::ArcStr;
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{EmittedChunk, Output};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, Plugin,
+  PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use tokio::sync::Mutex;
+
+/// Emits `worker.js` with a fixed filename on build start, and `service-worker.js` once `main.js` is loaded.
+#[derive(Debug, Default)]
+struct EmitChunkPlugin {
+  reference_ids: Mutex<Vec<(ArcStr, &'static str)>>,
+}
+
+impl Plugin for EmitChunkPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "EmitChunkPlugin".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    let reference_id = ctx.emit_chunk(EmittedChunk {
+      file_name: Some("worker.js".into()),
+      id: "./worker.js".to_string(),
+      ..Default::default()
+    })?;
+    self.reference_ids.lock().await.push((reference_id, "worker.js"));
+    Ok(())
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id.ends_with("main.js") {
+      let reference_id = ctx.emit_chunk(EmittedChunk {
+        name: Some("sw".into()),
+        id: "./service-worker.js".to_string(),
+        importer: Some(args.id.to_string()),
+        ..Default::default()
+      })?;
+      self.reference_ids.lock().await.push((reference_id, "sw.js"));
+    }
+    Ok(None)
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    args: &mut HookGenerateBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    for (reference_id, expected) in self.reference_ids.lock().await.iter() {
+      let file_name = ctx.get_file_name(reference_id);
+      assert_eq!(file_name.as_str(), *expected);
+      assert!(args
+        .bundle
+        .iter()
+        .any(|output| matches!(output, Output::Chunk(chunk) if chunk.filename == file_name)));
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_chunk() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(EmitChunkPlugin::default())],
+    )
+    .await;
+}
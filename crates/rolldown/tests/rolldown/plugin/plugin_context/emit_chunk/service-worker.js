@@ -0,0 +1 @@
+self.addEventListener('install', () => console.log('installed'))
@@ -0,0 +1,54 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, LogPosition, Plugin, PluginContextLog,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct LintPlugin;
+
+impl Plugin for LintPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LintPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let offset = args.code.find("console").expect("should have console");
+    Err(ctx.error(PluginContextLog {
+      message: "Unexpected console statement".to_string(),
+      code: Some("NO_CONSOLE".to_string()),
+      pos: Some(LogPosition::Offset(offset)),
+      ..Default::default()
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn error() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta {
+    expect_executed: false,
+    expect_error: true,
+    ..Default::default()
+  })
+  .run_with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      ..Default::default()
+    },
+    vec![Arc::new(LintPlugin)],
+  )
+  .await;
+}
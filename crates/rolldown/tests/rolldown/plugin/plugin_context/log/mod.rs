@@ -0,0 +1,61 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, OnLog};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, LogPosition, Plugin, PluginContextLog,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct LintPlugin;
+
+impl Plugin for LintPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LintPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    ctx.warn(PluginContextLog {
+      message: "Unexpected console statement".to_string(),
+      code: Some("NO_CONSOLE".to_string()),
+      pos: Some(LogPosition::LineColumn { line: 3, column: 0 }),
+      ..Default::default()
+    });
+    ctx.warn(PluginContextLog {
+      message: "Should be dropped by `on_log`".to_string(),
+      code: Some("IGNORED".to_string()),
+      ..Default::default()
+    });
+    ctx.info(format!("Linted {} bytes", args.code.len()));
+    ctx.debug("Should be dropped by `log_level`");
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn log() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        on_log: Some(OnLog::new(Arc::new(|log| {
+          let severity = (log.plugin_code() != Some("IGNORED")).then(|| log.severity());
+          Box::pin(async move { Ok(severity) })
+        }))),
+        ..Default::default()
+      },
+      vec![Arc::new(LintPlugin)],
+    )
+    .await;
+}
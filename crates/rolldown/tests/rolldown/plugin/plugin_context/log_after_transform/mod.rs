@@ -0,0 +1,84 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, LogPosition, Plugin,
+  PluginContextLog, SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use string_wizard::{MagicString, SourceMapOptions};
+
+#[derive(Debug)]
+struct BannerPlugin;
+
+impl Plugin for BannerPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "BannerPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let mut magic_string = MagicString::new(args.code);
+    magic_string.prepend("const banner = 'banner'\nconsole.log(banner)\n");
+    Ok(Some(HookTransformOutput {
+      code: Some(magic_string.to_string()),
+      map: Some(
+        magic_string
+          .source_map(SourceMapOptions { hires: string_wizard::Hires::True, ..Default::default() }),
+      ),
+      ..Default::default()
+    }))
+  }
+}
+
+#[derive(Debug)]
+struct LintPlugin;
+
+impl Plugin for LintPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LintPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    // Positions refer to the code passed to this hook, which starts with the banner.
+    ctx.warn(PluginContextLog {
+      message: "Unexpected console statement".to_string(),
+      code: Some("NO_CONSOLE".to_string()),
+      pos: Some(LogPosition::LineColumn { line: 5, column: 0 }),
+      ..Default::default()
+    });
+    ctx.warn(PluginContextLog {
+      message: "Unexpected const declaration".to_string(),
+      code: Some("NO_CONST".to_string()),
+      pos: args.code.rfind("const").map(LogPosition::Offset),
+      ..Default::default()
+    });
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn log_after_transform() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(BannerPlugin), Arc::new(LintPlugin)],
+    )
+    .await;
+}
@@ -0,0 +1,51 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, OnLog, OutputFormat};
+use rolldown_error::EventKind;
+use rolldown_plugin::{HookNoopReturn, HookWriteBundleArgs, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct ReportPlugin;
+
+impl Plugin for ReportPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ReportPlugin".into()
+  }
+
+  async fn write_bundle(
+    &self,
+    ctx: &PluginContext,
+    args: &mut HookWriteBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    ctx.warn(format!("Wrote {} files", args.bundle.len()));
+    Ok(())
+  }
+}
+
+/// Logs of `write_bundle` are reported, and the warnings of normalizing options go through `on_log`.
+#[tokio::test(flavor = "multi_thread")]
+async fn log_in_write_bundle() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        format: Some(OutputFormat::Iife),
+        // Warns that `iife` doesn't support code splitting.
+        inline_dynamic_imports: Some(false),
+        on_log: Some(OnLog::new(Arc::new(|log| {
+          let severity = (!matches!(log.kind(), EventKind::InvalidOption)).then(|| log.severity());
+          Box::pin(async move { Ok(severity) })
+        }))),
+        ..Default::default()
+      },
+      vec![Arc::new(ReportPlugin)],
+    )
+    .await;
+}
@@ -1 +1,7 @@
 mod custom_arg_in_resolve;
+mod emit_chunk;
+mod error;
+mod log;
+mod log_after_transform;
+mod log_in_write_bundle;
+mod parse;
@@ -0,0 +1,5 @@
+import './typed.ts'
+
+export const value = 1
+
+console.log(value)
@@ -0,0 +1,59 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, ModuleType};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, PluginContextParseOptions,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Appends the count of top-level statements to each module.
+#[derive(Debug)]
+struct CountStatementsPlugin;
+
+impl Plugin for CountStatementsPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "CountStatementsPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let options = PluginContextParseOptions {
+      id: Some(args.id.to_string()),
+      typescript: matches!(args.module_type, ModuleType::Ts),
+      ..Default::default()
+    };
+    let ast = ctx.inner.parse(args.code.as_str(), &options)?;
+    let count = ast.program().body.len();
+
+    let errors = ctx.inner.parse("const = 1", &options).expect_err("should be a syntax error");
+    assert_eq!(errors[0].kind().to_string(), "PARSE_ERROR");
+
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("{}\nconsole.log('{count} statements')\n", args.code)),
+      ..Default::default()
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parse() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(CountStatementsPlugin)],
+    )
+    .await;
+}
@@ -0,0 +1,3 @@
+const typed: number = 1
+
+console.log(typed)
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { operations } from './query.graphql'
+import { Greeting } from './greeting.proto'
+import './unused.proto'
+
+assert.deepStrictEqual(operations, ['GetUser', 'UpdateUser'])
+assert.deepStrictEqual(Greeting, { name: 'Greeting', fields: ['text', 'sender'] })
+assert.deepStrictEqual(globalThis.registeredMessages, [Greeting])
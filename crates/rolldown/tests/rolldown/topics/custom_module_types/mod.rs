@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use oxc::span::SourceType;
+use rolldown::{
+  BundlerOptions, CustomModuleContent, CustomModuleTypeArgs, CustomModuleTypeHandler,
+  CustomModuleTypeOutput, CustomModuleTypes, InputItem, ModuleType,
+};
+use rolldown_ecmascript::EcmaCompiler;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rustc_hash::FxHashMap;
+
+/// Exports the names of the operations in a `.graphql` file as a lazy exported object.
+#[derive(Debug)]
+struct GraphqlHandler;
+
+impl CustomModuleTypeHandler for GraphqlHandler {
+  fn to_ecmascript(
+    &self,
+    args: CustomModuleTypeArgs<'_>,
+  ) -> anyhow::Result<CustomModuleTypeOutput> {
+    let source = args.source.try_into_string()?;
+    let operations = source
+      .lines()
+      .filter_map(|line| {
+        let (keyword, rest) = line.split_once(' ')?;
+        matches!(keyword, "query" | "mutation" | "subscription")
+          .then(|| format!("{:?}", rest.trim_end_matches(['{', ' '])))
+      })
+      .collect::<Vec<_>>();
+    Ok(CustomModuleTypeOutput {
+      content: CustomModuleContent::Source(format!(
+        "{{ operations: [{}] }}",
+        operations.join(", ")
+      )),
+      lazy_export: true,
+    })
+  }
+}
+
+/// Exports and registers each message of a `.proto` file. The registration is considered side effect free,
+/// so messages of unused files are not registered.
+#[derive(Debug)]
+struct ProtoHandler;
+
+impl CustomModuleTypeHandler for ProtoHandler {
+  fn to_ecmascript(
+    &self,
+    args: CustomModuleTypeArgs<'_>,
+  ) -> anyhow::Result<CustomModuleTypeOutput> {
+    let source = args.source.try_into_string()?;
+    let mut code = String::new();
+    let mut message = None;
+    let mut fields = vec![];
+    for line in source.lines().map(str::trim) {
+      if let Some(name) = line.strip_prefix("message ") {
+        message = Some(name.trim_end_matches(['{', ' ']).to_string());
+      } else if line == "}" {
+        let name = message.take().unwrap_or_default();
+        code.push_str(&format!(
+          "export const {name} = {{ name: {name:?}, fields: [{}] }};\n(globalThis.registeredMessages ??= []).push({name});\n",
+          std::mem::take(&mut fields).join(", ")
+        ));
+      } else if let Some(field) = line.split_whitespace().nth(1) {
+        fields.push(format!("{field:?}"));
+      }
+    }
+    let ast = EcmaCompiler::parse(args.stable_id, code, SourceType::mjs())
+      .map_err(|_| anyhow::format_err!("Failed to parse the code generated for {}", args.id))?;
+    Ok(CustomModuleTypeOutput { content: CustomModuleContent::Ast(ast), lazy_export: false })
+  }
+
+  fn side_effects(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn custom_module_types() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      module_types: Some(FxHashMap::from_iter([
+        (".graphql".to_string(), ModuleType::Custom("graphql".to_string())),
+        (".proto".to_string(), ModuleType::Custom("proto".to_string())),
+      ])),
+      custom_module_types: Some(CustomModuleTypes::from_iter([
+        ("graphql", Arc::new(GraphqlHandler) as Arc<dyn CustomModuleTypeHandler>),
+        ("proto", Arc::new(ProtoHandler)),
+      ])),
+      ..Default::default()
+    })
+    .await;
+}
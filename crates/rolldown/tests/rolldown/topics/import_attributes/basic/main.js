@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import data from './data.data' with { type: 'json' }
+import message from './message.md' with { type: 'text' }
+import bytes from './bytes.md' with { type: 'bytes' }
+
+assert.deepStrictEqual(data, { name: 'rolldown' })
+assert.strictEqual(message, 'hello')
+assert.deepStrictEqual(Array.from(bytes), [104, 101, 108, 108, 111])
+
+import('./data.data', { with: { type: 'json' } }).then((mod) => {
+  assert.deepStrictEqual(mod.default, { name: 'rolldown' })
+})
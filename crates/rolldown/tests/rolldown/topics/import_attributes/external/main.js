@@ -0,0 +1,4 @@
+import config from './config.json' with { type: 'json' }
+import * as styles from './styles.css' with { type: 'css' }
+
+console.log(config, styles)
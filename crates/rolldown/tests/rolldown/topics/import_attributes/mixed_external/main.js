@@ -0,0 +1,5 @@
+import data from './data.json' with { type: 'json' }
+import { raw } from './data.json'
+import './other.js'
+
+console.log(data, raw)
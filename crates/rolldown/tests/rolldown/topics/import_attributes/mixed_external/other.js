@@ -0,0 +1,3 @@
+import { value } from './data.json' with { type: 'json' }
+
+console.log(value)
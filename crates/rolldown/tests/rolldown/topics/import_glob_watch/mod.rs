@@ -0,0 +1,71 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::Duration,
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, Watcher};
+use rolldown_common::{BundleEvent, WatcherEvent};
+use tokio::sync::Mutex;
+
+/// Wait for the watcher to finish a build. Returns `false` if it doesn't finish in time.
+async fn wait_for_bundle_end(watcher: &Watcher) -> bool {
+  let emitter = watcher.emitter();
+  let rx = emitter.rx.lock().await;
+  while let Ok(event) = rx.recv_timeout(Duration::from_secs(10)) {
+    match event {
+      WatcherEvent::Event(BundleEvent::BundleEnd(_)) => return true,
+      WatcherEvent::Event(BundleEvent::Error(errs)) => panic!("{:?}", errs.diagnostics),
+      _ => {}
+    }
+  }
+  false
+}
+
+fn read_output(cwd: &Path) -> String {
+  std::fs::read_to_string(cwd.join("dist/main.js")).unwrap()
+}
+
+/// Adding a file matching `import.meta.glob` should rebuild the importer, even though the file
+/// isn't imported yet. The watcher blocks a worker thread while waiting for changes, so more
+/// workers are needed.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn watch() {
+  let cwd: PathBuf =
+    std::env::temp_dir().join(format!("rolldown-import-glob-watch-{}", std::process::id()));
+  std::fs::create_dir_all(cwd.join("routes")).unwrap();
+  let cwd = cwd.canonicalize().unwrap();
+  std::fs::write(cwd.join("main.js"), "export default import.meta.glob('./routes/*.js')\n")
+    .unwrap();
+  std::fs::write(cwd.join("routes/a.js"), "export default 'a'\n").unwrap();
+
+  let bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+  let watcher = Arc::new(Watcher::new(vec![Arc::new(Mutex::new(bundler))], None).unwrap());
+  let handle = tokio::spawn({
+    let watcher = Arc::clone(&watcher);
+    async move { watcher.start().await }
+  });
+
+  assert!(wait_for_bundle_end(&watcher).await);
+  let output = read_output(&cwd);
+  std::fs::write(cwd.join("routes/b.js"), "export default 'b'\n").unwrap();
+  let is_rebuilt = wait_for_bundle_end(&watcher).await;
+  let rebuilt_output = read_output(&cwd);
+
+  // Close the watcher before asserting, otherwise a failed assertion hangs the blocked runtime.
+  watcher.close().await.unwrap();
+  handle.await.unwrap();
+  std::fs::remove_dir_all(&cwd).unwrap();
+
+  assert!(output.contains("./routes/a.js"));
+  assert!(!output.contains("./routes/b.js"));
+  assert!(is_rebuilt, "Adding `routes/b.js` should trigger a rebuild");
+  assert!(rebuilt_output.contains("./routes/b.js"));
+}
@@ -0,0 +1,19 @@
+import assert from 'node:assert'
+
+const before = 'before'
+
+function sum() {
+  var a = 1, b = 2
+  return a + b
+}
+
+const after = () => {
+  const value = 'after'
+  return value
+}
+
+assert.equal(before, 'before')
+assert.equal(sum(), 3)
+assert.equal(after(), 'after')
+assert.equal(sum.name, 'sum')
+assert.equal(after.name, 'after')
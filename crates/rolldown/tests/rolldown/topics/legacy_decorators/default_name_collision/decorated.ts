@@ -0,0 +1,11 @@
+const _default = 'local'
+
+function decorate(cls: any) {
+  // `_default1` is a global, which the binding of the anonymous class must not shadow.
+  cls.decorated = _default1 === 'global'
+}
+
+@decorate
+export default class {}
+
+export { _default as local }
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+
+globalThis._default1 = 'global'
+const { default: Decorated, local } = await import('./decorated')
+
+assert.strictEqual(Decorated.decorated, true)
+assert.strictEqual(local, 'local')
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import { Service, calls } from './service'
+
+assert.deepStrictEqual(calls, [
+  'run 1 second',
+  'run 0 first',
+  'static create 0',
+  'constructor 1',
+  'constructor 0',
+])
+assert.strictEqual(new Service('label', 1).run('a', 'b'), 'a b')
+assert.strictEqual(Service.create(2), 2)
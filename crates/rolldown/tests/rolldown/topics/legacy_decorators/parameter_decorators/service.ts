@@ -0,0 +1,26 @@
+export const calls: string[] = []
+
+function param(label: string) {
+  return (_target: any, key: string | undefined, index: number) => {
+    calls.push(`${key ?? 'constructor'} ${index} ${label}`.trim())
+  }
+}
+
+function indexOnly(_target: any, key: string | undefined, index: number) {
+  calls.push(`${key === undefined ? 'constructor' : `static ${key}`} ${index}`)
+}
+
+export class Service {
+  constructor(
+    @indexOnly private label: string,
+    @indexOnly readonly count: number,
+  ) {}
+
+  run(@param('first') first: string, @param('second') second: string) {
+    return `${first} ${second}`
+  }
+
+  static create(@indexOnly count: number) {
+    return count
+  }
+}
@@ -0,0 +1,2 @@
+mod custom_module_types;
+mod import_glob_watch;
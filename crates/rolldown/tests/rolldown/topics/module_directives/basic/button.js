@@ -0,0 +1,6 @@
+'use client'
+import { format } from './utils.js'
+
+export function Button() {
+  return format('button')
+}
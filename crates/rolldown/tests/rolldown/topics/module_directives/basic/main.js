@@ -0,0 +1,6 @@
+import { Button } from './button.js'
+import { format } from './utils.js'
+
+export function render() {
+  return [Button, format('main')]
+}
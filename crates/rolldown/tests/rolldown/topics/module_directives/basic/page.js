@@ -0,0 +1,4 @@
+"use client";
+import { format } from './utils.js'
+
+export const page = format('page')
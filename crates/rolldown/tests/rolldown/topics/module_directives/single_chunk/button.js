@@ -0,0 +1,3 @@
+'use client'
+
+export function Button() {}
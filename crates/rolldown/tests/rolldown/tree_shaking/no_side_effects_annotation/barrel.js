@@ -0,0 +1,2 @@
+export { createStore as store } from './lib.js'
+export * from './lib.js'
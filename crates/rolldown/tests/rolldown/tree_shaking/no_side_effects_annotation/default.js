@@ -0,0 +1,4 @@
+/* @__NO_SIDE_EFFECTS__ */
+export default function () {
+  console.log('defineComponent')
+}
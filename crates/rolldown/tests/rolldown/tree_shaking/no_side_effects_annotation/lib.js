@@ -0,0 +1,21 @@
+/* @__NO_SIDE_EFFECTS__ */
+function createStore(name) {
+  console.log('createStore', name)
+  return { name }
+}
+
+/* #__NO_SIDE_EFFECTS__ */ export function createSignal(value) {
+  console.log('createSignal', value)
+  return { value }
+}
+
+export const createEffect = /* @__NO_SIDE_EFFECTS__ */ (fn) => {
+  console.log('createEffect')
+  return fn
+}
+
+export function impure() {
+  console.log('impure')
+}
+
+export { createStore }
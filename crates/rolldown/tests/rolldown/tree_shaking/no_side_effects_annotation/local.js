@@ -0,0 +1,8 @@
+const unused = defineLocal()
+
+/* @__NO_SIDE_EFFECTS__ */
+function defineLocal() {
+  console.log('defineLocal')
+}
+
+export const localValue = 'local'
@@ -0,0 +1,16 @@
+import { createSignal, createEffect, impure } from './lib.js'
+import { store } from './barrel.js'
+import defineComponent from './default.js'
+import { localValue } from './local.js'
+
+// Removed, since the annotated functions are side effect free
+store('unused')
+createSignal(0)
+createEffect(() => {})
+const component = defineComponent()
+
+// Kept, since the argument has side effects
+createSignal(impure())
+
+impure()
+console.log(localValue, createSignal(1))
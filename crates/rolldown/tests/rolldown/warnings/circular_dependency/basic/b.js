@@ -0,0 +1,5 @@
+import { a } from './a.js'
+export * from './a.js'
+
+export const b = 'b'
+export const getA = () => a
@@ -0,0 +1,4 @@
+import { a } from './a.js'
+import { lib } from './node_modules/lib/index.js'
+
+console.log(a, lib)
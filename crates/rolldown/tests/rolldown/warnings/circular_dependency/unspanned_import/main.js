@@ -0,0 +1,5 @@
+import { a } from './a.js'
+
+export const value = 'main'
+
+console.log(a)
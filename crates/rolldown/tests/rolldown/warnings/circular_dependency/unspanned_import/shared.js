@@ -0,0 +1,3 @@
+import { value } from './main.js'
+
+export const shared = () => value
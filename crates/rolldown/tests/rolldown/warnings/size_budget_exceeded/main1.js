@@ -0,0 +1,4 @@
+import { shared } from './shared.js'
+import { table } from './table.js'
+
+console.log(shared, table)
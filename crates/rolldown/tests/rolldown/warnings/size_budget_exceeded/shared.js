@@ -0,0 +1 @@
+export const shared = 'This string is shared by both entries and makes the shared chunk exceed its budget.'
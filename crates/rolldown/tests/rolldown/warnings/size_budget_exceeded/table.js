@@ -0,0 +1,8 @@
+export const table = [
+  ['alpha', 1],
+  ['beta', 2],
+  ['gamma', 3],
+  ['delta', 4],
+  ['epsilon', 5],
+  ['zeta', 6],
+]
@@ -131,28 +131,28 @@ impl Bundler {
   pub async fn write_impl(&self) -> napi::Result<BindingOutputs> {
     let mut bundler_core = self.inner.lock().await;
 
-    let outputs = match bundler_core.write().await {
+    let mut outputs = match bundler_core.write().await {
       Ok(outputs) => outputs,
       Err(errs) => return Ok(self.handle_errors(errs.into_vec())),
     };
 
-    self.handle_warnings(outputs.warnings).await;
+    self.handle_warnings(std::mem::take(&mut outputs.warnings)).await;
 
-    Ok(outputs.assets.into())
+    Ok(BindingOutputs::from_bundle_output(outputs))
   }
 
   #[allow(clippy::significant_drop_tightening)]
   pub async fn generate_impl(&self) -> napi::Result<BindingOutputs> {
     let mut bundler_core = self.inner.lock().await;
 
-    let bundle_output = match bundler_core.generate().await {
+    let mut bundle_output = match bundler_core.generate().await {
       Ok(output) => output,
       Err(errs) => return Ok(self.handle_errors(errs.into_vec())),
     };
 
-    self.handle_warnings(bundle_output.warnings).await;
+    self.handle_warnings(std::mem::take(&mut bundle_output.warnings)).await;
 
-    Ok(bundle_output.assets.into())
+    Ok(BindingOutputs::from_bundle_output(bundle_output))
   }
 
   #[allow(clippy::significant_drop_tightening)]
@@ -160,6 +160,7 @@ impl Bundler {
     let mut bundler_core = self.inner.lock().await;
 
     handle_result(bundler_core.close().await)?;
+    self.handle_warnings(bundler_core.take_warnings()).await;
 
     Ok(())
   }
@@ -193,7 +194,7 @@ impl Bundler {
       for warning in warnings {
         on_log
           .call_async((
-            BindingLogLevel::Warn.to_string(),
+            BindingLogLevel::from(warning.severity()).to_string(),
             BindingLog {
               code: warning.kind().to_string(),
               message: warning
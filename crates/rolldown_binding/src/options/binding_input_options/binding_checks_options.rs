@@ -1,11 +1,118 @@
+use napi::Either;
+
 #[napi_derive::napi(object)]
 #[derive(Debug, Default)]
 pub struct BindingChecksOptions {
-  pub circular_dependency: Option<bool>,
+  pub circular_dependency: Option<Either<bool, BindingCircularDependencyOptions>>,
+  pub size_budgets: Option<BindingSizeBudgetsOptions>,
 }
 
 impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
   fn from(value: BindingChecksOptions) -> Self {
-    Self { circular_dependency: value.circular_dependency }
+    Self {
+      circular_dependency: value.circular_dependency.map(|check| match check {
+        Either::A(value) => rolldown_common::CircularDependencyCheck::Boolean(value),
+        Either::B(options) => rolldown_common::CircularDependencyCheck::Option(options.into()),
+      }),
+      size_budgets: value.size_budgets.map(Into::into),
+    }
+  }
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Default)]
+pub struct BindingCircularDependencyOptions {
+  pub ignore: Option<Vec<String>>,
+  pub mode: Option<BindingCircularDependencyMode>,
+}
+
+impl From<BindingCircularDependencyOptions> for rolldown_common::CircularDependencyOptions {
+  fn from(value: BindingCircularDependencyOptions) -> Self {
+    Self {
+      ignore: value.ignore,
+      mode: value.mode.map(|mode| match mode {
+        BindingCircularDependencyMode::Warn => rolldown_common::CircularDependencyMode::Warn,
+        BindingCircularDependencyMode::ErrorOnUnsafe => {
+          rolldown_common::CircularDependencyMode::ErrorOnUnsafe
+        }
+        BindingCircularDependencyMode::Error => rolldown_common::CircularDependencyMode::Error,
+      }),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi_derive::napi(string_enum)]
+pub enum BindingCircularDependencyMode {
+  #[napi(value = "warn")]
+  Warn,
+  #[napi(value = "errorOnUnsafe")]
+  ErrorOnUnsafe,
+  #[napi(value = "error")]
+  Error,
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Default)]
+pub struct BindingSizeBudgetsOptions {
+  pub entry: Option<BindingSizeLimit>,
+  pub chunks: Option<Vec<BindingChunkSizeBudget>>,
+  pub initial: Option<BindingSizeLimit>,
+  pub severity: Option<BindingSizeBudgetSeverity>,
+}
+
+impl From<BindingSizeBudgetsOptions> for rolldown_common::SizeBudgetsOptions {
+  fn from(value: BindingSizeBudgetsOptions) -> Self {
+    Self {
+      entry: value.entry.map(Into::into),
+      chunks: value.chunks.map(|chunks| chunks.into_iter().map(Into::into).collect()),
+      initial: value.initial.map(Into::into),
+      severity: value.severity.map(|severity| match severity {
+        BindingSizeBudgetSeverity::Warning => rolldown_common::SizeBudgetSeverity::Warning,
+        BindingSizeBudgetSeverity::Error => rolldown_common::SizeBudgetSeverity::Error,
+      }),
+    }
+  }
+}
+
+/// Sizes are in bytes.
+#[napi_derive::napi(object)]
+#[derive(Debug, Default)]
+pub struct BindingSizeLimit {
+  pub raw: Option<f64>,
+  pub gzip: Option<f64>,
+  pub brotli: Option<f64>,
+}
+
+impl From<BindingSizeLimit> for rolldown_common::SizeLimit {
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  fn from(value: BindingSizeLimit) -> Self {
+    Self {
+      raw: value.raw.map(|size| size as u64),
+      gzip: value.gzip.map(|size| size as u64),
+      brotli: value.brotli.map(|size| size as u64),
+    }
   }
 }
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingChunkSizeBudget {
+  pub pattern: String,
+  pub limit: BindingSizeLimit,
+}
+
+impl From<BindingChunkSizeBudget> for rolldown_common::ChunkSizeBudget {
+  fn from(value: BindingChunkSizeBudget) -> Self {
+    Self { pattern: value.pattern, limit: value.limit.into() }
+  }
+}
+
+#[derive(Debug)]
+#[napi_derive::napi(string_enum)]
+pub enum BindingSizeBudgetSeverity {
+  #[napi(value = "warning")]
+  Warning,
+  #[napi(value = "error")]
+  Error,
+}
@@ -16,6 +16,8 @@ pub struct BindingResolveOptions {
   pub modules: Option<Vec<String>>,
   pub symlinks: Option<bool>,
   pub tsconfig_filename: Option<String>,
+  pub tsconfig_auto: Option<bool>,
+  pub yarn_pnp: Option<bool>,
 }
 
 impl From<BindingResolveOptions> for rolldown::ResolveOptions {
@@ -39,6 +41,8 @@ impl From<BindingResolveOptions> for rolldown::ResolveOptions {
       modules: value.modules,
       symlinks: value.symlinks,
       tsconfig_filename: value.tsconfig_filename,
+      tsconfig_auto: value.tsconfig_auto,
+      yarn_pnp: value.yarn_pnp,
     }
   }
 }
@@ -0,0 +1,49 @@
+use rolldown::{MinifyOptions, MinifyOptionsObject, RawMinifyOptions};
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingMinifyOptions {
+  pub compress: Option<bool>,
+  pub mangle: Option<bool>,
+  pub whitespace: Option<bool>,
+  pub drop_console: Option<bool>,
+  pub drop_debugger: Option<bool>,
+  pub pure_funcs: Option<Vec<String>>,
+  pub keep_fnames: Option<bool>,
+  pub keep_classnames: Option<bool>,
+  pub target: Option<String>,
+}
+
+impl TryFrom<BindingMinifyOptions> for RawMinifyOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingMinifyOptions) -> anyhow::Result<Self> {
+    Ok(Self::Option(MinifyOptionsObject {
+      compress: value.compress,
+      mangle: value.mangle,
+      whitespace: value.whitespace,
+      drop_console: value.drop_console,
+      drop_debugger: value.drop_debugger,
+      pure_funcs: value.pure_funcs,
+      keep_fnames: value.keep_fnames,
+      keep_classnames: value.keep_classnames,
+      target: value.target.as_deref().map(std::str::FromStr::from_str).transpose()?,
+    }))
+  }
+}
+
+impl From<&MinifyOptions> for BindingMinifyOptions {
+  fn from(value: &MinifyOptions) -> Self {
+    Self {
+      compress: Some(value.compress),
+      mangle: Some(value.mangle),
+      whitespace: Some(value.whitespace),
+      drop_console: Some(value.drop_console),
+      drop_debugger: Some(value.drop_debugger),
+      pure_funcs: Some(value.pure_funcs.clone()),
+      keep_fnames: Some(value.keep_fnames),
+      keep_classnames: Some(value.keep_classnames),
+      target: Some(value.target.to_string()),
+    }
+  }
+}
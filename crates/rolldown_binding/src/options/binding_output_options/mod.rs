@@ -1,4 +1,5 @@
 mod binding_advanced_chunks_options;
+mod binding_minify_options;
 mod binding_pre_rendered_chunk;
 
 use derive_more::Debug;
@@ -7,6 +8,7 @@ use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
 use binding_advanced_chunks_options::BindingAdvancedChunksOptions;
+pub use binding_minify_options::BindingMinifyOptions;
 use binding_pre_rendered_chunk::PreRenderedChunk;
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
@@ -107,10 +109,13 @@ pub struct BindingOutputOptions {
   // validate: boolean;
 
   // --- Enhanced options
-  pub minify: Option<bool>,
+  #[napi(ts_type = "boolean | BindingMinifyOptions")]
+  pub minify: Option<Either<bool, BindingMinifyOptions>>,
   pub advanced_chunks: Option<BindingAdvancedChunksOptions>,
   #[napi(ts_type = "'none' | 'preserve-legal'")]
   pub comments: Option<String>,
+  #[napi(ts_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
+  pub legal_comments: Option<String>,
   pub polyfill_require: Option<bool>,
   pub target: Option<String>,
 }
@@ -4,8 +4,11 @@ use napi_derive::napi;
 
 use rolldown_plugin::PluginContext;
 
+#[cfg(feature = "estree")]
+use super::types::binding_plugin_context_parse_options::BindingPluginContextParseOptions;
 use super::types::{
-  binding_emitted_asset::BindingEmittedAsset, binding_hook_side_effects::BindingHookSideEffects,
+  binding_emitted_asset::BindingEmittedAsset, binding_emitted_chunk::BindingEmittedChunk,
+  binding_hook_side_effects::BindingHookSideEffects,
   binding_plugin_context_resolve_options::BindingPluginContextResolveOptions,
 };
 
@@ -78,8 +81,22 @@ impl BindingPluginContext {
   }
 
   #[napi]
-  pub fn get_file_name(&self, reference_id: String) -> String {
-    self.inner.get_file_name(reference_id.as_str()).to_string()
+  pub fn emit_chunk(&self, file: BindingEmittedChunk) -> napi::Result<String> {
+    let chunk = file.try_into().map_err(napi::Error::from_reason)?;
+    self
+      .inner
+      .emit_chunk(chunk)
+      .map(|reference_id| reference_id.to_string())
+      .map_err(|err| napi::Error::from_reason(err.to_string()))
+  }
+
+  #[napi]
+  pub fn get_file_name(&self, reference_id: String) -> napi::Result<String> {
+    self
+      .inner
+      .try_get_file_name(reference_id.as_str())
+      .map(|file_name| file_name.to_string())
+      .map_err(napi::Error::from_reason)
   }
 
   #[napi]
@@ -98,6 +115,28 @@ impl BindingPluginContext {
   }
 }
 
+#[cfg(feature = "estree")]
+#[napi]
+impl BindingPluginContext {
+  /// Returns the ESTree-compatible AST as JSON. Positions are utf16 indices of `code`, like in js strings.
+  #[napi]
+  pub fn parse(
+    &self,
+    code: String,
+    options: Option<BindingPluginContextParseOptions>,
+  ) -> napi::Result<String> {
+    let converter = crate::utils::utf8_to_utf16::Utf8ToUtf16::new(&code);
+    let mut ast = self
+      .inner
+      .parse(code, &options.map(Into::into).unwrap_or_default())
+      .map_err(|errs| napi::Error::from_reason(errs.to_string()))?;
+    if let Some(mut converter) = converter {
+      ast.program.with_mut(|fields| converter.convert_program(fields.program));
+    }
+    Ok(ast.program.with_dependent(|_owner, dep| dep.program.to_json()))
+  }
+}
+
 impl From<PluginContext> for BindingPluginContext {
   fn from(inner: PluginContext) -> Self {
     Self { inner }
@@ -18,8 +18,12 @@ use super::{
     binding_hook_filter::{BindingGeneralHookFilter, BindingTransformHookFilter},
     binding_hook_load_output::BindingHookLoadOutput,
     binding_hook_render_chunk_output::BindingHookRenderChunkOutput,
+    binding_hook_render_dynamic_import_args::BindingHookRenderDynamicImportArgs,
+    binding_hook_render_dynamic_import_output::BindingHookRenderDynamicImportOutput,
+    binding_hook_resolve_file_url_args::BindingHookResolveFileUrlArgs,
     binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
     binding_hook_resolve_id_output::BindingHookResolveIdOutput,
+    binding_hook_resolve_import_meta_args::BindingHookResolveImportMetaArgs,
     binding_hook_transform_output::BindingHookTransformOutput,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
   },
@@ -101,6 +105,33 @@ pub struct BindingPluginOptions {
   >,
   pub build_end_meta: Option<BindingPluginHookMeta>,
 
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, args: BindingHookRenderDynamicImportArgs) => MaybePromise<VoidNullable<BindingHookRenderDynamicImportOutput>>"
+  )]
+  pub render_dynamic_import: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, BindingHookRenderDynamicImportArgs),
+      Option<BindingHookRenderDynamicImportOutput>,
+    >,
+  >,
+  pub render_dynamic_import_meta: Option<BindingPluginHookMeta>,
+
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, args: BindingHookResolveFileUrlArgs) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub resolve_file_url: Option<
+    MaybeAsyncJsCallback<(BindingPluginContext, BindingHookResolveFileUrlArgs), Option<String>>,
+  >,
+  pub resolve_file_url_meta: Option<BindingPluginHookMeta>,
+
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, args: BindingHookResolveImportMetaArgs) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub resolve_import_meta: Option<
+    MaybeAsyncJsCallback<(BindingPluginContext, BindingHookResolveImportMetaArgs), Option<String>>,
+  >,
+  pub resolve_import_meta_meta: Option<BindingPluginHookMeta>,
+
   #[napi(
     ts_type = "(ctx: BindingPluginContext, code: string, chunk: RenderedChunk, opts: BindingNormalizedOptions) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>"
   )]
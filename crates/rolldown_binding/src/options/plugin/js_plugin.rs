@@ -363,6 +363,54 @@ impl Plugin for JsPlugin {
     self.footer_meta.as_ref().map(Into::into)
   }
 
+  async fn render_dynamic_import(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookRenderDynamicImportArgs<'_>,
+  ) -> rolldown_plugin::HookRenderDynamicImportReturn {
+    if let Some(cb) = &self.render_dynamic_import {
+      Ok(cb.await_call((ctx.clone().into(), args.into())).await?.map(Into::into))
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn render_dynamic_import_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.render_dynamic_import_meta.as_ref().map(Into::into)
+  }
+
+  async fn resolve_file_url(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveFileUrlArgs<'_>,
+  ) -> rolldown_plugin::HookInjectionOutputReturn {
+    if let Some(cb) = &self.resolve_file_url {
+      Ok(cb.await_call((ctx.clone().into(), args.into())).await?)
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn resolve_file_url_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.resolve_file_url_meta.as_ref().map(Into::into)
+  }
+
+  async fn resolve_import_meta(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveImportMetaArgs<'_>,
+  ) -> rolldown_plugin::HookInjectionOutputReturn {
+    if let Some(cb) = &self.resolve_import_meta {
+      Ok(cb.await_call((ctx.clone().into(), args.into())).await?)
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.resolve_import_meta_meta.as_ref().map(Into::into)
+  }
+
   async fn render_chunk(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -0,0 +1,36 @@
+use rolldown_common::PreserveEntrySignatures;
+
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingEmittedChunk {
+  pub name: Option<String>,
+  pub file_name: Option<String>,
+  pub id: String,
+  pub importer: Option<String>,
+  #[napi(ts_type = "'strict' | 'allow-extension' | 'exports-only' | 'false'")]
+  pub preserve_signature: Option<String>,
+}
+
+impl TryFrom<BindingEmittedChunk> for rolldown_common::EmittedChunk {
+  type Error = String;
+
+  fn try_from(value: BindingEmittedChunk) -> Result<Self, Self::Error> {
+    let preserve_signature = value
+      .preserve_signature
+      .map(|preserve_signature| match preserve_signature.as_str() {
+        "strict" => Ok(PreserveEntrySignatures::Strict),
+        "allow-extension" => Ok(PreserveEntrySignatures::AllowExtension),
+        "exports-only" => Ok(PreserveEntrySignatures::ExportsOnly),
+        "false" => Ok(PreserveEntrySignatures::False),
+        _ => Err(format!("Invalid preserveSignature: {preserve_signature}")),
+      })
+      .transpose()?;
+    Ok(Self {
+      name: value.name.map(Into::into),
+      file_name: value.file_name.map(Into::into),
+      id: value.id,
+      importer: value.importer,
+      preserve_signature,
+    })
+  }
+}
@@ -0,0 +1,26 @@
+use crate::types::binding_normalized_options::format_to_string;
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingHookRenderDynamicImportArgs {
+  pub module_id: String,
+  pub target_module_id: String,
+  pub chunk_id: String,
+  pub target_chunk_id: Option<String>,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+}
+
+impl From<&rolldown_plugin::HookRenderDynamicImportArgs<'_>>
+  for BindingHookRenderDynamicImportArgs
+{
+  fn from(value: &rolldown_plugin::HookRenderDynamicImportArgs<'_>) -> Self {
+    Self {
+      module_id: value.module_id.to_string(),
+      target_module_id: value.target_module_id.to_string(),
+      chunk_id: value.chunk_id.to_string(),
+      target_chunk_id: value.target_chunk_id.map(ToString::to_string),
+      format: format_to_string(value.format),
+    }
+  }
+}
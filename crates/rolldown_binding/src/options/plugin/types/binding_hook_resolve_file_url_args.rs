@@ -0,0 +1,26 @@
+use crate::types::binding_normalized_options::format_to_string;
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingHookResolveFileUrlArgs {
+  pub reference_id: String,
+  pub file_name: String,
+  pub relative_path: String,
+  pub module_id: String,
+  pub chunk_id: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+}
+
+impl From<&rolldown_plugin::HookResolveFileUrlArgs<'_>> for BindingHookResolveFileUrlArgs {
+  fn from(value: &rolldown_plugin::HookResolveFileUrlArgs<'_>) -> Self {
+    Self {
+      reference_id: value.reference_id.to_string(),
+      file_name: value.file_name.to_string(),
+      relative_path: value.relative_path.to_string(),
+      module_id: value.module_id.to_string(),
+      chunk_id: value.chunk_id.to_string(),
+      format: format_to_string(value.format),
+    }
+  }
+}
@@ -0,0 +1,22 @@
+use crate::types::binding_normalized_options::format_to_string;
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingHookResolveImportMetaArgs {
+  pub property: Option<String>,
+  pub module_id: String,
+  pub chunk_id: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+}
+
+impl From<&rolldown_plugin::HookResolveImportMetaArgs<'_>> for BindingHookResolveImportMetaArgs {
+  fn from(value: &rolldown_plugin::HookResolveImportMetaArgs<'_>) -> Self {
+    Self {
+      property: value.property.map(ToString::to_string),
+      module_id: value.module_id.to_string(),
+      chunk_id: value.chunk_id.to_string(),
+      format: format_to_string(value.format),
+    }
+  }
+}
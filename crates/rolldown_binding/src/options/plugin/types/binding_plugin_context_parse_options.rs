@@ -0,0 +1,18 @@
+use rolldown_plugin::PluginContextParseOptions;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingPluginContextParseOptions {
+  pub typescript: Option<bool>,
+  pub jsx: Option<bool>,
+}
+
+impl From<BindingPluginContextParseOptions> for PluginContextParseOptions {
+  fn from(value: BindingPluginContextParseOptions) -> Self {
+    Self {
+      id: None,
+      typescript: value.typescript.unwrap_or_default(),
+      jsx: value.jsx.unwrap_or_default(),
+    }
+  }
+}
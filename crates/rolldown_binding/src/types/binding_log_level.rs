@@ -1,4 +1,6 @@
 use napi_derive::napi;
+use rolldown::LogLevel;
+use rolldown_error::Severity;
 use std::fmt::{self, Display, Formatter};
 
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
@@ -23,6 +25,28 @@ impl From<String> for BindingLogLevel {
   }
 }
 
+impl From<BindingLogLevel> for LogLevel {
+  fn from(value: BindingLogLevel) -> Self {
+    match value {
+      BindingLogLevel::Silent => Self::Silent,
+      BindingLogLevel::Warn => Self::Warn,
+      BindingLogLevel::Info => Self::Info,
+      BindingLogLevel::Debug => Self::Debug,
+    }
+  }
+}
+
+impl From<Severity> for BindingLogLevel {
+  fn from(value: Severity) -> Self {
+    match value {
+      // Errors never reach `onLog`, they fail the build instead.
+      Severity::Error | Severity::Warning => Self::Warn,
+      Severity::Info => Self::Info,
+      Severity::Debug => Self::Debug,
+    }
+  }
+}
+
 impl Display for BindingLogLevel {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
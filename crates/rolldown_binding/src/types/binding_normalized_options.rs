@@ -5,6 +5,8 @@ use napi_derive::napi;
 use rolldown::SharedNormalizedBundlerOptions;
 use rustc_hash::FxBuildHasher;
 
+use crate::options::BindingMinifyOptions;
+
 #[napi]
 pub struct BindingNormalizedOptions {
   inner: SharedNormalizedBundlerOptions,
@@ -117,13 +119,7 @@ impl BindingNormalizedOptions {
 
   #[napi(getter, ts_return_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
   pub fn format(&self) -> String {
-    match self.inner.format {
-      rolldown::OutputFormat::Esm => "es".to_string(),
-      rolldown::OutputFormat::Cjs => "cjs".to_string(),
-      rolldown::OutputFormat::App => "app".to_string(),
-      rolldown::OutputFormat::Iife => "iife".to_string(),
-      rolldown::OutputFormat::Umd => "umd".to_string(),
-    }
+    format_to_string(self.inner.format)
   }
 
   #[napi(getter, ts_return_type = "'default' | 'named' | 'none' | 'auto'")]
@@ -227,8 +223,12 @@ impl BindingNormalizedOptions {
   }
 
   #[napi(getter)]
-  pub fn minify(&self) -> bool {
-    self.inner.minify
+  pub fn minify(&self) -> Either<bool, BindingMinifyOptions> {
+    if self.inner.minify.is_enabled() {
+      Either::B((&self.inner.minify).into())
+    } else {
+      Either::A(false)
+    }
   }
 
   #[napi(getter)]
@@ -240,4 +240,20 @@ impl BindingNormalizedOptions {
   pub fn comments(&self) -> String {
     self.inner.comments.to_string()
   }
+
+  #[napi(getter, ts_return_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
+  pub fn legal_comments(&self) -> String {
+    self.inner.legal_comments.to_string()
+  }
+}
+
+/// The name of the format in the js options.
+pub fn format_to_string(format: rolldown::OutputFormat) -> String {
+  match format {
+    rolldown::OutputFormat::Esm => "es".to_string(),
+    rolldown::OutputFormat::Cjs => "cjs".to_string(),
+    rolldown::OutputFormat::App => "app".to_string(),
+    rolldown::OutputFormat::Iife => "iife".to_string(),
+    rolldown::OutputFormat::Umd => "umd".to_string(),
+  }
 }
@@ -1,6 +1,7 @@
 use super::{
   binding_output_asset::{BindingOutputAsset, JsOutputAsset},
   binding_output_chunk::{update_output_chunk, BindingOutputChunk, JsOutputChunk},
+  binding_plugin_timings::BindingPluginTimings,
 };
 use napi_derive::napi;
 use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
@@ -11,6 +12,7 @@ pub struct BindingOutputs {
   chunks: Vec<BindingOutputChunk>,
   assets: Vec<BindingOutputAsset>,
   error: Option<rolldown_common::OutputsDiagnostics>,
+  plugin_timings: Option<rolldown_plugin::PluginTimings>,
 }
 
 #[napi]
@@ -25,6 +27,11 @@ impl BindingOutputs {
     std::mem::take(&mut self.assets)
   }
 
+  #[napi(getter)]
+  pub fn plugin_timings(&self) -> Option<BindingPluginTimings> {
+    self.plugin_timings.as_ref().map(Into::into)
+  }
+
   #[napi(getter)]
   pub fn errors(&mut self) -> Vec<napi::Either<napi::JsError, BindingError>> {
     if let Some(rolldown_common::OutputsDiagnostics { diagnostics, cwd }) = self.error.as_ref() {
@@ -38,7 +45,11 @@ impl BindingOutputs {
 
   pub fn from_errors(diagnostics: Vec<BuildDiagnostic>, cwd: std::path::PathBuf) -> Self {
     let error = rolldown_common::OutputsDiagnostics { diagnostics, cwd };
-    Self { assets: vec![], chunks: vec![], error: Some(error) }
+    Self { assets: vec![], chunks: vec![], error: Some(error), plugin_timings: None }
+  }
+
+  pub fn from_bundle_output(output: rolldown::BundleOutput) -> Self {
+    Self { plugin_timings: output.plugin_timings, ..output.assets.into() }
   }
 }
 
@@ -54,7 +65,7 @@ impl From<Vec<rolldown_common::Output>> for BindingOutputs {
         assets.push(BindingOutputAsset::new(*asset));
       }
     });
-    Self { chunks, assets, error: None }
+    Self { chunks, assets, error: None, plugin_timings: None }
   }
 }
 
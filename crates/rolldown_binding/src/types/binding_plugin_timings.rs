@@ -0,0 +1,39 @@
+use napi_derive::napi;
+use rolldown_plugin::PluginTimings;
+
+#[napi(object)]
+pub struct BindingPluginHookTiming {
+  pub plugin: String,
+  pub hook: String,
+  pub calls: u32,
+  /// In milliseconds.
+  pub duration: f64,
+}
+
+#[napi(object)]
+pub struct BindingPluginTimings {
+  /// Sorted by `duration` in descending order.
+  pub hooks: Vec<BindingPluginHookTiming>,
+  pub dominant_plugins: Vec<String>,
+  /// The timings formatted as a table.
+  pub summary: String,
+}
+
+impl From<&PluginTimings> for BindingPluginTimings {
+  fn from(timings: &PluginTimings) -> Self {
+    Self {
+      hooks: timings
+        .hooks
+        .iter()
+        .map(|timing| BindingPluginHookTiming {
+          plugin: timing.plugin.clone(),
+          hook: timing.hook.to_string(),
+          calls: timing.calls,
+          duration: timing.duration.as_secs_f64() * 1000.0,
+        })
+        .collect(),
+      dominant_plugins: timings.dominant_plugins().into_iter().map(ToString::to_string).collect(),
+      summary: timings.to_string(),
+    }
+  }
+}
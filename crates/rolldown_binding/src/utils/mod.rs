@@ -2,6 +2,8 @@ use napi::Env;
 use rolldown_tracing::try_init_tracing;
 pub mod napi_error;
 pub mod normalize_binding_options;
+#[cfg(feature = "estree")]
+pub mod utf8_to_utf16;
 
 pub fn try_init_custom_trace_subscriber(napi_env: Env) {
   let maybe_guard = try_init_tracing();
@@ -193,13 +193,23 @@ pub fn normalize_binding_options(
     }),
     globals: normalize_globals_option(output_options.globals),
     module_types,
+    // Handlers of custom module types are only available to Rust users.
+    custom_module_types: None,
     experimental: input_options.experimental.map(|inner| ExperimentalOptions {
       strict_execution_order: inner.strict_execution_order,
+      lazy_shared_modules: inner.lazy_shared_modules,
       disable_live_bindings: inner.disable_live_bindings,
       vite_mode: inner.vite_mode,
       resolve_new_url_to_asset: inner.resolve_new_url_to_asset,
     }),
-    minify: output_options.minify,
+    minify: output_options
+      .minify
+      .map(|minify| match minify {
+        Either::A(value) => Ok(value.into()),
+        Either::B(options) => options.try_into(),
+      })
+      .transpose()
+      .map_err(|err: anyhow::Error| napi::Error::new(napi::Status::GenericFailure, err))?,
     extend: output_options.extend,
     define: input_options.define.map(FxIndexMap::from_iter),
     inject: input_options
@@ -238,10 +248,19 @@ pub fn normalize_binding_options(
         )),
       })
       .transpose()?,
+    legal_comments: output_options
+      .legal_comments
+      .as_deref()
+      .map(std::str::FromStr::from_str)
+      .transpose()?,
     drop_labels: input_options.drop_labels,
     target: output_options.target.as_deref().map(std::str::FromStr::from_str).transpose()?,
     keep_names: input_options.keep_names,
     polyfill_require: output_options.polyfill_require,
+    plugin_timings: input_options.plugin_timings,
+    log_level: Some(input_options.log_level.into()),
+    // `onLog` is called by `Bundler` of the binding, which also receives the logs of JS plugins.
+    on_log: None,
   };
 
   #[cfg(not(target_family = "wasm"))]
@@ -0,0 +1,40 @@
+use oxc::{
+  ast::{ast::Program, VisitMut},
+  span::Span,
+};
+
+/// Converts the spans of an AST from utf8 byte offsets to the utf16 indices of js strings.
+pub struct Utf8ToUtf16 {
+  /// The utf16 index of each utf8 byte offset of the source.
+  offsets: Vec<u32>,
+}
+
+impl Utf8ToUtf16 {
+  /// Both offsets are the same for an ascii `source`, so there is nothing to convert.
+  pub fn new(source: &str) -> Option<Self> {
+    if source.is_ascii() {
+      return None;
+    }
+    let mut offsets = Vec::with_capacity(source.len() + 1);
+    let mut utf16_index = 0;
+    for c in source.chars() {
+      offsets.extend(std::iter::repeat(utf16_index).take(c.len_utf8()));
+      #[allow(clippy::cast_possible_truncation)]
+      let len = c.len_utf16() as u32;
+      utf16_index += len;
+    }
+    offsets.push(utf16_index);
+    Some(Self { offsets })
+  }
+
+  pub fn convert_program(&mut self, program: &mut Program<'_>) {
+    self.visit_program(program);
+  }
+}
+
+impl VisitMut<'_> for Utf8ToUtf16 {
+  fn visit_span(&mut self, span: &mut Span) {
+    span.start = self.offsets[span.start as usize];
+    span.end = self.offsets[span.end as usize];
+  }
+}
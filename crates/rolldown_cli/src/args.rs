@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use rolldown::{BundlerOptions, InputItem, IsExternal, OutputFormat, Platform, SourceMapType};
+
+/// Bundle JavaScript and TypeScript without Node.js.
+///
+/// Flags override the values of the config file.
+#[derive(Debug, Parser)]
+#[command(name = "rolldown", version)]
+pub struct Args {
+  /// Entry files.
+  pub input: Vec<String>,
+  /// Read options from a `.json` or `.toml` config file. Without a path, `rolldown.config.json` or
+  /// `rolldown.config.toml` in the current directory is used.
+  #[arg(short, long, num_args = 0..=1, value_name = "FILE")]
+  #[allow(clippy::option_option)] // `Some(None)` means `--config` is passed without a path
+  pub config: Option<Option<PathBuf>>,
+  /// Directory for the generated chunks.
+  #[arg(short, long)]
+  pub dir: Option<String>,
+  /// Format of the generated chunks.
+  #[arg(short, long, value_enum)]
+  pub format: Option<CliOutputFormat>,
+  /// Generate sourcemaps. Defaults to `file` if no value is given.
+  #[arg(short, long, value_enum, num_args = 0..=1, default_missing_value = "file")]
+  pub sourcemap: Option<CliSourceMapType>,
+  /// Minify the generated chunks.
+  #[arg(short, long)]
+  pub minify: bool,
+  /// Modules that should stay external. Accepts a comma separated list or multiple flags.
+  #[arg(short, long, value_delimiter = ',')]
+  pub external: Vec<String>,
+  /// Replace global identifiers, e.g. `--define process.env.NODE_ENV='"production"'`.
+  #[arg(long, value_name = "KEY=VALUE", value_parser = parse_define)]
+  pub define: Vec<(String, String)>,
+  /// Platform the generated chunks run on.
+  #[arg(short, long, value_enum)]
+  pub platform: Option<CliPlatform>,
+  /// Rebuild when the files of the module graph change.
+  #[arg(short, long)]
+  pub watch: bool,
+  /// Print the time spent in hooks of each plugin.
+  #[arg(long)]
+  pub plugin_timings: bool,
+}
+
+impl Args {
+  pub fn apply_to(&self, options: &mut BundlerOptions) {
+    if !self.input.is_empty() {
+      options.input = Some(self.input.iter().cloned().map(InputItem::from).collect());
+    }
+    if let Some(dir) = &self.dir {
+      options.dir = Some(dir.clone());
+    }
+    if let Some(format) = self.format {
+      options.format = Some(format.into());
+    }
+    if let Some(sourcemap) = self.sourcemap {
+      options.sourcemap = Some(sourcemap.into());
+    }
+    if self.minify {
+      options.minify = Some(true.into());
+    }
+    if !self.external.is_empty() {
+      options.external = Some(IsExternal::from_vec(self.external.clone()));
+    }
+    if !self.define.is_empty() {
+      options.define.get_or_insert_with(Default::default).extend(self.define.iter().cloned());
+    }
+    if let Some(platform) = self.platform {
+      options.platform = Some(platform.into());
+    }
+    if self.plugin_timings {
+      options.plugin_timings = Some(true);
+    }
+  }
+}
+
+fn parse_define(value: &str) -> Result<(String, String), String> {
+  match value.split_once('=') {
+    Some((key, replacement)) if !key.is_empty() => Ok((key.to_string(), replacement.to_string())),
+    _ => Err(format!("expected `KEY=VALUE`, but got `{value}`")),
+  }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliOutputFormat {
+  #[value(alias = "es")]
+  Esm,
+  Cjs,
+  Iife,
+  Umd,
+  App,
+}
+
+impl From<CliOutputFormat> for OutputFormat {
+  fn from(value: CliOutputFormat) -> Self {
+    match value {
+      CliOutputFormat::Esm => OutputFormat::Esm,
+      CliOutputFormat::Cjs => OutputFormat::Cjs,
+      CliOutputFormat::Iife => OutputFormat::Iife,
+      CliOutputFormat::Umd => OutputFormat::Umd,
+      CliOutputFormat::App => OutputFormat::App,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliSourceMapType {
+  File,
+  Inline,
+  Hidden,
+}
+
+impl From<CliSourceMapType> for SourceMapType {
+  fn from(value: CliSourceMapType) -> Self {
+    match value {
+      CliSourceMapType::File => SourceMapType::File,
+      CliSourceMapType::Inline => SourceMapType::Inline,
+      CliSourceMapType::Hidden => SourceMapType::Hidden,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliPlatform {
+  Node,
+  Browser,
+  Neutral,
+}
+
+impl From<CliPlatform> for Platform {
+  fn from(value: CliPlatform) -> Self {
+    match value {
+      CliPlatform::Node => Platform::Node,
+      CliPlatform::Browser => Platform::Browser,
+      CliPlatform::Neutral => Platform::Neutral,
+    }
+  }
+}
@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use rolldown::BundlerOptions;
+use sugar_path::SugarPath;
+
+use crate::args::Args;
+
+const DEFAULT_CONFIG_FILES: [&str; 2] = ["rolldown.config.json", "rolldown.config.toml"];
+
+/// Create `BundlerOptions` from the config file and flags. Flags take precedence.
+pub fn load_options(args: &Args) -> anyhow::Result<BundlerOptions> {
+  let mut options = match &args.config {
+    Some(path) => {
+      let path = match path {
+        Some(path) => path.absolutize(),
+        None => find_default_config()?,
+      };
+      read_config_file(&path)?
+    }
+    None => BundlerOptions::default(),
+  };
+  args.apply_to(&mut options);
+  Ok(options)
+}
+
+fn find_default_config() -> anyhow::Result<PathBuf> {
+  let cwd = std::env::current_dir()?;
+  match DEFAULT_CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file()) {
+    Some(path) => Ok(path),
+    None => bail!("Could not find {} in {}", DEFAULT_CONFIG_FILES.join(" or "), cwd.display()),
+  }
+}
+
+/// Relative paths in the config file, including `cwd`, are resolved from the directory of the
+/// config file, so the config works no matter where the command runs.
+fn read_config_file(path: &Path) -> anyhow::Result<BundlerOptions> {
+  let content = std::fs::read_to_string(path)
+    .with_context(|| format!("Failed to read config file {}", path.display()))?;
+  let mut options: BundlerOptions = match path.extension().and_then(|ext| ext.to_str()) {
+    Some("json") => serde_json::from_str(&content)
+      .with_context(|| format!("Invalid config file {}", path.display()))?,
+    Some("toml") => {
+      toml::from_str(&content).with_context(|| format!("Invalid config file {}", path.display()))?
+    }
+    _ => bail!("Unsupported config file {}, expected a `.json` or `.toml` file", path.display()),
+  };
+  let config_dir = path.parent().expect("config file should have a parent directory");
+  options.cwd = Some(match options.cwd {
+    Some(cwd) => config_dir.join(cwd).normalize(),
+    None => config_dir.to_path_buf(),
+  });
+  Ok(options)
+}
+
+#[test]
+fn test_read_config_file() {
+  // The pid keeps concurrent runs of the test from sharing the directory.
+  let dir =
+    std::env::temp_dir().join(format!("rolldown_cli_test_read_config_file_{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+
+  let json = dir.join("rolldown.config.json");
+  std::fs::write(&json, r#"{ "input": [{ "import": "./main.js" }], "cwd": "src" }"#).unwrap();
+  let options = read_config_file(&json).unwrap();
+  assert_eq!(options.input.unwrap()[0].import, "./main.js");
+  assert_eq!(options.cwd, Some(dir.join("src")));
+
+  let toml = dir.join("rolldown.config.toml");
+  std::fs::write(&toml, "dir = 'out'\n\n[[input]]\nname = 'app'\nimport = './main.js'\n").unwrap();
+  let options = read_config_file(&toml).unwrap();
+  assert_eq!(options.dir.as_deref(), Some("out"));
+  assert_eq!(options.input.unwrap()[0].name.as_deref(), Some("app"));
+  assert_eq!(options.cwd, Some(dir.clone()));
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,114 @@
+mod args;
+mod config;
+
+use std::{num::NonZeroUsize, path::Path, process::ExitCode, sync::Arc, time::Instant};
+
+use clap::Parser;
+use rolldown::{Bundler, BundlerOptions, Watcher};
+use rolldown_common::{BundleEvent, WatcherEvent};
+use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+use tokio::sync::Mutex;
+
+use crate::{args::Args, config::load_options};
+
+fn main() -> ExitCode {
+  let args = Args::parse();
+  let options = match load_options(&args) {
+    Ok(options) => options,
+    Err(err) => {
+      eprintln!("{err:#}");
+      return ExitCode::FAILURE;
+    }
+  };
+  // The watcher keeps a worker blocked while waiting for file system events, so an extra worker is
+  // needed to avoid starving the bundler on single core machines.
+  let worker_threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get) + 1;
+  let runtime = tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(worker_threads)
+    .enable_all()
+    .build()
+    .expect("Failed to create tokio runtime");
+  runtime.block_on(async {
+    if args.watch {
+      watch(options).await
+    } else {
+      build(options).await
+    }
+  })
+}
+
+async fn build(options: BundlerOptions) -> ExitCode {
+  let start = Instant::now();
+  let mut bundler = Bundler::new(options);
+  let cwd = bundler.options().cwd.clone();
+  let out_dir = bundler.options().out_dir.clone();
+  match bundler.write().await {
+    Ok(output) => {
+      print_diagnostics(&output.warnings, &cwd);
+      for asset in &output.assets {
+        eprintln!(
+          "{:<40} {}",
+          Path::new(&out_dir).join(asset.filename()).display(),
+          format_size(asset.content_as_bytes().len())
+        );
+      }
+      if let Some(plugin_timings) = &output.plugin_timings {
+        eprintln!("{plugin_timings}");
+      }
+      eprintln!("Finished in {}ms", start.elapsed().as_millis());
+      ExitCode::SUCCESS
+    }
+    Err(errors) => {
+      print_diagnostics(&errors, &cwd);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+async fn watch(options: BundlerOptions) -> ExitCode {
+  let bundler = Arc::new(Mutex::new(Bundler::new(options)));
+  let watcher = match Watcher::new(vec![bundler], None) {
+    Ok(watcher) => watcher,
+    Err(err) => {
+      eprintln!("{err:#}");
+      return ExitCode::FAILURE;
+    }
+  };
+  let emitter = watcher.emitter();
+  // The emitter uses a blocking channel, so listen to it on its own thread.
+  std::thread::spawn(move || {
+    let rx = emitter.rx.blocking_lock();
+    while let Ok(event) = rx.recv() {
+      match event {
+        WatcherEvent::Change(data) => eprintln!("{} changed", data.path),
+        WatcherEvent::Event(BundleEvent::BundleEnd(data)) => {
+          eprintln!("Built {} in {}ms", data.output, data.duration);
+        }
+        WatcherEvent::Event(BundleEvent::Error(outputs)) => {
+          print_diagnostics(&outputs.diagnostics, &outputs.cwd);
+        }
+        WatcherEvent::Close => break,
+        WatcherEvent::Event(_) | WatcherEvent::ReStart => {}
+      }
+    }
+  });
+  eprintln!("Watching for changes...");
+  watcher.start().await;
+  ExitCode::SUCCESS
+}
+
+fn print_diagnostics(diagnostics: &[BuildDiagnostic], cwd: &Path) {
+  let opts = DiagnosticOptions { cwd: cwd.to_path_buf() };
+  for diagnostic in diagnostics {
+    eprintln!("{}", diagnostic.to_diagnostic_with(&opts).to_color_string());
+  }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_size(bytes: usize) -> String {
+  if bytes < 1024 {
+    format!("{bytes} B")
+  } else {
+    format!("{:.2} kB", bytes as f64 / 1024.0)
+  }
+}
@@ -0,0 +1,94 @@
+use std::{
+  path::{Path, PathBuf},
+  process::Command,
+};
+
+/// Create a unique directory with the given files for a test, since tests of the same run and concurrent
+/// runs share the temp directory.
+fn create_fixture(name: &str, files: &[(&str, &str)]) -> PathBuf {
+  let dir = std::env::temp_dir().join(format!("rolldown_cli_{name}_{}", std::process::id()));
+  if dir.exists() {
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+  for (path, content) in files {
+    let path = dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+  }
+  dir
+}
+
+fn run_cli(cwd: &Path, args: &[&str]) {
+  let output =
+    Command::new(env!("CARGO_BIN_EXE_rolldown")).args(args).current_dir(cwd).output().unwrap();
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn build_with_flags() {
+  let dir = create_fixture(
+    "build_with_flags",
+    &[
+      (
+        "src/main.js",
+        "import { readFileSync } from 'node:fs'
+import { name } from './name.js'
+
+if (process.env.NODE_ENV === 'production') {
+  console.log(readFileSync, name)
+}
+",
+      ),
+      ("src/name.js", "export const name = 'rolldown'\n"),
+    ],
+  );
+
+  run_cli(
+    &dir,
+    &[
+      "src/main.js",
+      "--dir",
+      "out",
+      "--format",
+      "cjs",
+      "--sourcemap",
+      "--minify",
+      "--external",
+      "node:fs",
+      "--define",
+      "process.env.NODE_ENV=\"production\"",
+      "--platform",
+      "node",
+    ],
+  );
+
+  let code = std::fs::read_to_string(dir.join("out/main.js")).unwrap();
+  assert!(code.contains("require(\"node:fs\")"), "{code}");
+  assert!(code.contains("\"rolldown\""), "{code}");
+  assert!(!code.contains("process.env.NODE_ENV"), "{code}");
+  assert!(code.contains("//# sourceMappingURL=main.js.map"), "{code}");
+  assert!(dir.join("out/main.js.map").is_file());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn flags_override_config_file() {
+  let dir = create_fixture(
+    "flags_override_config_file",
+    &[
+      (
+        "rolldown.config.toml",
+        "dir = 'config-out'\nformat = 'cjs'\n\n[[input]]\nname = 'app'\nimport = './main.js'\n",
+      ),
+      ("main.js", "export const answer = 42\n"),
+    ],
+  );
+
+  run_cli(&dir, &["--config", "--format", "esm"]);
+
+  let code = std::fs::read_to_string(dir.join("config-out/app.js")).unwrap();
+  assert!(code.contains("export { answer }"), "{code}");
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -48,6 +48,11 @@ pub struct Chunk {
   pub imports_from_external_modules: Vec<(ModuleIdx, Vec<NamedImport>)>,
   // meaningless if the chunk is an entrypoint
   pub exports_to_other_chunks: FxHashMap<SymbolRef, Rstr>,
+  /// Module-level directives like `"use client"` shared by the modules of this chunk, rendered at the top of
+  /// the chunk.
+  pub directives: Vec<Rstr>,
+  /// The filename of a chunk emitted by plugins with an explicit `fileName`, used as is.
+  pub file_name: Option<ArcStr>,
 }
 
 impl Chunk {
@@ -131,6 +136,9 @@ impl Chunk {
         .to_string();
       return Ok(PreliminaryFilename::new(basename, None));
     }
+    if let Some(file_name) = &self.file_name {
+      return Ok(PreliminaryFilename::new(file_name.to_string(), None));
+    }
     let filename_template = self.filename_template(options, rollup_pre_rendered_chunk).await?;
     let extracted_hash_pattern = extract_hash_pattern(filename_template.template());
 
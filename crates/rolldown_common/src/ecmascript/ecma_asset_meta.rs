@@ -4,4 +4,6 @@ use crate::RollupRenderedChunk;
 
 pub struct EcmaAssetMeta {
   pub rendered_chunk: RollupRenderedChunk,
+  /// Deduplicated legal comments of the modules in the chunk, if `legalComments` extracts them.
+  pub legal_comments: Vec<String>,
 }
@@ -1,6 +1,9 @@
 use arcstr::ArcStr;
 use bitflags::bitflags;
-use oxc::{semantic::SymbolId, span::Span};
+use oxc::{
+  semantic::{ScopeId, SymbolId},
+  span::Span,
+};
 use oxc_index::IndexVec;
 use rolldown_rstr::Rstr;
 use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
@@ -121,10 +124,21 @@ pub struct EcmaView {
   pub self_referenced_class_decl_symbol_ids: FxHashSet<SymbolId>,
   // the range of hashbang in source
   pub hashbang_range: Option<Span>,
+  /// Module-level directives other than `"use strict"`, like `"use client"`. They are rendered at the top of
+  /// the chunk rather than at the top of the module.
+  pub directives: Vec<Rstr>,
+  /// The ranges of legal comments in source. Only collected if `legalComments` moves them out of the
+  /// module, and these comments are removed from the ast.
+  pub legal_comments: Vec<Span>,
+  /// Scopes that call `eval` directly. The code evaluated by it may reference any binding that is visible to
+  /// these scopes by name.
+  pub direct_eval_scopes: FxHashSet<ScopeId>,
   pub meta: EcmaViewMeta,
   pub mutations: Vec<BoxedSourceMutation>,
   /// `Span` of `new URL('path', import.meta.url)` -> `ImportRecordIdx`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
+  /// `Span` of `import.meta.xxx` -> `Some("xxx")`, `Span` of bare `import.meta` -> `None`
+  pub import_meta_references: Vec<(Span, Option<Rstr>)>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
 }
 
@@ -134,6 +148,7 @@ bitflags! {
         const ModuleRef = 1;
         const ExportsRef = 1 << 1;
         const ModuleOrExports = Self::ModuleRef.bits() | Self::ExportsRef.bits();
+        const TopLevelAwait = 1 << 2;
     }
 }
 
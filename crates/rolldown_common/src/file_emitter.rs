@@ -8,7 +8,7 @@ use rolldown_utils::xxhash::xxhash_base64_url;
 use std::ffi::OsStr;
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 pub struct EmittedAsset {
@@ -18,6 +18,33 @@ pub struct EmittedAsset {
   pub source: StrOrBytes,
 }
 
+/// How the exports of the entry module of an emitted chunk should be preserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreserveEntrySignatures {
+  AllowExtension,
+  Strict,
+  ExportsOnly,
+  False,
+}
+
+#[derive(Debug, Default)]
+pub struct EmittedChunk {
+  pub name: Option<ArcStr>,
+  pub file_name: Option<ArcStr>,
+  pub id: String,
+  pub importer: Option<String>,
+  /// Rolldown doesn't create facade chunks yet, so the entry chunk always exposes all exports of its module.
+  pub preserve_signature: Option<PreserveEntrySignatures>,
+}
+
+#[derive(Debug, Default)]
+struct EmittedChunkState {
+  /// The resolved id of the entry module, set by the module loader.
+  module_id: Option<ArcStr>,
+  /// The final filename of the entry chunk, set once chunks are rendered.
+  file_name: Option<ArcStr>,
+}
+
 #[derive(Debug)]
 pub struct FileEmitter {
   source_hash_to_reference_id: FxDashMap<ArcStr, ArcStr>,
@@ -27,6 +54,10 @@ pub struct FileEmitter {
   options: Arc<NormalizedBundlerOptions>,
   /// Mark the files that have been emitted to bundle.
   emitted_files: FxDashSet<ArcStr>,
+  chunks: FxDashMap<ArcStr, EmittedChunkState>,
+  /// Chunks emitted but not yet added as entry points by the module loader. It's `None` once module loading
+  /// has finished.
+  pending_chunks: Mutex<Option<Vec<(ArcStr, EmittedChunk)>>>,
 }
 
 impl FileEmitter {
@@ -38,6 +69,8 @@ impl FileEmitter {
       base_reference_id: AtomicUsize::new(0),
       options,
       emitted_files: DashSet::default(),
+      chunks: DashMap::default(),
+      pending_chunks: Mutex::new(Some(vec![])),
     }
   }
 
@@ -77,7 +110,59 @@ impl FileEmitter {
     reference_id
   }
 
+  /// Registers a chunk emitted by a plugin and returns its reference id. The module loader picks up emitted
+  /// chunks and adds them as entry points, so chunks can't be emitted after module loading has finished.
+  pub fn emit_chunk(&self, chunk: EmittedChunk) -> anyhow::Result<ArcStr> {
+    let mut pending_chunks = self.pending_chunks.lock().expect("should not be poisoned");
+    let Some(pending_chunks) = pending_chunks.as_mut() else {
+      anyhow::bail!("Cannot emit chunks after module loading has finished.");
+    };
+    let reference_id = self.assign_reference_id(chunk.file_name.clone());
+    self.chunks.insert(reference_id.clone(), EmittedChunkState::default());
+    pending_chunks.push((reference_id.clone(), chunk));
+    Ok(reference_id)
+  }
+
+  /// Allows emitting chunks until `finish_chunk_emission` is called. Called before the `build_start` hook.
+  pub fn start_chunk_emission(&self) {
+    *self.pending_chunks.lock().expect("should not be poisoned") = Some(vec![]);
+  }
+
+  pub fn finish_chunk_emission(&self) {
+    *self.pending_chunks.lock().expect("should not be poisoned") = None;
+  }
+
+  pub fn take_pending_chunks(&self) -> Vec<(ArcStr, EmittedChunk)> {
+    self
+      .pending_chunks
+      .lock()
+      .expect("should not be poisoned")
+      .as_mut()
+      .map(std::mem::take)
+      .unwrap_or_default()
+  }
+
+  pub fn set_chunk_module_id(&self, reference_id: &str, module_id: ArcStr) {
+    if let Some(mut state) = self.chunks.get_mut(reference_id) {
+      state.module_id = Some(module_id);
+    }
+  }
+
+  /// Records the final filename of the entry chunk of `module_id` for all chunks emitted with that module.
+  pub fn set_chunk_file_name(&self, module_id: &str, file_name: &ArcStr) {
+    self.chunks.iter_mut().for_each(|mut state| {
+      if state.module_id.as_deref() == Some(module_id) {
+        state.file_name = Some(file_name.clone());
+      }
+    });
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
+    if let Some(state) = self.chunks.get(reference_id) {
+      return state.file_name.clone().ok_or(format!(
+        "Unable to get file name for emitted chunk: {reference_id}. You can only get file names once chunks have been generated."
+      ));
+    }
     let file = self
       .files
       .get(reference_id)
@@ -167,6 +252,8 @@ impl FileEmitter {
     self.source_hash_to_reference_id.clear();
     self.base_reference_id.store(0, Ordering::Relaxed);
     self.emitted_files.clear();
+    self.chunks.clear();
+    self.start_chunk_emission();
   }
 }
 
@@ -4,8 +4,11 @@ use std::{fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
 use types::checks_options::ChecksOptions;
 use types::comments::Comments;
+use types::custom_module_types::CustomModuleTypes;
 use types::inject_import::InjectImport;
 use types::jsx::Jsx;
+use types::legal_comments::LegalComments;
+use types::minify_options::RawMinifyOptions;
 use types::output_option::GlobalsOutputOption;
 use types::target::ESTarget;
 use types::watch_option::WatchOption;
@@ -21,12 +24,14 @@ use types::experimental_options::ExperimentalOptions;
 use self::types::treeshake::TreeshakeOptions;
 use self::types::{
   es_module_flag::EsModuleFlag, hash_characters::HashCharacters, input_item::InputItem,
-  is_external::IsExternal, output_exports::OutputExports, output_format::OutputFormat,
-  output_option::AddonOutputOption, platform::Platform, resolve_options::ResolveOptions,
-  source_map_type::SourceMapType, sourcemap_path_transform::SourceMapPathTransform,
+  is_external::IsExternal, log_level::LogLevel, on_log::OnLog, output_exports::OutputExports,
+  output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
+  resolve_options::ResolveOptions, source_map_type::SourceMapType,
+  sourcemap_path_transform::SourceMapPathTransform,
 };
 use crate::{ChunkFilenamesOutputOption, ModuleType, SourceMapIgnoreList};
 
+pub mod output_options;
 pub mod types;
 
 #[derive(Default, Debug, Clone)]
@@ -128,6 +133,13 @@ pub struct BundlerOptions {
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<FxHashMap<String, ModuleType>>,
+  /// Handlers that turn modules of a `ModuleType::Custom` type into ECMAScript.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub custom_module_types: Option<CustomModuleTypes>,
   // --- options for resolve
   pub resolve: Option<ResolveOptions>,
   #[cfg_attr(
@@ -136,7 +148,7 @@ pub struct BundlerOptions {
   )]
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
-  pub minify: Option<bool>,
+  pub minify: Option<RawMinifyOptions>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<FxHashMap<String, String>>")
@@ -158,8 +170,20 @@ pub struct BundlerOptions {
   pub jsx: Option<Jsx>,
   pub watch: Option<WatchOption>,
   pub comments: Option<Comments>,
+  pub legal_comments: Option<LegalComments>,
   pub target: Option<ESTarget>,
   pub polyfill_require: Option<bool>,
+  /// Record calls and time of each hook of each plugin. The summary is available in the output of the build.
+  pub plugin_timings: Option<bool>,
+  /// Defaults to `LogLevel::Info`.
+  pub log_level: Option<LogLevel>,
+  /// Called with each log that passes `log_level`, to filter, promote or drop it.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub on_log: Option<OnLog>,
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -0,0 +1,70 @@
+use super::types::{
+  advanced_chunks_options::AdvancedChunksOptions,
+  comments::Comments,
+  es_module_flag::EsModuleFlag,
+  hash_characters::HashCharacters,
+  legal_comments::LegalComments,
+  minify_options::RawMinifyOptions,
+  output_exports::OutputExports,
+  output_format::OutputFormat,
+  output_option::{AddonOutputOption, ChunkFilenamesOutputOption, GlobalsOutputOption},
+  source_map_type::SourceMapType,
+  sourcemap_ignore_list::SourceMapIgnoreList,
+  sourcemap_path_transform::SourceMapPathTransform,
+  target::ESTarget,
+};
+use super::BundlerOptions;
+
+macro_rules! define_output_options {
+  ($($field:ident: $ty:ty,)*) => {
+    /// The output half of [`BundlerOptions`], which is what the `output_options` hook of plugins receives.
+    /// The fields match the `output` options of the JavaScript API.
+    #[derive(Default, Debug, Clone)]
+    pub struct OutputOptions {
+      $(pub $field: Option<$ty>,)*
+    }
+
+    impl BundlerOptions {
+      pub fn output_options(&self) -> OutputOptions {
+        OutputOptions { $($field: self.$field.clone(),)* }
+      }
+
+      pub fn set_output_options(&mut self, output_options: OutputOptions) {
+        $(self.$field = output_options.$field;)*
+      }
+    }
+  };
+}
+
+define_output_options! {
+  name: String,
+  entry_filenames: ChunkFilenamesOutputOption,
+  chunk_filenames: ChunkFilenamesOutputOption,
+  css_entry_filenames: ChunkFilenamesOutputOption,
+  css_chunk_filenames: ChunkFilenamesOutputOption,
+  asset_filenames: String,
+  dir: String,
+  file: String,
+  format: OutputFormat,
+  exports: OutputExports,
+  globals: GlobalsOutputOption,
+  sourcemap: SourceMapType,
+  sourcemap_ignore_list: SourceMapIgnoreList,
+  sourcemap_path_transform: SourceMapPathTransform,
+  sourcemap_debug_ids: bool,
+  es_module: EsModuleFlag,
+  hash_characters: HashCharacters,
+  banner: AddonOutputOption,
+  footer: AddonOutputOption,
+  intro: AddonOutputOption,
+  outro: AddonOutputOption,
+  extend: bool,
+  external_live_bindings: bool,
+  inline_dynamic_imports: bool,
+  advanced_chunks: AdvancedChunksOptions,
+  minify: RawMinifyOptions,
+  comments: Comments,
+  legal_comments: LegalComments,
+  target: ESTarget,
+  polyfill_require: bool,
+}
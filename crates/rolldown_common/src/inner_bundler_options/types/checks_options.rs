@@ -10,5 +10,125 @@ use serde::Deserialize;
   serde(rename_all = "camelCase", deny_unknown_fields)
 )]
 pub struct ChecksOptions {
-  pub circular_dependency: Option<bool>,
+  pub circular_dependency: Option<CircularDependencyCheck>,
+  pub size_budgets: Option<SizeBudgetsOptions>,
+}
+
+/// `circularDependency: true` warns about every cycle. Use the object form to ignore cycles or to fail the build.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum CircularDependencyCheck {
+  Boolean(bool),
+  Option(CircularDependencyOptions),
+}
+
+impl From<bool> for CircularDependencyCheck {
+  fn from(value: bool) -> Self {
+    Self::Boolean(value)
+  }
+}
+
+impl CircularDependencyCheck {
+  pub fn options(&self) -> Option<CircularDependencyOptions> {
+    match self {
+      Self::Boolean(false) => None,
+      Self::Boolean(true) => Some(CircularDependencyOptions::default()),
+      Self::Option(options) => Some(options.clone()),
+    }
+  }
+}
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CircularDependencyOptions {
+  /// Cycles containing a module whose stable id matches one of these globs are ignored, e.g. `node_modules/**`.
+  pub ignore: Option<Vec<String>>,
+  /// Defaults to `warn`.
+  pub mode: Option<CircularDependencyMode>,
+}
+
+impl CircularDependencyOptions {
+  pub fn is_ignored(&self, stable_id: &str) -> bool {
+    self.ignore.iter().flatten().any(|pattern| glob_match::glob_match(pattern, stable_id))
+  }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum CircularDependencyMode {
+  /// Report every cycle as a warning.
+  #[default]
+  Warn,
+  /// Report cycles as errors if they cross chunk boundaries or contain modules that are wrapped or use
+  /// top-level await, since the execution order of such modules could differ from the source. Other cycles
+  /// are reported as warnings.
+  ErrorOnUnsafe,
+  /// Report every cycle as an error.
+  Error,
+}
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SizeBudgetsOptions {
+  /// Limits of every entry chunk.
+  pub entry: Option<SizeLimit>,
+  /// Limits of chunks whose filename matches the pattern. A chunk is checked against every matching pattern.
+  pub chunks: Option<Vec<ChunkSizeBudget>>,
+  /// Limits of the initial load of every entry chunk, which is the entry chunk and all chunks it imports statically.
+  pub initial: Option<SizeLimit>,
+  /// Defaults to `warning`.
+  pub severity: Option<SizeBudgetSeverity>,
+}
+
+/// Sizes are in bytes. Compressed sizes are only computed if they have a limit.
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SizeLimit {
+  pub raw: Option<u64>,
+  pub gzip: Option<u64>,
+  pub brotli: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ChunkSizeBudget {
+  /// A glob pattern matched against the filename of chunks, e.g. `vendor-*.js`.
+  pub pattern: String,
+  pub limit: SizeLimit,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum SizeBudgetSeverity {
+  #[default]
+  Warning,
+  Error,
 }
@@ -0,0 +1,69 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use rolldown_ecmascript::EcmaAst;
+use rustc_hash::FxHashMap;
+
+use crate::StrOrBytes;
+
+pub struct CustomModuleTypeArgs<'a> {
+  /// The name of the custom module type, e.g. `graphql` for `ModuleType::Custom("graphql")`.
+  pub module_type: &'a str,
+  pub id: &'a str,
+  pub stable_id: &'a str,
+  pub source: StrOrBytes,
+}
+
+pub enum CustomModuleContent {
+  /// ECMAScript source code. With `lazy_export`, it should be a single expression.
+  Source(String),
+  /// A parsed ECMAScript program. With `lazy_export`, it should only contain a single expression statement.
+  Ast(EcmaAst),
+}
+
+pub struct CustomModuleTypeOutput {
+  pub content: CustomModuleContent,
+  /// The content is an expression that becomes the default export of the module, like the `json` and `text`
+  /// module types. Properties of a lazy exported object literal could be imported and tree-shaken individually.
+  pub lazy_export: bool,
+}
+
+/// Turns modules of a custom module type into ECMAScript natively, without the need of rewriting them in
+/// the `load` or `transform` hooks.
+pub trait CustomModuleTypeHandler: Debug + Send + Sync {
+  fn to_ecmascript(&self, args: CustomModuleTypeArgs<'_>)
+    -> anyhow::Result<CustomModuleTypeOutput>;
+
+  /// The side effects of modules of this type, if neither hooks nor `package.json` specify them. `None` means
+  /// the side effects are analyzed from the generated code.
+  fn side_effects(&self) -> Option<bool> {
+    None
+  }
+}
+
+/// Handlers of `ModuleType::Custom`, keyed by the name of the custom module type.
+///
+/// Modules are only given a custom module type by the `moduleTypes` option or by the `load`/`transform`
+/// hooks, so registering a handler doesn't change the module type of any file by itself.
+#[derive(Debug, Default, Clone)]
+pub struct CustomModuleTypes(FxHashMap<String, Arc<dyn CustomModuleTypeHandler>>);
+
+impl CustomModuleTypes {
+  pub fn insert(
+    &mut self,
+    module_type: impl Into<String>,
+    handler: Arc<dyn CustomModuleTypeHandler>,
+  ) {
+    self.0.insert(module_type.into(), handler);
+  }
+
+  pub fn get(&self, module_type: &str) -> Option<&Arc<dyn CustomModuleTypeHandler>> {
+    self.0.get(module_type)
+  }
+}
+
+impl<S: Into<String>> FromIterator<(S, Arc<dyn CustomModuleTypeHandler>)> for CustomModuleTypes {
+  fn from_iter<T: IntoIterator<Item = (S, Arc<dyn CustomModuleTypeHandler>)>>(iter: T) -> Self {
+    Self(iter.into_iter().map(|(module_type, handler)| (module_type.into(), handler)).collect())
+  }
+}
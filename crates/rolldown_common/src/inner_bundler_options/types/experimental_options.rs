@@ -13,6 +13,10 @@ use crate::ROLLDOWN_IGNORE;
 )]
 pub struct ExperimentalOptions {
   pub strict_execution_order: Option<bool>,
+  /// Only wrap modules that are placed in common chunks, i.e. modules shared by multiple entries (including
+  /// dynamic imports) or grouped by `advancedChunks`. Such modules are evaluated lazily by their importers
+  /// rather than when the common chunk is loaded.
+  pub lazy_shared_modules: Option<bool>,
   pub disable_live_bindings: Option<bool>,
   pub vite_mode: Option<bool>,
   pub resolve_new_url_to_asset: Option<bool>,
@@ -23,6 +27,10 @@ impl ExperimentalOptions {
     self.strict_execution_order.unwrap_or(false)
   }
 
+  pub fn is_lazy_shared_modules_enabled(&self) -> bool {
+    self.lazy_shared_modules.unwrap_or(false)
+  }
+
   pub fn is_disable_live_bindings_enabled(&self) -> bool {
     self.disable_live_bindings.unwrap_or(false)
   }
@@ -0,0 +1,61 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How to handle comments that contain `@license` or `@preserve`, or start with `//!` or `/*!`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum LegalComments {
+  /// Remove legal comments
+  None,
+  /// Keep legal comments where they are, if `comments` allows it
+  #[default]
+  Inline,
+  /// Move legal comments of all modules to the end of the chunk
+  Eof,
+  /// Move legal comments to `<chunk>.LEGAL.txt` and link to it with a comment at the end of the chunk
+  Linked,
+  /// Move legal comments to `<chunk>.LEGAL.txt`
+  External,
+}
+
+impl LegalComments {
+  /// Whether legal comments are collected from modules instead of being printed in place.
+  pub fn is_extracted(self) -> bool {
+    matches!(self, Self::Eof | Self::Linked | Self::External)
+  }
+}
+
+impl FromStr for LegalComments {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "none" => Ok(Self::None),
+      "inline" => Ok(Self::Inline),
+      "eof" => Ok(Self::Eof),
+      "linked" => Ok(Self::Linked),
+      "external" => Ok(Self::External),
+      _ => Err(anyhow::anyhow!("Invalid legalComments \"{s}\".")),
+    }
+  }
+}
+
+impl Display for LegalComments {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::None => write!(f, "none"),
+      Self::Inline => write!(f, "inline"),
+      Self::Eof => write!(f, "eof"),
+      Self::Linked => write!(f, "linked"),
+      Self::External => write!(f, "external"),
+    }
+  }
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use rolldown_error::Severity;
+
+/// The most verbose logs to report. Logs of a lower level are dropped before reaching `on_log`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
+#[cfg_attr(feature = "deserialize_bundler_options", serde(rename_all = "camelCase"))]
+pub enum LogLevel {
+  Silent,
+  Warn,
+  #[default]
+  Info,
+  Debug,
+}
+
+impl LogLevel {
+  /// Errors are never dropped.
+  pub fn allows(self, severity: Severity) -> bool {
+    match severity {
+      Severity::Error => true,
+      Severity::Warning => self >= Self::Warn,
+      Severity::Info => self >= Self::Info,
+      Severity::Debug => self >= Self::Debug,
+    }
+  }
+}
+
+impl TryFrom<&str> for LogLevel {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "silent" => Ok(Self::Silent),
+      "warn" => Ok(Self::Warn),
+      "info" => Ok(Self::Info),
+      "debug" => Ok(Self::Debug),
+      _ => Err(format!("Unknown log level: {value:?}")),
+    }
+  }
+}
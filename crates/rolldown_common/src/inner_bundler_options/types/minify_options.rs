@@ -0,0 +1,99 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use super::target::ESTarget;
+
+/// `minify: true` enables every step. Use the object form to enable them separately.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum RawMinifyOptions {
+  Boolean(bool),
+  Option(MinifyOptionsObject),
+}
+
+impl From<bool> for RawMinifyOptions {
+  fn from(value: bool) -> Self {
+    Self::Boolean(value)
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyOptionsObject {
+  /// Default `true`
+  pub compress: Option<bool>,
+  /// Default `true`
+  pub mangle: Option<bool>,
+  /// Remove whitespace and comments of the output. Default `true`
+  pub whitespace: Option<bool>,
+  /// Remove `console.*` calls whose result is unused. Only works with `compress`. Default `false`
+  pub drop_console: Option<bool>,
+  /// Remove `debugger;` statements. Only works with `compress`. Default `true`
+  pub drop_debugger: Option<bool>,
+  /// Calls of these functions are considered side effect free and removed if their result is
+  /// unused, e.g. `["console.info", "assert"]`. Only works with `compress`.
+  pub pure_funcs: Option<Vec<String>>,
+  /// Preserve the `name` of functions. Defaults to `keepNames`.
+  pub keep_fnames: Option<bool>,
+  /// Preserve the `name` of classes. Defaults to `keepNames`.
+  pub keep_classnames: Option<bool>,
+  /// The syntax level the minifier is allowed to output. Modules are lowered to it if it is lower than `target`. Defaults to `target`.
+  pub target: Option<ESTarget>,
+}
+
+#[allow(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
+#[derive(Debug, Default, Clone)]
+pub struct MinifyOptions {
+  pub compress: bool,
+  pub mangle: bool,
+  pub whitespace: bool,
+  pub drop_console: bool,
+  pub drop_debugger: bool,
+  pub pure_funcs: Vec<String>,
+  pub keep_fnames: bool,
+  pub keep_classnames: bool,
+  pub target: ESTarget,
+}
+
+impl MinifyOptions {
+  pub fn new(raw: Option<RawMinifyOptions>, keep_names: bool, target: ESTarget) -> Self {
+    let object = match raw {
+      None | Some(RawMinifyOptions::Boolean(false)) => {
+        return Self {
+          keep_fnames: keep_names,
+          keep_classnames: keep_names,
+          target,
+          ..Self::default()
+        }
+      }
+      Some(RawMinifyOptions::Boolean(true)) => MinifyOptionsObject::default(),
+      Some(RawMinifyOptions::Option(object)) => object,
+    };
+    Self {
+      compress: object.compress.unwrap_or(true),
+      mangle: object.mangle.unwrap_or(true),
+      whitespace: object.whitespace.unwrap_or(true),
+      drop_console: object.drop_console.unwrap_or(false),
+      drop_debugger: object.drop_debugger.unwrap_or(true),
+      pure_funcs: object.pure_funcs.unwrap_or_default(),
+      keep_fnames: object.keep_fnames.unwrap_or(keep_names),
+      keep_classnames: object.keep_classnames.unwrap_or(keep_names),
+      target: object.target.unwrap_or(target),
+    }
+  }
+
+  /// Whether any of the minify steps is enabled.
+  pub fn is_enabled(&self) -> bool {
+    self.compress || self.mangle || self.whitespace
+  }
+}
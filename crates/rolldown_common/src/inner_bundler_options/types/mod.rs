@@ -1,6 +1,7 @@
 pub mod advanced_chunks_options;
 pub mod checks_options;
 pub mod comments;
+pub mod custom_module_types;
 pub mod es_module_flag;
 pub mod experimental_options;
 pub mod filename_template;
@@ -9,8 +10,12 @@ pub mod inject_import;
 pub mod input_item;
 pub mod is_external;
 pub mod jsx;
+pub mod legal_comments;
+pub mod log_level;
+pub mod minify_options;
 pub mod module_type;
 pub mod normalized_bundler_options;
+pub mod on_log;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
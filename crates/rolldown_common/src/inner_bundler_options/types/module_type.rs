@@ -17,6 +17,8 @@ pub enum ModuleType {
   Ts,
   Tsx,
   Json,
+  Yaml,
+  Toml,
   Text,
   Base64,
   Dataurl,
@@ -35,6 +37,8 @@ impl ModuleType {
       "ts" => Ok(Self::Ts),
       "tsx" => Ok(Self::Tsx),
       "json" => Ok(Self::Json),
+      "yaml" => Ok(Self::Yaml),
+      "toml" => Ok(Self::Toml),
       "text" => Ok(Self::Text),
       "base64" => Ok(Self::Base64),
       "dataurl" => Ok(Self::Dataurl),
@@ -44,6 +48,17 @@ impl ModuleType {
     }
   }
 
+  /// Map the `type` import attribute to the module type it asserts. Unknown types assert nothing.
+  pub fn from_import_attribute_type(ty: &str) -> Option<Self> {
+    match ty {
+      "json" => Some(Self::Json),
+      "css" => Some(Self::Css),
+      "text" => Some(Self::Text),
+      "bytes" => Some(Self::Binary),
+      _ => None,
+    }
+  }
+
   /// error: method `from_str` can be confused for the standard trait method `std::str::FromStr::from_str`
   /// to avoid conflicting with std
   pub fn from_str_with_fallback<S: AsRef<str>>(s: S) -> Self {
@@ -53,6 +68,8 @@ impl ModuleType {
       "ts" => Self::Ts,
       "tsx" => Self::Tsx,
       "json" => Self::Json,
+      "yaml" => Self::Yaml,
+      "toml" => Self::Toml,
       "text" => Self::Text,
       "base64" => Self::Base64,
       "dataurl" => Self::Dataurl,
@@ -71,6 +88,8 @@ impl Display for ModuleType {
       ModuleType::Ts => write!(f, "ts"),
       ModuleType::Tsx => write!(f, "tsx"),
       ModuleType::Json => write!(f, "json"),
+      ModuleType::Yaml => write!(f, "yaml"),
+      ModuleType::Toml => write!(f, "toml"),
       ModuleType::Text => write!(f, "text"),
       ModuleType::Base64 => write!(f, "base64"),
       ModuleType::Dataurl => write!(f, "dataurl"),
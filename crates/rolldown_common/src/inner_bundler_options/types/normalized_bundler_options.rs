@@ -10,16 +10,19 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use super::advanced_chunks_options::AdvancedChunksOptions;
 use super::checks_options::ChecksOptions;
 use super::comments::Comments;
+use super::custom_module_types::CustomModuleTypes;
 use super::experimental_options::ExperimentalOptions;
 use super::jsx::Jsx;
+use super::legal_comments::LegalComments;
+use super::minify_options::MinifyOptions;
 use super::output_option::ChunkFilenamesOutputOption;
 use super::target::ESTarget;
 use super::treeshake::TreeshakeOptions;
 use super::watch_option::WatchOption;
 use super::{
-  filename_template::FilenameTemplate, is_external::IsExternal, output_exports::OutputExports,
-  output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
-  source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
+  filename_template::FilenameTemplate, is_external::IsExternal, log_level::LogLevel, on_log::OnLog,
+  output_exports::OutputExports, output_format::OutputFormat, output_option::AddonOutputOption,
+  platform::Platform, source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
   sourcemap_path_transform::SourceMapPathTransform,
 };
 use crate::{
@@ -39,6 +42,7 @@ pub struct NormalizedBundlerOptions {
   pub shim_missing_exports: bool,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
   pub module_types: FxHashMap<String, ModuleType>,
+  pub custom_module_types: CustomModuleTypes,
   // --- Output
   pub name: Option<String>,
   pub css_entry_filenames: ChunkFilenamesOutputOption,
@@ -65,10 +69,9 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: bool,
   pub experimental: ExperimentalOptions,
-  pub minify: bool,
+  pub minify: MinifyOptions,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
-  pub keep_names: bool,
   pub inject: Vec<InjectImport>,
   pub oxc_inject_global_variables_config: InjectGlobalVariablesConfig,
   pub external_live_bindings: bool,
@@ -79,9 +82,13 @@ pub struct NormalizedBundlerOptions {
   pub jsx: Jsx,
   pub watch: WatchOption,
   pub comments: Comments,
+  pub legal_comments: LegalComments,
   pub drop_labels: FxHashSet<String>,
   pub target: ESTarget,
   pub polyfill_require: bool,
+  pub plugin_timings: bool,
+  pub log_level: LogLevel,
+  pub on_log: Option<OnLog>,
 }
 
 pub type SharedNormalizedBundlerOptions = Arc<NormalizedBundlerOptions>;
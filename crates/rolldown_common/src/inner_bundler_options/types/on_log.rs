@@ -0,0 +1,31 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::{future::Future, pin::Pin};
+
+use rolldown_error::{BuildDiagnostic, Severity};
+
+/// Returns the severity to report the log with, e.g. `Severity::Error` to fail the build, or `None` to drop it.
+type OnLogFn = dyn Fn(
+    &BuildDiagnostic,
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<Severity>>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+#[derive(Clone)]
+pub struct OnLog(Arc<OnLogFn>);
+
+impl Debug for OnLog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OnLog::Fn(...)")
+  }
+}
+
+impl OnLog {
+  pub fn new(f: Arc<OnLogFn>) -> Self {
+    Self(f)
+  }
+
+  pub async fn call(&self, log: &BuildDiagnostic) -> anyhow::Result<Option<Severity>> {
+    self.0(log).await
+  }
+}
@@ -23,4 +23,9 @@ pub struct ResolveOptions {
   pub modules: Option<Vec<String>>,
   pub symlinks: Option<bool>,
   pub tsconfig_filename: Option<String>,
+  /// Use the nearest `tsconfig.json` of each importer, following its `extends` and `references`,
+  /// instead of a single `tsconfig_filename` for the whole build. Ignored if `tsconfig_filename` is set.
+  pub tsconfig_auto: Option<bool>,
+  /// Resolve bare specifiers through the Yarn Plug'n'Play manifest (`.pnp.data.json` or `.pnp.cjs`).
+  pub yarn_pnp: Option<bool>,
 }
@@ -1,13 +1,13 @@
 // cspell:ignore Estarget
 use oxc::transformer::ESTarget as OxcEstarget;
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
 #[cfg_attr(feature = "deserialize_bundler_options", serde(rename_all = "camelCase"))]
 pub enum ESTarget {
@@ -48,6 +48,26 @@ impl FromStr for ESTarget {
   }
 }
 
+impl Display for ESTarget {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let target = match self {
+      Self::Es5 => "es5",
+      Self::Es2015 => "es2015",
+      Self::Es2016 => "es2016",
+      Self::Es2017 => "es2017",
+      Self::Es2018 => "es2018",
+      Self::Es2019 => "es2019",
+      Self::Es2020 => "es2020",
+      Self::Es2021 => "es2021",
+      Self::Es2022 => "es2022",
+      Self::Es2023 => "es2023",
+      Self::Es2024 => "es2024",
+      Self::EsNext => "esnext",
+    };
+    f.write_str(target)
+  }
+}
+
 impl From<ESTarget> for OxcEstarget {
   fn from(value: ESTarget) -> OxcEstarget {
     match value {
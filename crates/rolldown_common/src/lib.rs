@@ -14,10 +14,18 @@ mod types;
 /// the same code in `rolldown` crate again.
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
+    output_options::OutputOptions,
     types::{
       advanced_chunks_options::{AdvancedChunksOptions, MatchGroup},
-      checks_options::ChecksOptions,
+      checks_options::{
+        ChecksOptions, ChunkSizeBudget, CircularDependencyCheck, CircularDependencyMode,
+        CircularDependencyOptions, SizeBudgetSeverity, SizeBudgetsOptions, SizeLimit,
+      },
       comments::Comments,
+      custom_module_types::{
+        CustomModuleContent, CustomModuleTypeArgs, CustomModuleTypeHandler, CustomModuleTypeOutput,
+        CustomModuleTypes,
+      },
       es_module_flag::EsModuleFlag,
       experimental_options::ExperimentalOptions,
       filename_template::{FileNameRenderOptions, FilenameTemplate},
@@ -26,8 +34,12 @@ pub mod bundler_options {
       input_item::InputItem,
       is_external::IsExternal,
       jsx::Jsx,
+      legal_comments::LegalComments,
+      log_level::LogLevel,
+      minify_options::{MinifyOptions, MinifyOptionsObject, RawMinifyOptions},
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
+      on_log::OnLog,
       output_exports::OutputExports,
       output_format::OutputFormat,
       output_option::{
@@ -72,7 +84,9 @@ pub use crate::{
     module_idx::ModuleIdx,
     node_builtin_modules::is_existing_node_builtin_modules,
   },
-  file_emitter::{EmittedAsset, FileEmitter, SharedFileEmitter},
+  file_emitter::{
+    EmittedAsset, EmittedChunk, FileEmitter, PreserveEntrySignatures, SharedFileEmitter,
+  },
   module::{
     external_module::ExternalModule,
     normal_module::{ModuleRenderArgs, NormalModule},
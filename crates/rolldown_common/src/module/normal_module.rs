@@ -229,7 +229,12 @@ impl NormalModule {
         // So here make sure using correct `source_name` and `source_content.
         let render_output = EcmaCompiler::print_with(
           ast,
-          PrintOptions { sourcemap: enable_sourcemap, filename: self.id.to_string(), comments },
+          PrintOptions {
+            sourcemap: enable_sourcemap,
+            filename: self.id.to_string(),
+            comments,
+            minify: options.minify.whitespace,
+          },
         );
         if !self.ecma_view.mutations.is_empty() {
           let original_code: Arc<str> = render_output.code.into();
@@ -7,6 +7,8 @@ pub struct EntryPoint {
   pub name: Option<ArcStr>,
   pub id: ModuleIdx,
   pub kind: EntryPointKind,
+  /// The filename of chunks emitted by plugins with an explicit `fileName`.
+  pub file_name: Option<ArcStr>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
@@ -22,6 +22,7 @@ pub struct ImportRecordStateInit {
 #[derive(Debug)]
 pub struct ImportRecordStateResolved {
   pub resolved_module: ModuleIdx,
+  pub span: Span,
 }
 
 bitflags::bitflags! {
@@ -50,6 +51,8 @@ pub struct ImportRecord<State: Debug> {
   /// `./lib.js` in `import { foo } from './lib.js';`
   pub module_request: Rstr,
   pub kind: ImportKind,
+  /// The `type` import attribute, e.g. `json` in `import data from './data.json' with { type: 'json' }`.
+  pub import_attribute_type: Option<Rstr>,
   /// We will turn `import { foo } from './cjs.js'; console.log(foo);` to `var import_foo = require_cjs(); console.log(importcjs.foo)`;
   /// `namespace_ref` represent the potential `import_foo` in above example. It's useless if we imported n esm module.
   pub namespace_ref: SymbolRef,
@@ -89,6 +92,7 @@ impl RawImportRecord {
     RawImportRecord {
       module_request: specifier,
       kind,
+      import_attribute_type: None,
       namespace_ref,
       meta: ImportRecordMeta::empty(),
       state: ImportRecordStateInit { span, asserted_module_type: assert_module_type },
@@ -102,9 +106,10 @@ impl RawImportRecord {
 
   pub fn into_resolved(self, resolved_module: ModuleIdx) -> ResolvedImportRecord {
     ResolvedImportRecord {
-      state: ImportRecordStateResolved { resolved_module },
+      state: ImportRecordStateResolved { resolved_module, span: self.state.span },
       module_request: self.module_request,
       kind: self.kind,
+      import_attribute_type: self.import_attribute_type,
       namespace_ref: self.namespace_ref,
       meta: self.meta,
     }
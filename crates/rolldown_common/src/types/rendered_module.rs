@@ -29,4 +29,12 @@ impl RenderedModule {
       joiner.join().0
     })
   }
+
+  /// The length of the rendered code in bytes, without joining the sources.
+  pub fn rendered_length(&self) -> usize {
+    self
+      .inner_code
+      .as_ref()
+      .map_or(0, |sources| sources.iter().map(|source| source.content().len()).sum())
+  }
 }
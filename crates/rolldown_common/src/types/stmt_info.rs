@@ -68,7 +68,7 @@ oxc_index::define_index_type! {
 }
 
 bitflags! {
-    #[derive(Debug, Default)]
+    #[derive(Debug, Default, Clone, Copy)]
     pub struct StmtInfoMeta: u8 {
         const FnDecl = 1;
         const ClassDecl = 1 << 1;
@@ -30,6 +30,9 @@ bitflags::bitflags! {
     const IS_NOT_REASSIGNED = 1;
     /// If this symbol is declared by `const`. Eg. `const a = 1;`
     const IS_CONST = 1 << 1;
+    /// If this symbol is a function annotated with `@__NO_SIDE_EFFECTS__`. Calls to it are side effect free
+    /// if the arguments are.
+    const SIDE_EFFECT_FREE_FUNCTION = 1 << 2;
   }
 }
 
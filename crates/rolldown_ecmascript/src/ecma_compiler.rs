@@ -3,20 +3,29 @@ use std::path::PathBuf;
 use arcstr::ArcStr;
 use either::Either;
 use oxc::{
-  allocator::Allocator,
   ast::AstBuilder,
-  codegen::{CodeGenerator, Codegen, CodegenOptions, CodegenReturn, LegalComment},
-  minifier::{Minifier, MinifierOptions},
+  codegen::{CodeGenerator, CodegenOptions, CodegenReturn, LegalComment},
   parser::{ParseOptions, Parser},
   span::{SourceType, SPAN},
 };
-use oxc_sourcemap::SourceMap;
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
 
 use crate::ecma_ast::{
   program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
   EcmaAst,
 };
+
+/// The source type of modules, with `typescript` and `jsx` added depending on the module.
+pub fn pure_esm_js_oxc_source_type() -> SourceType {
+  let pure_esm_js = SourceType::default().with_module(true);
+  debug_assert!(pure_esm_js.is_javascript());
+  debug_assert!(!pure_esm_js.is_jsx());
+  debug_assert!(pure_esm_js.is_module());
+  debug_assert!(pure_esm_js.is_strict());
+
+  pure_esm_js
+}
+
 pub struct EcmaCompiler;
 
 impl EcmaCompiler {
@@ -100,31 +109,11 @@ impl EcmaCompiler {
         comments: is_print_full_comments,
         source_map_path: options.sourcemap.then(|| PathBuf::from(options.filename)),
         legal_comments,
+        minify: options.minify,
         ..CodegenOptions::default()
       })
       .build(ast.program())
   }
-
-  pub fn minify(
-    source_text: &str,
-    enable_sourcemap: bool,
-    filename: &str,
-  ) -> (String, Option<SourceMap>) {
-    let allocator = Allocator::default();
-    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
-    let program = allocator.alloc(program);
-    let options = MinifierOptions::default();
-    let ret = Minifier::new(options).build(&allocator, program);
-    let ret = Codegen::new()
-      .with_options(CodegenOptions {
-        source_map_path: enable_sourcemap.then(|| PathBuf::from(filename)),
-        minify: true,
-        ..CodegenOptions::default()
-      })
-      .with_mangler(ret.mangler)
-      .build(program);
-    (ret.code, ret.map)
-  }
 }
 
 #[test]
@@ -138,4 +127,6 @@ pub struct PrintOptions {
   pub comments: Either</* is print full comments */ bool, LegalComment>,
   pub filename: String,
   pub sourcemap: bool,
+  /// Print without whitespace.
+  pub minify: bool,
 }
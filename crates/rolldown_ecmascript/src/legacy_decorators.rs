@@ -0,0 +1,450 @@
+use oxc::{
+  allocator::{Allocator, Box as ArenaBox, CloneIn, Vec as ArenaVec},
+  ast::{
+    ast::{
+      Argument, ArrayExpressionElement, Class, ClassElement, ClassType, Declaration, Decorator,
+      ExportDefaultDeclarationKind, Expression, ImportOrExportKind, MethodDefinitionKind,
+      NumberBase, Program, PropertyKey, Statement, VariableDeclarationKind,
+    },
+    visit::walk_mut,
+    AstBuilder, VisitMut, NONE,
+  },
+  diagnostics::OxcDiagnostic,
+  semantic::ScopeTree,
+  span::{Atom, GetSpan, SPAN},
+};
+
+const DECORATE_CLASS: &str = "__decorateClass";
+const DECORATE_PARAM: &str = "__decorateParam";
+
+/// Lower TypeScript legacy decorators (`experimentalDecorators`) to calls of the runtime helpers,
+/// in the same way as `tsc` and esbuild.
+///
+/// ```js
+/// @dec class A { @m method(@p x) {} }
+/// // becomes
+/// let A = class { method(x) {} };
+/// __decorateClass([m, __decorateParam(0, p)], A.prototype, "method", 1);
+/// A = __decorateClass([dec], A);
+/// ```
+pub struct LowerLegacyDecorators<'me, 'a> {
+  ast: AstBuilder<'a>,
+  scopes: &'me ScopeTree,
+  runtime_module_id: &'me str,
+  is_decorate_class_used: bool,
+  is_decorate_param_used: bool,
+  errors: Vec<OxcDiagnostic>,
+}
+
+impl<'me, 'a> LowerLegacyDecorators<'me, 'a> {
+  pub fn new(
+    allocator: &'a Allocator,
+    scopes: &'me ScopeTree,
+    runtime_module_id: &'me str,
+  ) -> Self {
+    Self {
+      ast: AstBuilder::new(allocator),
+      scopes,
+      runtime_module_id,
+      is_decorate_class_used: false,
+      is_decorate_param_used: false,
+      errors: vec![],
+    }
+  }
+
+  /// Returns `true` if the program is changed.
+  pub fn build(mut self, program: &mut Program<'a>) -> Result<bool, Vec<OxcDiagnostic>> {
+    self.visit_program(program);
+    if !self.errors.is_empty() {
+      return Err(self.errors);
+    }
+    if !self.is_decorate_class_used {
+      return Ok(false);
+    }
+    // Import the helpers from the runtime module, like the binary loader does with `__toBinary`.
+    let mut specifiers = self.ast.vec();
+    for (name, is_used) in
+      [(DECORATE_CLASS, self.is_decorate_class_used), (DECORATE_PARAM, self.is_decorate_param_used)]
+    {
+      if is_used {
+        specifiers.push(self.ast.import_declaration_specifier_import_specifier(
+          SPAN,
+          self.ast.module_export_name_identifier_name(SPAN, name),
+          self.ast.binding_identifier(SPAN, name),
+          ImportOrExportKind::Value,
+        ));
+      }
+    }
+    let import_decl = self.ast.module_declaration_import_declaration(
+      SPAN,
+      Some(specifiers),
+      self.ast.string_literal(SPAN, self.ast.atom(self.runtime_module_id), None),
+      None,
+      NONE,
+      ImportOrExportKind::Value,
+    );
+    program.body.insert(0, Statement::from(import_decl));
+    Ok(true)
+  }
+
+  /// Append the lowered form of `stmt` to `out`.
+  fn lower_statement(&mut self, stmt: Statement<'a>, out: &mut ArenaVec<'a, Statement<'a>>) {
+    match stmt {
+      Statement::ClassDeclaration(mut class) if has_decorators(&class) => {
+        let name = class
+          .id
+          .as_ref()
+          .map_or_else(|| self.generate_unique_name("_class"), |id| id.name.clone());
+        let member_stmts = self.take_member_decorators(&mut class, &name);
+        match self.take_class_decorators(&mut class, &name) {
+          Some(class_stmt) => {
+            out.push(Statement::from(self.class_to_let(class, &name)));
+            out.extend(member_stmts);
+            out.push(class_stmt);
+          }
+          None => {
+            out.push(Statement::ClassDeclaration(class));
+            out.extend(member_stmts);
+          }
+        }
+      }
+      Statement::ExportNamedDeclaration(mut decl) if matches!(&decl.declaration, Some(Declaration::ClassDeclaration(class)) if has_decorators(class)) =>
+      {
+        let Some(Declaration::ClassDeclaration(mut class)) = decl.declaration.take() else {
+          unreachable!()
+        };
+        let name = class
+          .id
+          .as_ref()
+          .map_or_else(|| self.generate_unique_name("_class"), |id| id.name.clone());
+        let member_stmts = self.take_member_decorators(&mut class, &name);
+        let class_stmt = self.take_class_decorators(&mut class, &name);
+        decl.declaration = Some(if class_stmt.is_some() {
+          // `export class A {}` -> `export let A = class {}`
+          self.class_to_let(class, &name)
+        } else {
+          Declaration::ClassDeclaration(class)
+        });
+        out.push(Statement::ExportNamedDeclaration(decl));
+        out.extend(member_stmts);
+        out.extend(class_stmt);
+      }
+      Statement::ExportDefaultDeclaration(decl) if matches!(&decl.declaration, ExportDefaultDeclarationKind::ClassDeclaration(class) if has_decorators(class)) =>
+      {
+        let mut decl = decl.unbox();
+        let ExportDefaultDeclarationKind::ClassDeclaration(mut class) = decl.declaration else {
+          unreachable!()
+        };
+        // The decorators need a binding to refer to an anonymous class.
+        let name = match &class.id {
+          Some(id) => id.name.clone(),
+          None => {
+            let name = self.generate_unique_name("_default");
+            class.id = Some(self.ast.binding_identifier(SPAN, name.clone()));
+            name
+          }
+        };
+        let member_stmts = self.take_member_decorators(&mut class, &name);
+        match self.take_class_decorators(&mut class, &name) {
+          Some(class_stmt) => {
+            // `export default class A {}` -> `let A = class {}; export { A as default }`
+            out.push(Statement::from(self.class_to_let(class, &name)));
+            out.extend(member_stmts);
+            out.push(class_stmt);
+            let specifier = self.ast.export_specifier(
+              SPAN,
+              self.ast.module_export_name_identifier_reference(SPAN, name),
+              self.ast.module_export_name_identifier_name(SPAN, "default"),
+              ImportOrExportKind::Value,
+            );
+            out.push(Statement::from(self.ast.module_declaration_export_named_declaration(
+              decl.span,
+              None,
+              self.ast.vec1(specifier),
+              None,
+              ImportOrExportKind::Value,
+              NONE,
+            )));
+          }
+          None => {
+            decl.declaration = ExportDefaultDeclarationKind::ClassDeclaration(class);
+            out.push(Statement::ExportDefaultDeclaration(self.ast.alloc(decl)));
+            out.extend(member_stmts);
+          }
+        }
+      }
+      stmt => out.push(stmt),
+    }
+  }
+
+  /// A top-level name that neither shadows a global used by the module nor is already declared.
+  fn generate_unique_name(&self, base: &str) -> Atom<'a> {
+    let mut name = base.to_string();
+    let mut index = 1;
+    while self.scopes.get_root_binding(&name).is_some()
+      || self.scopes.root_unresolved_references().contains_key(name.as_str())
+    {
+      name = format!("{base}{index}");
+      index += 1;
+    }
+    self.ast.atom(&name)
+  }
+
+  /// `class A {}` -> `let A = class {}`. The class is named `A` by the assignment, and references to
+  /// `A` in the class body should see the decorated class.
+  fn class_to_let(&self, mut class: ArenaBox<'a, Class<'a>>, name: &Atom<'a>) -> Declaration<'a> {
+    class.r#type = ClassType::ClassExpression;
+    class.id = None;
+    let declarator = self.ast.variable_declarator(
+      SPAN,
+      VariableDeclarationKind::Let,
+      self.ast.binding_pattern(
+        self.ast.binding_pattern_kind_binding_identifier(SPAN, name.clone()),
+        NONE,
+        false,
+      ),
+      Some(Expression::ClassExpression(class)),
+      false,
+    );
+    self.ast.declaration_variable(
+      SPAN,
+      VariableDeclarationKind::Let,
+      self.ast.vec1(declarator),
+      false,
+    )
+  }
+
+  /// Take the decorators of the class and the parameters of its constructor, and return
+  /// `A = __decorateClass([...], A)` if there are any.
+  fn take_class_decorators(
+    &mut self,
+    class: &mut Class<'a>,
+    name: &Atom<'a>,
+  ) -> Option<Statement<'a>> {
+    let mut decorators = Self::take_decorators(&mut class.decorators);
+    for element in class.body.body.iter_mut() {
+      if let ClassElement::MethodDefinition(method) = element {
+        if method.kind == MethodDefinitionKind::Constructor {
+          self.take_param_decorators(&mut method.value.params.items, &mut decorators);
+        }
+      }
+    }
+    if decorators.is_empty() {
+      return None;
+    }
+    let call = self.decorate_class_call(
+      decorators,
+      self.ast.expression_identifier_reference(SPAN, name.clone()),
+      None,
+    );
+    let assignment = self.ast.expression_assignment(
+      SPAN,
+      oxc::ast::ast::AssignmentOperator::Assign,
+      self.ast.simple_assignment_target_identifier_reference(SPAN, name.clone()).into(),
+      call,
+    );
+    Some(self.ast.statement_expression(SPAN, assignment))
+  }
+
+  /// Take the decorators of the members and their parameters, and return
+  /// `__decorateClass([...], A.prototype, "key", kind)` for each decorated member.
+  /// Instance members are decorated before static members, like `tsc` does.
+  fn take_member_decorators(
+    &mut self,
+    class: &mut Class<'a>,
+    name: &Atom<'a>,
+  ) -> Vec<Statement<'a>> {
+    let mut instance_stmts = vec![];
+    let mut static_stmts = vec![];
+    for element in class.body.body.iter_mut() {
+      let (decorators, key, is_static, kind) = match element {
+        ClassElement::MethodDefinition(method) => {
+          if method.kind == MethodDefinitionKind::Constructor {
+            continue;
+          }
+          let mut decorators = Self::take_decorators(&mut method.decorators);
+          self.take_param_decorators(&mut method.value.params.items, &mut decorators);
+          (decorators, &method.key, method.r#static, 1.0)
+        }
+        ClassElement::PropertyDefinition(property) => {
+          (Self::take_decorators(&mut property.decorators), &property.key, property.r#static, 2.0)
+        }
+        _ => continue,
+      };
+      if decorators.is_empty() {
+        continue;
+      }
+      let Some(key) = self.member_key(key) else { continue };
+      let class_ref = self.ast.expression_identifier_reference(SPAN, name.clone());
+      let target = if is_static {
+        class_ref
+      } else {
+        Expression::from(self.ast.member_expression_static(
+          SPAN,
+          class_ref,
+          self.ast.identifier_name(SPAN, "prototype"),
+          false,
+        ))
+      };
+      let call = self.decorate_class_call(decorators, target, Some((key, kind)));
+      let stmt = self.ast.statement_expression(SPAN, call);
+      if is_static {
+        static_stmts.push(stmt);
+      } else {
+        instance_stmts.push(stmt);
+      }
+    }
+    instance_stmts.extend(static_stmts);
+    instance_stmts
+  }
+
+  /// The key of a decorated member, evaluated again outside of the class.
+  fn member_key(&mut self, key: &PropertyKey<'a>) -> Option<Expression<'a>> {
+    match key {
+      PropertyKey::StaticIdentifier(ident) => {
+        Some(self.ast.expression_string_literal(SPAN, ident.name.clone(), None))
+      }
+      PropertyKey::StringLiteral(lit) => {
+        Some(self.ast.expression_string_literal(SPAN, lit.value.clone(), None))
+      }
+      PropertyKey::NumericLiteral(lit) => {
+        Some(self.ast.expression_numeric_literal(SPAN, lit.value, None, NumberBase::Decimal))
+      }
+      PropertyKey::PrivateIdentifier(ident) => {
+        self.errors.push(
+          OxcDiagnostic::error(
+            "Decorators are not valid on private members with `experimentalDecorators`.",
+          )
+          .with_label(ident.span),
+        );
+        None
+      }
+      // Keys like `[Symbol.iterator]` are side effect free to evaluate twice.
+      _ => match key.as_expression() {
+        Some(expr) if is_static_member_chain(expr) => Some(expr.clone_in(self.ast.allocator)),
+        _ => {
+          self.errors.push(
+            OxcDiagnostic::error(
+              "Computed keys of decorated members must be identifiers or member expressions with `experimentalDecorators`.",
+            )
+            .with_label(key.span()),
+          );
+          None
+        }
+      },
+    }
+  }
+
+  fn take_decorators(decorators: &mut ArenaVec<'a, Decorator<'a>>) -> Vec<Expression<'a>> {
+    decorators.drain(..).map(|decorator| decorator.expression).collect()
+  }
+
+  /// `(@p x)` -> `__decorateParam(0, p)`
+  #[allow(clippy::cast_precision_loss)]
+  fn take_param_decorators(
+    &mut self,
+    params: &mut ArenaVec<'a, oxc::ast::ast::FormalParameter<'a>>,
+    out: &mut Vec<Expression<'a>>,
+  ) {
+    for (index, param) in params.iter_mut().enumerate() {
+      for decorator in param.decorators.drain(..) {
+        self.is_decorate_param_used = true;
+        let arguments = self.ast.vec_from_iter([
+          Argument::from(self.ast.expression_numeric_literal(
+            SPAN,
+            index as f64,
+            None,
+            NumberBase::Decimal,
+          )),
+          Argument::from(decorator.expression),
+        ]);
+        out.push(self.ast.expression_call(
+          SPAN,
+          self.ast.expression_identifier_reference(SPAN, DECORATE_PARAM),
+          NONE,
+          arguments,
+          false,
+        ));
+      }
+    }
+  }
+
+  fn decorate_class_call(
+    &mut self,
+    decorators: Vec<Expression<'a>>,
+    target: Expression<'a>,
+    key_and_kind: Option<(Expression<'a>, f64)>,
+  ) -> Expression<'a> {
+    self.is_decorate_class_used = true;
+    let decorators = self.ast.expression_array(
+      SPAN,
+      self.ast.vec_from_iter(decorators.into_iter().map(ArrayExpressionElement::from)),
+      None,
+    );
+    let mut arguments =
+      self.ast.vec_from_iter([Argument::from(decorators), Argument::from(target)]);
+    if let Some((key, kind)) = key_and_kind {
+      arguments.push(Argument::from(key));
+      arguments.push(Argument::from(self.ast.expression_numeric_literal(
+        SPAN,
+        kind,
+        None,
+        NumberBase::Decimal,
+      )));
+    }
+    self.ast.expression_call(
+      SPAN,
+      self.ast.expression_identifier_reference(SPAN, DECORATE_CLASS),
+      NONE,
+      arguments,
+      false,
+    )
+  }
+}
+
+impl<'a> VisitMut<'a> for LowerLegacyDecorators<'_, 'a> {
+  fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+    walk_mut::walk_statements(self, stmts);
+    if !stmts.iter().any(is_decorated_class_statement) {
+      return;
+    }
+    let mut lowered = self.ast.vec_with_capacity(stmts.len());
+    for stmt in stmts.drain(..) {
+      self.lower_statement(stmt, &mut lowered);
+    }
+    *stmts = lowered;
+  }
+}
+
+fn is_decorated_class_statement(stmt: &Statement) -> bool {
+  match stmt {
+    Statement::ClassDeclaration(class) => has_decorators(class),
+    Statement::ExportNamedDeclaration(decl) => {
+      matches!(&decl.declaration, Some(Declaration::ClassDeclaration(class)) if has_decorators(class))
+    }
+    Statement::ExportDefaultDeclaration(decl) => {
+      matches!(&decl.declaration, ExportDefaultDeclarationKind::ClassDeclaration(class) if has_decorators(class))
+    }
+    _ => false,
+  }
+}
+
+fn has_decorators(class: &Class) -> bool {
+  !class.decorators.is_empty()
+    || class.body.body.iter().any(|element| match element {
+      ClassElement::MethodDefinition(method) => {
+        !method.decorators.is_empty()
+          || method.value.params.items.iter().any(|param| !param.decorators.is_empty())
+      }
+      ClassElement::PropertyDefinition(property) => !property.decorators.is_empty(),
+      _ => false,
+    })
+}
+
+fn is_static_member_chain(expr: &Expression) -> bool {
+  match expr {
+    Expression::Identifier(_) => true,
+    Expression::StaticMemberExpression(member) => is_static_member_chain(&member.object),
+    _ => false,
+  }
+}
@@ -1,7 +1,11 @@
 mod ecma_ast;
 mod ecma_compiler;
+mod legacy_decorators;
+mod pure_calls;
 
 pub use crate::{
   ecma_ast::{program_cell::WithMutFields, EcmaAst, ToSourceString},
-  ecma_compiler::{EcmaCompiler, PrintOptions},
+  ecma_compiler::{pure_esm_js_oxc_source_type, EcmaCompiler, PrintOptions},
+  legacy_decorators::LowerLegacyDecorators,
+  pure_calls::RemovePureCalls,
 };
@@ -0,0 +1,103 @@
+use oxc::{
+  allocator::{Allocator, Vec as ArenaVec},
+  ast::{
+    ast::{Argument, Expression, Program, Statement},
+    visit::walk_mut,
+    AstBuilder, VisitMut,
+  },
+  semantic::SymbolTable,
+  span::GetSpan,
+};
+
+/// Remove calls of `pure_funcs` whose result is unused, like `pure_funcs` of terser.
+/// Arguments that may have side effects are kept, so `assert(check())` becomes `check()`.
+pub struct RemovePureCalls<'me, 'a> {
+  ast: AstBuilder<'a>,
+  symbols: &'me SymbolTable,
+  pure_funcs: &'me [String],
+}
+
+impl<'me, 'a> RemovePureCalls<'me, 'a> {
+  pub fn new(
+    allocator: &'a Allocator,
+    symbols: &'me SymbolTable,
+    pure_funcs: &'me [String],
+  ) -> Self {
+    Self { ast: AstBuilder::new(allocator), symbols, pure_funcs }
+  }
+
+  pub fn build(mut self, program: &mut Program<'a>) {
+    if !self.pure_funcs.is_empty() {
+      self.visit_program(program);
+    }
+  }
+
+  fn is_pure_callee(&self, callee: &Expression<'a>) -> bool {
+    let mut path = vec![];
+    let mut expr = callee;
+    loop {
+      match expr {
+        Expression::Identifier(ident) => {
+          path.push(ident.name.as_str());
+          break;
+        }
+        Expression::StaticMemberExpression(member) => {
+          path.push(member.property.name.as_str());
+          expr = &member.object;
+        }
+        _ => return false,
+      }
+    }
+    path.reverse();
+    let path = path.join(".");
+    self.pure_funcs.iter().any(|name| *name == path)
+  }
+
+  /// Keep the arguments that may have side effects. Spread arguments should be checked by the caller.
+  fn take_side_effect_arguments(
+    &self,
+    arguments: &mut ArenaVec<'a, Argument<'a>>,
+  ) -> ArenaVec<'a, Expression<'a>> {
+    let mut kept = self.ast.vec();
+    for expr in arguments.iter_mut().filter_map(Argument::as_expression_mut) {
+      let is_side_effect_free = match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::ThisExpression(_) => true,
+        // Reading an undeclared global throws.
+        Expression::Identifier(ident) => ident.reference_id.get().is_some_and(|reference_id| {
+          self.symbols.get_reference(reference_id).symbol_id().is_some()
+        }),
+        _ => false,
+      };
+      if !is_side_effect_free {
+        kept.push(self.ast.move_expression(expr));
+      }
+    }
+    kept
+  }
+}
+
+impl<'a> VisitMut<'a> for RemovePureCalls<'_, 'a> {
+  fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+    walk_mut::walk_statement(self, stmt);
+    let span = stmt.span();
+    let Statement::ExpressionStatement(expr_stmt) = stmt else { return };
+    let Expression::CallExpression(call_expr) = &mut expr_stmt.expression else { return };
+    // `foo(...args)` iterates `args`, which may have side effects.
+    if !self.is_pure_callee(&call_expr.callee)
+      || call_expr.arguments.iter().any(|arg| !arg.is_expression())
+    {
+      return;
+    }
+    let mut kept = self.take_side_effect_arguments(&mut call_expr.arguments);
+    *stmt = match kept.as_mut_slice() {
+      [] => self.ast.statement_empty(span),
+      [expr] => self.ast.statement_expression(span, self.ast.move_expression(expr)),
+      _ => self.ast.statement_expression(span, self.ast.expression_sequence(span, kept)),
+    };
+  }
+}
@@ -870,6 +870,7 @@ impl<'ast> AstSnippet<'ast> {
 
   pub fn keep_name_call_expr_stmt(
     &self,
+    helper_name: PassedStr,
     original_name: PassedStr,
     new_name: PassedStr,
   ) -> Statement<'ast> {
@@ -877,7 +878,7 @@ impl<'ast> AstSnippet<'ast> {
       SPAN,
       self.builder.expression_call(
         SPAN,
-        self.builder.expression_identifier_reference(SPAN, "__name"),
+        self.builder.expression_identifier_reference(SPAN, helper_name),
         NONE,
         {
           let mut items = self.builder.vec_with_capacity(2);
@@ -890,14 +891,18 @@ impl<'ast> AstSnippet<'ast> {
     )
   }
 
-  pub fn static_block_keep_name_helper(&self, name: PassedStr) -> ClassElement<'ast> {
+  pub fn static_block_keep_name_helper(
+    &self,
+    helper_name: PassedStr,
+    name: PassedStr,
+  ) -> ClassElement<'ast> {
     self.builder.class_element_static_block(
       SPAN,
       self.builder.vec1(self.builder.statement_expression(
         SPAN,
         self.builder.expression_call(
           SPAN,
-          self.builder.expression_identifier_reference(SPAN, "__name"),
+          self.builder.expression_identifier_reference(SPAN, helper_name),
           NONE,
           {
             let mut items = self.builder.vec_with_capacity(2);
@@ -1,3 +1,4 @@
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 use super::severity::Severity;
@@ -7,9 +8,13 @@ use oxc::diagnostics::OxcDiagnostic;
 use oxc::{diagnostics::LabeledSpan, span::Span};
 use oxc_resolver::ResolveError;
 
+use crate::event_kind::EventKind;
 use crate::events::assign_to_import::AssignToImport;
+use crate::events::data_parse::DataParse;
 use crate::events::export_undefined_variable::ExportUndefinedVariable;
+use crate::events::ignored_by_browser_field::IgnoredByBrowserField;
 use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
+use crate::events::import_attribute_type_conflict::ImportAttributeTypeConflict;
 use crate::events::import_is_undefined::ImportIsUndefined;
 use crate::events::invalid_define_config::InvalidDefineConfig;
 use crate::events::invalid_option::{InvalidOption, InvalidOptionType};
@@ -26,7 +31,7 @@ use crate::events::DiagnosableArcstr;
 use crate::events::NapiError;
 use crate::events::{
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
-  circular_dependency::CircularDependency,
+  circular_dependency::{CircularDependency, CircularDependencyImport},
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
   eval::Eval,
   external_entry::ExternalEntry,
@@ -34,7 +39,10 @@ use crate::events::{
   invalid_export_option::InvalidExportOption,
   missing_export::MissingExport,
   mixed_export::MixedExport,
+  module_level_directive::ModuleLevelDirective,
   parse_error::ParseError,
+  plugin_log::PluginLog,
+  size_budget_exceeded::SizeBudgetExceeded,
   sourcemap_error::SourceMapError,
   unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
@@ -65,10 +73,12 @@ impl BuildDiagnostic {
   pub fn unresolved_entry(
     unresolved_id: impl AsRef<Path>,
     resolve_error: Option<ResolveError>,
+    help: Option<String>,
   ) -> Self {
     Self::new_inner(UnresolvedEntry {
       unresolved_id: unresolved_id.as_ref().to_path_buf(),
       resolve_error,
+      help,
     })
   }
 
@@ -82,8 +92,17 @@ impl BuildDiagnostic {
     importee: DiagnosableArcstr,
     reason: String,
     title: Option<&'static str>,
+    help: Option<String>,
   ) -> Self {
-    Self::new_inner(DiagnosableResolveError { source, importer_id, importee, reason, title })
+    Self::new_inner(DiagnosableResolveError { source, importer_id, importee, reason, title, help })
+  }
+
+  pub fn ignored_by_browser_field(
+    source: ArcStr,
+    importer_id: ArcStr,
+    importee: DiagnosableArcstr,
+  ) -> Self {
+    Self::new_inner(IgnoredByBrowserField { source, importer_id, importee })
   }
 
   pub fn unloadable_dependency(
@@ -100,8 +119,30 @@ impl BuildDiagnostic {
     Self::new_inner(SourceMapError { error })
   }
 
-  pub fn circular_dependency(paths: Vec<String>) -> Self {
-    Self::new_inner(CircularDependency { paths })
+  pub fn circular_dependency(paths: Vec<String>, imports: Vec<CircularDependencyImport>) -> Self {
+    Self::new_inner(CircularDependency { paths, imports })
+  }
+
+  pub fn import_attribute_type_conflict(
+    importer: String,
+    importer_source: ArcStr,
+    import_span: Span,
+    importee: String,
+    asserted: String,
+    loaded: String,
+  ) -> Self {
+    Self::new_inner(ImportAttributeTypeConflict {
+      importer,
+      importer_source,
+      import_span,
+      importee,
+      asserted,
+      loaded,
+    })
+  }
+
+  pub fn size_budget_exceeded(event: SizeBudgetExceeded) -> Self {
+    Self::new_inner(event)
   }
 
   pub fn unresolved_import_treated_as_external(
@@ -132,6 +173,10 @@ impl BuildDiagnostic {
     })
   }
 
+  pub fn module_level_directive(module: String, directive: String) -> Self {
+    Self::new_inner(ModuleLevelDirective { module, directive })
+  }
+
   pub fn mixed_export(module_name: ArcStr, entry_module: ArcStr, export_keys: Vec<ArcStr>) -> Self {
     Self::new_inner(MixedExport { module_name, entry_module, export_keys })
   }
@@ -253,6 +298,11 @@ impl BuildDiagnostic {
     diagnostic
   }
 
+  pub fn plugin_log(log: PluginLog) -> Self {
+    let severity = log.severity;
+    Self::new_inner(log).with_severity(severity)
+  }
+
   pub fn eval(filename: String, source: ArcStr, span: Span) -> Self {
     Self::new_inner(Eval { filename, span, source })
   }
@@ -284,12 +334,36 @@ impl BuildDiagnostic {
     Self::new_inner(JsonParse { filename, source, span, message })
   }
 
+  #[allow(clippy::cast_possible_truncation)]
+  pub fn yaml_parse(
+    filename: ArcStr,
+    source: ArcStr,
+    span: Option<Range<usize>>,
+    message: ArcStr,
+  ) -> Self {
+    let span = span.map(|span| Span::new(span.start as u32, span.end as u32));
+    Self::new_inner(DataParse { kind: EventKind::YamlParse, filename, source, span, message })
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  pub fn toml_parse(
+    filename: ArcStr,
+    source: ArcStr,
+    span: Option<Range<usize>>,
+    message: ArcStr,
+  ) -> Self {
+    let span = span.map(|span| Span::new(span.start as u32, span.end as u32));
+    Self::new_inner(DataParse { kind: EventKind::TomlParse, filename, source, span, message })
+  }
+
   pub fn invalid_define_config(message: String) -> Self {
     Self::new_inner(InvalidDefineConfig { message })
   }
 
   pub fn unhandleable_error(err: anyhow::Error) -> Self {
-    downcast_napi_error_diagnostics(err)
+    err
+      .downcast::<BuildDiagnostic>()
+      .or_else(downcast_napi_error_diagnostics)
       .unwrap_or_else(|err| Self::new_inner(UnhandleableError(err)))
   }
 }
@@ -20,6 +20,9 @@ pub struct BuildDiagnostic {
   severity: Severity,
 }
 
+// Allows plugins to return a `BuildDiagnostic` through `anyhow::Error`, see `BuildDiagnostic::unhandleable_error`.
+impl std::error::Error for BuildDiagnostic {}
+
 fn _assert_build_error_send_sync() {
   fn assert_send_sync<T: Send + Sync>() {}
   assert_send_sync::<BuildDiagnostic>();
@@ -51,6 +54,26 @@ impl BuildDiagnostic {
     self
   }
 
+  #[must_use]
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = severity;
+    self
+  }
+
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
+  /// Name of the plugin that reported the diagnostic.
+  pub fn plugin(&self) -> Option<&str> {
+    self.inner.plugin()
+  }
+
+  /// The code given by the plugin that reported the diagnostic.
+  pub fn plugin_code(&self) -> Option<&str> {
+    self.inner.plugin_code()
+  }
+
   pub fn to_diagnostic(&self) -> Diagnostic {
     self.to_diagnostic_with(&DiagnosticOptions::default())
   }
@@ -104,7 +127,9 @@ impl From<BuildDiagnostic> for BatchedBuildDiagnostic {
 
 impl From<anyhow::Error> for BatchedBuildDiagnostic {
   fn from(err: anyhow::Error) -> Self {
-    Self::new(vec![BuildDiagnostic::unhandleable_error(err)])
+    err
+      .downcast::<BatchedBuildDiagnostic>()
+      .unwrap_or_else(|err| Self::new(vec![BuildDiagnostic::unhandleable_error(err)]))
   }
 }
 
@@ -133,6 +158,21 @@ impl BatchedBuildDiagnostic {
   }
 }
 
+impl Display for BatchedBuildDiagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (idx, diagnostic) in self.0.iter().enumerate() {
+      if idx > 0 {
+        writeln!(f)?;
+      }
+      diagnostic.fmt(f)?;
+    }
+    Ok(())
+  }
+}
+
+// Allows returning the diagnostics from plugin hooks, e.g. the syntax errors of `PluginContext::parse`.
+impl std::error::Error for BatchedBuildDiagnostic {}
+
 impl Deref for BatchedBuildDiagnostic {
   type Target = Vec<BuildDiagnostic>;
 
@@ -1,5 +1,7 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Severity {
   Error,
   Warning,
+  Info,
+  Debug,
 }
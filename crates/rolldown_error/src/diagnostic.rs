@@ -66,6 +66,7 @@ impl Diagnostic {
       match self.severity {
         Severity::Error => ReportKind::Error,
         Severity::Warning => ReportKind::Warning,
+        Severity::Info | Severity::Debug => ReportKind::Advice,
       },
       (ArcStr::default(), 0..0),
     )
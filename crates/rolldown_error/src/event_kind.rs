@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+#[derive(Debug, Clone, Copy)]
 pub enum EventKind {
   // --- These kinds are copied from rollup: https://github.com/rollup/rollup/blob/0b665c31833525c923c0fc20f43ebfca748c6670/src/utils/logs.ts#L102-L179
   AmbiguousExternalNamespace,
@@ -13,6 +14,7 @@ pub enum EventKind {
   MissingNameOptionForIifeExport,
   MissingNameOptionForUmdExport,
   MixedExport,
+  ModuleLevelDirective,
   ParseError,
   SourcemapError,
   UnresolvedEntry,
@@ -27,9 +29,18 @@ pub enum EventKind {
   UnsupportedFeature,
 
   // --- These kinds are rolldown specific
+  ChunkSizeBudgetExceeded,
   JsonParse,
+  TomlParse,
+  YamlParse,
+  IgnoredByBrowserField,
   IllegalReassignment,
+  ImportAttributeTypeConflict,
+  InitialLoadSizeBudgetExceeded,
   InvalidDefineConfig,
+  PluginError,
+  PluginLog,
+  PluginWarning,
   ResolveError(Option<&'static str>),
   UnhandleableError,
   UnloadableDependency,
@@ -50,6 +61,7 @@ impl Display for EventKind {
       EventKind::InvalidExportOption => write!(f, "INVALID_EXPORT_OPTION"),
       EventKind::InvalidOption => write!(f, "INVALID_OPTION"),
       EventKind::MixedExport => write!(f, "MIXED_EXPORT"),
+      EventKind::ModuleLevelDirective => write!(f, "MODULE_LEVEL_DIRECTIVE"),
       EventKind::MissingGlobalName => write!(f, "MISSING_GLOBAL_NAME"),
       EventKind::MissingNameOptionForIifeExport => write!(f, "MISSING_NAME_OPTION_FOR_IIFE_EXPORT"),
       EventKind::MissingNameOptionForUmdExport => write!(f, "MISSING_NAME_OPTION_FOR_UMD_EXPORT"),
@@ -67,9 +79,18 @@ impl Display for EventKind {
       EventKind::UnsupportedFeature => write!(f, "UNSUPPORTED_FEATURE"),
 
       // --- Rolldown specific
+      EventKind::ChunkSizeBudgetExceeded => write!(f, "CHUNK_SIZE_BUDGET_EXCEEDED"),
       EventKind::JsonParse => write!(f, "JSON_PARSE"),
+      EventKind::TomlParse => write!(f, "TOML_PARSE"),
+      EventKind::YamlParse => write!(f, "YAML_PARSE"),
+      EventKind::IgnoredByBrowserField => write!(f, "IGNORED_BY_BROWSER_FIELD"),
       EventKind::IllegalReassignment => write!(f, "ILLEGAL_REASSIGNMENT"),
+      EventKind::ImportAttributeTypeConflict => write!(f, "IMPORT_ATTRIBUTE_TYPE_CONFLICT"),
+      EventKind::InitialLoadSizeBudgetExceeded => write!(f, "INITIAL_LOAD_SIZE_BUDGET_EXCEEDED"),
       EventKind::InvalidDefineConfig => write!(f, "INVALID_DEFINE_CONFIG"),
+      EventKind::PluginError => write!(f, "PLUGIN_ERROR"),
+      EventKind::PluginLog => write!(f, "PLUGIN_LOG"),
+      EventKind::PluginWarning => write!(f, "PLUGIN_WARNING"),
       EventKind::ResolveError(title) => match title {
         Some(title) => write!(f, "{title}"),
         None => write!(f, "RESOLVE_ERROR"),
@@ -1,9 +1,24 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
 use super::BuildEvent;
-use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+/// The import statement in `filename` that imports `importee`, the next module of the cycle.
+#[derive(Debug)]
+pub struct CircularDependencyImport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub importee: String,
+}
 
 #[derive(Debug)]
 pub struct CircularDependency {
   pub paths: Vec<String>,
+  pub imports: Vec<CircularDependencyImport>,
 }
 
 impl CircularDependency {
@@ -20,4 +35,15 @@ impl BuildEvent for CircularDependency {
   fn message(&self, opts: &DiagnosticOptions) -> String {
     format!("Circular dependency: {}.", self.stable_paths(opts).join(" -> "))
   }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    for import in &self.imports {
+      let file_id = diagnostic.add_file(import.filename.clone(), import.source.clone());
+      diagnostic.add_label(
+        &file_id,
+        import.span.start..import.span.end,
+        format!("Imports \"{}\".", opts.stabilize_path(&import.importee)),
+      );
+    }
+  }
 }
@@ -0,0 +1,34 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// A parse error of a data file that is loaded like json, e.g. a yaml or toml file.
+#[derive(Debug)]
+pub struct DataParse {
+  pub kind: crate::event_kind::EventKind,
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  pub span: Option<Span>,
+  pub message: ArcStr,
+}
+
+impl BuildEvent for DataParse {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    self.kind
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("Failed to parse {}: {}", opts.stabilize_path(self.filename.as_str()), self.message)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    if let Some(span) = self.span {
+      let filename = opts.stabilize_path(self.filename.as_str());
+      let file_id = diagnostic.add_file(filename, self.source.clone());
+      diagnostic.add_label(&file_id, span.start..span.end, self.message.to_string());
+    }
+  }
+}
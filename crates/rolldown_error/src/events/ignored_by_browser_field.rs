@@ -0,0 +1,43 @@
+use arcstr::ArcStr;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::{BuildEvent, DiagnosableArcstr};
+
+#[derive(Debug)]
+pub struct IgnoredByBrowserField {
+  pub source: ArcStr,
+  pub importer_id: ArcStr,
+  pub importee: DiagnosableArcstr,
+}
+
+impl BuildEvent for IgnoredByBrowserField {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::IgnoredByBrowserField
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let importee = match &self.importee {
+      DiagnosableArcstr::String(str) => str.as_str(),
+      DiagnosableArcstr::Span(span) => &self.source.as_str()[*span],
+    };
+    format!(
+      r#"{importee} imported by {} is mapped to `false` by the "browser" field. It's replaced with an empty module."#,
+      opts.stabilize_path(self.importer_id.as_str())
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    if let DiagnosableArcstr::Span(span) = self.importee {
+      if !span.is_unspanned() {
+        let importer_file =
+          diagnostic.add_file(opts.stabilize_path(self.importer_id.as_str()), self.source.clone());
+        diagnostic.add_label(
+          &importer_file,
+          span.start..span.end,
+          "Ignored by the \"browser\" field".to_string(),
+        );
+      }
+    }
+  }
+}
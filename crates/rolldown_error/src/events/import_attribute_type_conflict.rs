@@ -0,0 +1,41 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use super::BuildEvent;
+use crate::{diagnostic::Diagnostic, event_kind::EventKind, DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct ImportAttributeTypeConflict {
+  pub importer: String,
+  pub importer_source: ArcStr,
+  pub import_span: Span,
+  pub importee: String,
+  pub asserted: String,
+  pub loaded: String,
+}
+
+impl BuildEvent for ImportAttributeTypeConflict {
+  fn kind(&self) -> EventKind {
+    EventKind::ImportAttributeTypeConflict
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "\"{}\" is imported as module type `{}` by \"{}\", but it is loaded as module type `{}`.",
+      opts.stabilize_path(&self.importee),
+      self.asserted,
+      opts.stabilize_path(&self.importer),
+      self.loaded
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.importer), self.importer_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.import_span.start..self.import_span.end,
+      format!("Imported as module type `{}` here.", self.asserted),
+    );
+  }
+}
@@ -6,6 +6,7 @@ pub enum InvalidOptionType {
   UnsupportedCodeSplittingFormat(String),
   InvalidOutputFile,
   InvalidOutputDirOption,
+  TsconfigFilenameWithTsconfigAuto,
 }
 
 #[derive(Debug)]
@@ -24,7 +25,8 @@ impl BuildEvent for InvalidOption {
         format!("Invalid value \"{format}\" for option \"output.format\" - UMD and IIFE are not supported for code splitting. You may set `output.inlineDynamicImports` to `true` when using dynamic imports.")
       }
       InvalidOptionType::InvalidOutputFile => "Invalid value for option \"output.file\" - When building multiple chunks, the \"output.dir\" option must be used, not \"output.file\". You may set `output.inlineDynamicImports` to `true` when using dynamic imports.".to_string(),
-      InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string()
+      InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string(),
+      InvalidOptionType::TsconfigFilenameWithTsconfigAuto => "Options \"resolve.tsconfigFilename\" and \"resolve.tsconfigAuto\" are both set - \"resolve.tsconfigFilename\" takes precedence and \"resolve.tsconfigAuto\" is ignored.".to_string(),
     }
   }
 }
@@ -11,11 +11,14 @@ pub mod ambiguous_external_namespace;
 pub mod assign_to_import;
 pub mod circular_dependency;
 pub mod commonjs_variable_in_esm;
+pub mod data_parse;
 pub mod eval;
 pub mod export_undefined_variable;
 pub mod external_entry;
 pub mod forbid_const_assign;
+pub mod ignored_by_browser_field;
 pub mod illegal_identifier_as_name;
+pub mod import_attribute_type_conflict;
 pub mod import_is_undefined;
 pub mod invalid_define_config;
 pub mod invalid_export_option;
@@ -26,8 +29,11 @@ pub mod missing_global_name;
 pub mod missing_name_option_for_iife_export;
 pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
+pub mod module_level_directive;
 pub mod parse_error;
+pub mod plugin_log;
 pub mod resolve_error;
+pub mod size_budget_exceeded;
 pub mod sourcemap_error;
 pub mod unhandleable_error;
 pub mod unloadable_dependency;
@@ -42,6 +48,16 @@ pub trait BuildEvent: Debug + Sync + Send {
   fn message(&self, opts: &DiagnosticOptions) -> String;
 
   fn on_diagnostic(&self, _diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {}
+
+  /// Name of the plugin that reported the event.
+  fn plugin(&self) -> Option<&str> {
+    None
+  }
+
+  /// The code given by the plugin that reported the event.
+  fn plugin_code(&self) -> Option<&str> {
+    None
+  }
 }
 
 impl<T: BuildEvent + 'static> From<T> for Box<dyn BuildEvent>
@@ -0,0 +1,23 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ModuleLevelDirective {
+  pub(crate) module: String,
+  pub(crate) directive: String,
+}
+
+impl BuildEvent for ModuleLevelDirective {
+  fn kind(&self) -> EventKind {
+    EventKind::ModuleLevelDirective
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Module level directive \"{}\" in {:?} was ignored, because the module is bundled into a chunk without it.",
+      self.directive,
+      opts.stabilize_path(&self.module)
+    )
+  }
+}
@@ -0,0 +1,57 @@
+use arcstr::ArcStr;
+
+use crate::{
+  build_error::severity::Severity, diagnostic::Diagnostic, event_kind::EventKind,
+  types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+/// A log reported by a plugin through its `PluginContext`.
+#[derive(Debug)]
+pub struct PluginLog {
+  pub plugin: String,
+  /// The severity the plugin reported the log with, which decides the code of the log even if
+  /// `on_log` changes its severity later.
+  pub severity: Severity,
+  /// The code given by the plugin, e.g. for filtering the log in `on_log`.
+  pub plugin_code: Option<String>,
+  pub message: String,
+  pub id: Option<String>,
+  pub source: Option<ArcStr>,
+  /// Byte offset of the position in `source`.
+  pub pos: Option<u32>,
+}
+
+impl BuildEvent for PluginLog {
+  fn kind(&self) -> EventKind {
+    match self.severity {
+      Severity::Error => EventKind::PluginError,
+      Severity::Warning => EventKind::PluginWarning,
+      Severity::Info | Severity::Debug => EventKind::PluginLog,
+    }
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    match &self.id {
+      Some(id) => format!("[plugin {}] {}: {}", self.plugin, opts.stabilize_path(id), self.message),
+      None => format!("[plugin {}] {}", self.plugin, self.message),
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let (Some(id), Some(source), Some(pos)) = (&self.id, &self.source, self.pos) else {
+      return;
+    };
+    let file_id = diagnostic.add_file(opts.stabilize_path(id), source.clone());
+    diagnostic.add_label(&file_id, pos..pos, self.message.clone());
+  }
+
+  fn plugin(&self) -> Option<&str> {
+    Some(&self.plugin)
+  }
+
+  fn plugin_code(&self) -> Option<&str> {
+    self.plugin_code.as_deref()
+  }
+}
@@ -0,0 +1,59 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct SizeBudgetExceeded {
+  /// The filename of the output file, or of the entry chunk if `initial_load` is set.
+  pub filename: String,
+  /// Filenames of the chunks loaded initially by the entry chunk, including itself.
+  pub initial_load: Option<Vec<String>>,
+  /// `raw`, `gzip` or `brotli`.
+  pub measure: &'static str,
+  pub size: u64,
+  pub limit: u64,
+  /// The largest modules of the output with their rendered sizes, in descending order.
+  pub largest_modules: Vec<(String, usize)>,
+}
+
+impl BuildEvent for SizeBudgetExceeded {
+  fn kind(&self) -> EventKind {
+    if self.initial_load.is_some() {
+      EventKind::InitialLoadSizeBudgetExceeded
+    } else {
+      EventKind::ChunkSizeBudgetExceeded
+    }
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let subject = match &self.initial_load {
+      Some(chunks) => {
+        format!("Initial load of entry \"{}\" ({})", self.filename, chunks.join(", "))
+      }
+      None => format!("\"{}\"", self.filename),
+    };
+    let mut message = format!(
+      "{subject} is {} ({}), which exceeds the budget of {}.",
+      format_size(self.size),
+      self.measure,
+      format_size(self.limit)
+    );
+    if !self.largest_modules.is_empty() {
+      let modules = self
+        .largest_modules
+        .iter()
+        .map(|(id, size)| format!("{} ({})", opts.stabilize_path(id), format_size(*size as u64)))
+        .collect::<Vec<_>>();
+      message.push_str(&format!(" Largest modules: {}.", modules.join(", ")));
+    }
+    message
+  }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_size(bytes: u64) -> String {
+  if bytes < 1000 {
+    format!("{bytes} B")
+  } else {
+    format!("{:.2} kB", bytes as f64 / 1000.0)
+  }
+}
@@ -8,6 +8,7 @@ use std::path::PathBuf;
 pub struct UnresolvedEntry {
   pub(crate) unresolved_id: PathBuf,
   pub(crate) resolve_error: Option<ResolveError>,
+  pub(crate) help: Option<String>,
 }
 
 impl BuildEvent for UnresolvedEntry {
@@ -31,4 +32,14 @@ impl BuildEvent for UnresolvedEntry {
 
     message.join("\n")
   }
+
+  fn on_diagnostic(
+    &self,
+    diagnostic: &mut crate::diagnostic::Diagnostic,
+    _opts: &DiagnosticOptions,
+  ) {
+    if let Some(help) = &self.help {
+      diagnostic.add_help(help.clone());
+    }
+  }
 }
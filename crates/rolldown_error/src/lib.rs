@@ -12,8 +12,11 @@ pub use crate::{
   build_error::{severity::Severity, BuildDiagnostic},
   event_kind::EventKind,
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
+  events::circular_dependency::CircularDependencyImport,
   events::commonjs_variable_in_esm::CjsExportSpan,
   events::invalid_option::InvalidOptionType,
+  events::plugin_log::PluginLog,
+  events::size_budget_exceeded::SizeBudgetExceeded,
   events::unloadable_dependency::UnloadableDependencyContext,
   events::DiagnosableArcstr,
   locator::line_column_to_byte_offset,
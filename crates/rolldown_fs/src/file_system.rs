@@ -1,8 +1,12 @@
-use std::{io, path::Path};
+use std::{
+  fmt::Debug,
+  io,
+  path::{Path, PathBuf},
+};
 
 use oxc_resolver::FileSystem as OxcResolverFileSystem;
 
-pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
+pub trait FileSystem: Debug + Send + Sync + OxcResolverFileSystem {
   /// # Errors
   ///
   /// * See [std::fs::remove_dir_all]
@@ -27,4 +31,12 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   ///
   /// * See [std::fs::read]
   fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+  /// # Errors
+  ///
+  /// * See [std::fs::read_dir]
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+  /// See [std::path::Path::is_dir]
+  fn is_dir(&self, path: &Path) -> bool;
 }
@@ -13,7 +13,7 @@ pub type FsPath = String;
 pub type FsFileContent = String;
 pub type FsFileMap<'a> = &'a [(&'a FsPath, &'a FsFileContent)];
 
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct MemoryFileSystem {
   // root path
   fs: Arc<MemoryFS>,
@@ -85,6 +85,24 @@ impl FileSystem for MemoryFileSystem {
       .read_to_end(&mut buf)?;
     Ok(buf)
   }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    Ok(
+      self
+        .fs
+        .read_dir(&path.to_string_lossy())
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?
+        .map(|name| path.join(name))
+        .collect(),
+    )
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self
+      .fs
+      .metadata(&path.to_string_lossy())
+      .is_ok_and(|metadata| metadata.file_type == vfs::VfsFileType::Directory)
+  }
 }
 
 impl OxcResolverFileSystem for MemoryFileSystem {
@@ -114,7 +132,7 @@ impl OxcResolverFileSystem for MemoryFileSystem {
     })
   }
 
-  fn canonicalize(&self, _path: &Path) -> io::Result<PathBuf> {
+  fn read_link(&self, _path: &Path) -> io::Result<PathBuf> {
     Err(io::Error::new(io::ErrorKind::NotFound, "not a symlink"))
   }
 }
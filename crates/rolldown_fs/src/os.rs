@@ -31,6 +31,14 @@ impl FileSystem for OsFileSystem {
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
     std::fs::read(path)
   }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect()
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    path.is_dir()
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
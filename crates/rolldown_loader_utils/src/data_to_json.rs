@@ -0,0 +1,84 @@
+use std::ops::Range;
+
+use serde_json::{Map, Number, Value};
+
+/// An error in a data file, e.g. a yaml or toml file.
+#[derive(Debug)]
+pub struct DataParseError {
+  pub message: String,
+  /// Byte range of the invalid content, if the parser reports one.
+  pub span: Option<Range<usize>>,
+}
+
+/// Convert a yaml file to json, so it could be loaded like a json module.
+pub fn yaml_to_json(source: &str) -> Result<String, DataParseError> {
+  let to_data_parse_error = |err: serde_norway::Error| DataParseError {
+    message: err.to_string(),
+    span: err.location().map(|location| location.index()..location.index()),
+  };
+  let mut value =
+    serde_norway::from_str::<serde_norway::Value>(source).map_err(to_data_parse_error)?;
+  // Resolve merge keys like `<<: *defaults`, otherwise they end up as a literal `<<` property.
+  value.apply_merge().map_err(to_data_parse_error)?;
+  Ok(yaml_value_to_json(value).to_string())
+}
+
+/// Convert a toml file to json, so it could be loaded like a json module.
+pub fn toml_to_json(source: &str) -> Result<String, DataParseError> {
+  let table = source
+    .parse::<toml::Table>()
+    .map_err(|err| DataParseError { message: err.message().to_string(), span: err.span() })?;
+  Ok(toml_value_to_json(toml::Value::Table(table)).to_string())
+}
+
+fn yaml_value_to_json(value: serde_norway::Value) -> Value {
+  match value {
+    serde_norway::Value::Null => Value::Null,
+    serde_norway::Value::Bool(value) => Value::Bool(value),
+    serde_norway::Value::Number(number) => {
+      if let Some(value) = number.as_i64() {
+        Value::Number(value.into())
+      } else if let Some(value) = number.as_u64() {
+        Value::Number(value.into())
+      } else {
+        // `.nan` and `.inf` can't be represented in json.
+        number.as_f64().and_then(Number::from_f64).map_or(Value::Null, Value::Number)
+      }
+    }
+    serde_norway::Value::String(value) => Value::String(value),
+    serde_norway::Value::Sequence(values) => {
+      Value::Array(values.into_iter().map(yaml_value_to_json).collect())
+    }
+    serde_norway::Value::Mapping(mapping) => Value::Object(
+      mapping
+        .into_iter()
+        .map(|(key, value)| (yaml_key_to_string(key), yaml_value_to_json(value)))
+        .collect::<Map<_, _>>(),
+    ),
+    // Tags like `!Ref` have no meaning in javascript, so only the tagged value is kept.
+    serde_norway::Value::Tagged(tagged) => yaml_value_to_json(tagged.value),
+  }
+}
+
+fn yaml_key_to_string(key: serde_norway::Value) -> String {
+  match yaml_value_to_json(key) {
+    Value::String(key) => key,
+    key => key.to_string(),
+  }
+}
+
+fn toml_value_to_json(value: toml::Value) -> Value {
+  match value {
+    toml::Value::String(value) => Value::String(value),
+    toml::Value::Integer(value) => Value::Number(value.into()),
+    toml::Value::Float(value) => Number::from_f64(value).map_or(Value::Null, Value::Number),
+    toml::Value::Boolean(value) => Value::Bool(value),
+    toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+    toml::Value::Array(values) => {
+      Value::Array(values.into_iter().map(toml_value_to_json).collect())
+    }
+    toml::Value::Table(table) => Value::Object(
+      table.into_iter().map(|(key, value)| (key, toml_value_to_json(value))).collect::<Map<_, _>>(),
+    ),
+  }
+}
@@ -1,7 +1,9 @@
 mod binary_to_esm;
+mod data_to_json;
 mod json_to_esm;
 mod text_to_esm;
 
 pub use binary_to_esm::binary_to_esm;
+pub use data_to_json::{toml_to_json, yaml_to_json, DataParseError};
 pub use json_to_esm::json_to_esm;
 pub use text_to_esm::text_to_string_literal;
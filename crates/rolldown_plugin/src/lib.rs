@@ -20,8 +20,9 @@ pub mod __inner {
 pub use crate::{
   plugin::{
     HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookOptionsReturn, HookOutputOptionsReturn, HookRenderChunkReturn,
+    HookRenderDynamicImportReturn, HookResolveIdReturn, HookTransformAstReturn,
+    HookTransformReturn, Plugin,
   },
   plugin_context::PluginContext,
   plugin_driver::{PluginDriver, SharedPluginDriver},
@@ -36,15 +37,22 @@ pub use crate::{
   types::hook_load_output::HookLoadOutput,
   types::hook_render_chunk_args::HookRenderChunkArgs,
   types::hook_render_chunk_output::HookRenderChunkOutput,
+  types::hook_render_dynamic_import_args::HookRenderDynamicImportArgs,
+  types::hook_render_dynamic_import_output::HookRenderDynamicImportOutput,
   types::hook_render_error::HookRenderErrorArgs,
   types::hook_render_start_args::HookRenderStartArgs,
+  types::hook_resolve_file_url_args::HookResolveFileUrlArgs,
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
+  types::hook_resolve_import_meta_args::HookResolveImportMetaArgs,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::HookTransformOutput,
   types::hook_write_bundle_args::HookWriteBundleArgs,
+  types::plugin_context_log::{LogPosition, PluginContextLog},
+  types::plugin_context_parse_options::PluginContextParseOptions,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
+  types::plugin_timings::{PluginHookTiming, PluginTimings},
 };
 
 pub use typedmap;
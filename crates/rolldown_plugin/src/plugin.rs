@@ -9,11 +9,14 @@ use crate::{
     hook_transform_output::HookTransformOutput,
   },
   HookAddonArgs, HookBuildEndArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput,
-  HookRenderChunkArgs, HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput,
-  HookTransformArgs, HookWriteBundleArgs, SharedTransformPluginContext,
+  HookRenderChunkArgs, HookRenderChunkOutput, HookRenderDynamicImportArgs,
+  HookRenderDynamicImportOutput, HookResolveFileUrlArgs, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveImportMetaArgs, HookTransformArgs, HookWriteBundleArgs, SharedTransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{
+  BundlerOptions, ModuleInfo, OutputOptions, RollupRenderedChunk, WatcherChangeKind,
+};
 use rolldown_ecmascript::EcmaAst;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
@@ -24,11 +27,40 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookRenderDynamicImportReturn = Result<Option<HookRenderDynamicImportOutput>>;
+pub type HookOptionsReturn = Result<Option<BundlerOptions>>;
+pub type HookOutputOptionsReturn = Result<Option<OutputOptions>>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
-  // The `option` hook consider call at node side.
+  /// Runs sequentially before the options are normalized. Returning `Some` replaces the options passed to
+  /// the following plugins and to the bundler.
+  ///
+  /// There is no `PluginContext` yet, since it depends on the normalized options.
+  fn options(
+    &self,
+    _options: &BundlerOptions,
+  ) -> impl std::future::Future<Output = HookOptionsReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn options_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Like `options`, but only receives the output options, e.g. `format`, `dir` and `entry_filenames`. Runs
+  /// after the `options` hook of all plugins.
+  fn output_options(
+    &self,
+    _options: &OutputOptions,
+  ) -> impl std::future::Future<Output = HookOutputOptionsReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn output_options_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
 
   // --- Build hooks ---
 
@@ -181,6 +213,45 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Customizes how `import()` expressions are rendered. Not called for inlined dynamic imports.
+  fn render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderDynamicImportArgs<'_>,
+  ) -> impl std::future::Future<Output = HookRenderDynamicImportReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn render_dynamic_import_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Returns the code replacing `import.meta.ROLLUP_FILE_URL_<referenceId>`.
+  fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs<'_>,
+  ) -> impl std::future::Future<Output = HookInjectionOutputReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Returns the code replacing `import.meta` or `import.meta.<property>`.
+  fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs<'_>,
+  ) -> impl std::future::Future<Output = HookInjectionOutputReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn render_chunk(
     &self,
     _ctx: &PluginContext,
@@ -10,9 +10,14 @@ use std::{
 use anyhow::Context;
 use arcstr::ArcStr;
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleDefFormat, ModuleInfo, ModuleLoaderMsg, ResolvedId,
-  SharedFileEmitter, SharedNormalizedBundlerOptions,
+  side_effects::HookSideEffects, EmittedChunk, ModuleDefFormat, ModuleInfo, ModuleLoaderMsg,
+  ResolvedId, SharedFileEmitter, SharedNormalizedBundlerOptions,
 };
+use rolldown_ecmascript::{pure_esm_js_oxc_source_type, EcmaAst, EcmaCompiler};
+use rolldown_error::{
+  line_column_to_byte_offset, BuildDiagnostic, BuildResult, PluginLog, Severity,
+};
+use rolldown_fs::FileSystem;
 use rolldown_resolver::{ResolveError, Resolver};
 use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
 use tokio::sync::Mutex;
@@ -20,7 +25,10 @@ use tokio::sync::Mutex;
 use crate::{
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped,
-    plugin_context_resolve_options::PluginContextResolveOptions, plugin_idx::PluginIdx,
+    plugin_context_log::{LogPosition, PluginContextLog},
+    plugin_context_parse_options::PluginContextParseOptions,
+    plugin_context_resolve_options::PluginContextResolveOptions,
+    plugin_idx::PluginIdx,
   },
   utils::resolve_id_with_plugins::resolve_id_check_external,
   PluginDriver,
@@ -40,12 +48,15 @@ impl PluginContext {
       plugin_idx: self.plugin_idx,
       plugin_driver: Weak::clone(&self.plugin_driver),
       resolver: Arc::clone(&self.resolver),
+      fs: Arc::clone(&self.fs),
       file_emitter: Arc::clone(&self.file_emitter),
       options: Arc::clone(&self.options),
       watch_files: Arc::clone(&self.watch_files),
+      watch_globs: Arc::clone(&self.watch_globs),
       modules: Arc::clone(&self.modules),
       context_load_modules: Arc::clone(&self.context_load_modules),
       tx: Arc::clone(&self.tx),
+      logs: Arc::clone(&self.logs),
     }))
   }
 }
@@ -84,13 +95,16 @@ pub struct PluginContextImpl {
   pub(crate) skipped_resolve_calls: Vec<Arc<HookResolveIdSkipped>>,
   pub(crate) plugin_idx: PluginIdx,
   pub(crate) resolver: Arc<Resolver>,
+  pub(crate) fs: Arc<dyn FileSystem>,
   pub(crate) plugin_driver: Weak<PluginDriver>,
   pub(crate) file_emitter: SharedFileEmitter,
   pub(crate) options: SharedNormalizedBundlerOptions,
   pub(crate) watch_files: Arc<FxDashSet<ArcStr>>,
+  pub(crate) watch_globs: Arc<FxDashSet<ArcStr>>,
   pub(crate) modules: Arc<FxDashMap<ArcStr, Arc<ModuleInfo>>>,
   pub(crate) context_load_modules: Arc<FxDashMap<ArcStr, LoadCallback>>,
   pub(crate) tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ModuleLoaderMsg>>>>,
+  pub(crate) logs: Arc<std::sync::Mutex<Vec<BuildDiagnostic>>>,
 }
 
 impl From<PluginContextImpl> for PluginContext {
@@ -173,6 +187,12 @@ impl PluginContextImpl {
     self.file_emitter.emit_file(file)
   }
 
+  /// Emits a new entry chunk. It only works before module loading finishes, i.e. in the
+  /// `buildStart/resolveId/load/transform/moduleParsed` hooks.
+  pub fn emit_chunk(&self, chunk: EmittedChunk) -> anyhow::Result<ArcStr> {
+    self.file_emitter.emit_chunk(chunk)
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
     self.file_emitter.try_get_file_name(reference_id)
   }
@@ -193,7 +213,95 @@ impl PluginContextImpl {
     self.resolver.cwd()
   }
 
+  /// The file system the bundler reads modules from.
+  pub fn fs(&self) -> &dyn FileSystem {
+    &*self.fs
+  }
+
   pub fn add_watch_file(&self, file: &str) {
     self.watch_files.insert(file.into());
   }
+
+  /// Rebuild if a file matching the absolute glob `pattern` is added or removed.
+  pub fn add_watch_glob(&self, pattern: &str) {
+    self.watch_globs.insert(pattern.into());
+  }
+
+  /// Parses `code` as an ES module, with the source type conventions the bundler uses for modules.
+  pub fn parse(
+    &self,
+    code: impl Into<ArcStr>,
+    options: &PluginContextParseOptions,
+  ) -> BuildResult<EcmaAst> {
+    let jsx = options.jsx && !self.options.jsx.is_jsx_disabled();
+    let source_type =
+      pure_esm_js_oxc_source_type().with_typescript(options.typescript).with_jsx(jsx);
+    EcmaCompiler::parse(options.id.as_deref().unwrap_or("<input>"), code, source_type)
+  }
+
+  /// Reports a warning without failing the build. The warning has a code frame if `pos` is given
+  /// and the source of `id` is known, i.e. the module is already parsed.
+  pub fn warn(&self, log: impl Into<PluginContextLog>) {
+    self.add_log(Severity::Warning, log.into(), None);
+  }
+
+  pub fn info(&self, log: impl Into<PluginContextLog>) {
+    self.add_log(Severity::Info, log.into(), None);
+  }
+
+  pub fn debug(&self, log: impl Into<PluginContextLog>) {
+    self.add_log(Severity::Debug, log.into(), None);
+  }
+
+  /// Creates an error, which fails the build with a proper diagnostic once returned from a hook.
+  pub fn error(&self, log: impl Into<PluginContextLog>) -> anyhow::Error {
+    self.create_log(Severity::Error, log.into(), None).into()
+  }
+
+  pub(crate) fn add_log(&self, severity: Severity, log: PluginContextLog, source: Option<ArcStr>) {
+    if !self.options.log_level.allows(severity) {
+      return;
+    }
+    let log = self.create_log(severity, log, source);
+    self.logs.lock().expect("logs lock poisoned").push(log);
+  }
+
+  /// `source` is the source of `log.id` if it's known by the caller.
+  pub(crate) fn create_log(
+    &self,
+    severity: Severity,
+    log: PluginContextLog,
+    source: Option<ArcStr>,
+  ) -> BuildDiagnostic {
+    let plugin = self
+      .plugin_driver
+      .upgrade()
+      .map(|plugin_driver| plugin_driver.plugin_name(self.plugin_idx))
+      .unwrap_or_default();
+    let source = source.or_else(|| {
+      let id = log.id.as_deref()?;
+      self.modules.get(id).and_then(|module_info| module_info.code.clone())
+    });
+    let pos = source.as_deref().zip(log.pos).and_then(|(source, pos)| {
+      let offset = match pos {
+        LogPosition::Offset(offset) => offset,
+        LogPosition::LineColumn { line, column } => {
+          if line == 0 || line > source.split('\n').count() {
+            return None;
+          }
+          line_column_to_byte_offset(source, line - 1, column)
+        }
+      };
+      u32::try_from(offset.min(source.len())).ok()
+    });
+    BuildDiagnostic::plugin_log(PluginLog {
+      plugin,
+      severity,
+      plugin_code: log.code,
+      message: log.message,
+      id: log.id,
+      source,
+      pos,
+    })
+  }
 }
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use crate::{
+  __inner::SharedPluginable,
+  plugin_driver::HookOrderIndicates,
   pluginable::HookTransformAstReturn,
+  type_aliases::IndexPluginable,
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
     plugin_idx::PluginIdx,
@@ -11,13 +14,31 @@ use crate::{
 };
 use anyhow::Result;
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleInfo, ModuleType, SharedNormalizedBundlerOptions,
+  side_effects::HookSideEffects, BundlerOptions, ModuleInfo, ModuleType,
+  SharedNormalizedBundlerOptions,
 };
 use rolldown_sourcemap::SourceMap;
 use rolldown_utils::unique_arc::UniqueArc;
 use string_wizard::{MagicString, SourceMapOptions};
+use tracing::Instrument;
 
 impl PluginDriver {
+  /// Calls the `options` hook of `plugins` in order. It's called before the `PluginDriver` is created, since
+  /// the driver depends on the normalized options. Returns whether any plugin replaced the options.
+  pub async fn options(plugins: &[SharedPluginable], options: &mut BundlerOptions) -> Result<bool> {
+    let index_plugins = IndexPluginable::from_vec(plugins.to_vec());
+    let mut replaced = false;
+    for plugin_idx in
+      HookOrderIndicates::sort_plugins_by_hook_meta(&index_plugins, |p| p.call_options_meta())
+    {
+      if let Some(patched) = index_plugins[plugin_idx].call_options(options).await? {
+        *options = patched;
+        replaced = true;
+      }
+    }
+    Ok(replaced)
+  }
+
   #[tracing::instrument(level = "trace", skip_all)]
   pub async fn build_start(&self, opts: &SharedNormalizedBundlerOptions) -> HookNoopReturn {
     // let ret = {
@@ -50,9 +71,16 @@ impl PluginDriver {
     //   r?;
     // }
 
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_build_start_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_build_start_meta)
     {
-      plugin.call_build_start(ctx, &crate::HookBuildStartArgs { options: opts }).await?;
+      self
+        .timed(
+          plugin_idx,
+          "build_start",
+          plugin.call_build_start(ctx, &crate::HookBuildStartArgs { options: opts }),
+        )
+        .await?;
     }
 
     Ok(())
@@ -90,18 +118,28 @@ impl PluginDriver {
       if skipped_plugins.iter().any(|p| *p == plugin_idx) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_resolve_id(
-          &skipped_resolve_calls.map_or_else(
-            || ctx.clone(),
-            |skipped_resolve_calls| {
-              PluginContext::new_shared_with_skipped_resolve_calls(
-                ctx,
-                skipped_resolve_calls.clone(),
-              )
-            },
-          ),
-          args,
+      if let Some(r) = self
+        .timed(
+          plugin_idx,
+          "resolve_id",
+          plugin
+            .call_resolve_id(
+              &skipped_resolve_calls.map_or_else(
+                || ctx.clone(),
+                |skipped_resolve_calls| {
+                  PluginContext::new_shared_with_skipped_resolve_calls(
+                    ctx,
+                    skipped_resolve_calls.clone(),
+                  )
+                },
+              ),
+              args,
+            )
+            .instrument(tracing::trace_span!(
+              "call_resolve_id",
+              plugin = %plugin.call_name(),
+              specifier = args.specifier,
+            )),
         )
         .await?
       {
@@ -126,18 +164,22 @@ impl PluginDriver {
       if skipped_plugins.iter().any(|p| *p == plugin_idx) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_resolve_dynamic_import(
-          &skipped_resolve_calls.map_or_else(
-            || ctx.clone(),
-            |skipped_resolve_calls| {
-              PluginContext::new_shared_with_skipped_resolve_calls(
-                ctx,
-                skipped_resolve_calls.clone(),
-              )
-            },
+      if let Some(r) = self
+        .timed(
+          plugin_idx,
+          "resolve_dynamic_import",
+          plugin.call_resolve_dynamic_import(
+            &skipped_resolve_calls.map_or_else(
+              || ctx.clone(),
+              |skipped_resolve_calls| {
+                PluginContext::new_shared_with_skipped_resolve_calls(
+                  ctx,
+                  skipped_resolve_calls.clone(),
+                )
+              },
+            ),
+            args,
           ),
-          args,
         )
         .await?
       {
@@ -148,10 +190,21 @@ impl PluginDriver {
   }
 
   pub async fn load(&self, args: &HookLoadArgs<'_>) -> HookLoadReturn {
-    for (_plugin_idx, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_load_meta)
     {
-      if let Some(r) = plugin.call_load(ctx, args).await? {
+      if let Some(r) = self
+        .timed(
+          plugin_idx,
+          "load",
+          plugin.call_load(ctx, args).instrument(tracing::trace_span!(
+            "call_load",
+            plugin = %plugin.call_name(),
+            module_id = args.id,
+          )),
+        )
+        .await?
+      {
         return Ok(Some(r));
       }
     }
@@ -169,18 +222,28 @@ impl PluginDriver {
     let mut code = original_code;
     let mut original_sourcemap_chain = std::mem::take(sourcemap_chain);
     let mut plugin_sourcemap_chain = UniqueArc::new(original_sourcemap_chain);
-    for (_plugin_idx, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_meta)
     {
-      if let Some(r) = plugin
-        .call_transform(
-          Arc::new(TransformPluginContext::new(
-            ctx.clone(),
-            plugin_sourcemap_chain.weak_ref(),
-            code.as_str().into(),
-            id.into(),
-          )),
-          &HookTransformArgs { id, code: &code, module_type: &*module_type },
+      if let Some(r) = self
+        .timed(
+          plugin_idx,
+          "transform",
+          plugin
+            .call_transform(
+              Arc::new(TransformPluginContext::new(
+                ctx.clone(),
+                plugin_sourcemap_chain.weak_ref(),
+                code.as_str().into(),
+                id.into(),
+              )),
+              &HookTransformArgs { id, code: &code, module_type: &*module_type },
+            )
+            .instrument(tracing::trace_span!(
+              "call_transform",
+              plugin = %plugin.call_name(),
+              module_id = id,
+            )),
         )
         .await?
       {
@@ -244,29 +307,39 @@ impl PluginDriver {
   }
 
   pub fn transform_ast(&self, mut args: HookTransformAstArgs) -> HookTransformAstReturn {
-    for (_, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_ast_meta)
     {
-      args.ast = plugin.call_transform_ast(
-        ctx,
-        HookTransformAstArgs { cwd: args.cwd, ast: args.ast, id: args.id },
-      )?;
+      args.ast = self.timed_sync(plugin_idx, "transform_ast", || {
+        plugin.call_transform_ast(
+          ctx,
+          HookTransformAstArgs { cwd: args.cwd, ast: args.ast, id: args.id },
+        )
+      })?;
     }
     Ok(args.ast)
   }
 
   pub async fn module_parsed(&self, module_info: Arc<ModuleInfo>) -> HookNoopReturn {
-    for (_, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_module_parsed_meta)
     {
-      plugin.call_module_parsed(ctx, Arc::clone(&module_info)).await?;
+      self
+        .timed(
+          plugin_idx,
+          "module_parsed",
+          plugin.call_module_parsed(ctx, Arc::clone(&module_info)),
+        )
+        .await?;
     }
     Ok(())
   }
 
   pub async fn build_end(&self, args: Option<&HookBuildEndArgs<'_>>) -> HookNoopReturn {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_build_end_meta) {
-      plugin.call_build_end(ctx, args).await?;
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_build_end_meta)
+    {
+      self.timed(plugin_idx, "build_end", plugin.call_build_end(ctx, args)).await?;
     }
     Ok(())
   }
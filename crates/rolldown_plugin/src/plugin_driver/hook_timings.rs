@@ -0,0 +1,71 @@
+use std::{
+  future::Future,
+  time::{Duration, Instant},
+};
+
+use crate::{
+  types::{
+    plugin_idx::PluginIdx,
+    plugin_timings::{PluginHookTiming, PluginTimings},
+  },
+  PluginDriver,
+};
+
+impl PluginDriver {
+  /// Run a hook of the plugin and record its duration if `plugin_timings` is enabled.
+  pub(crate) async fn timed<T>(
+    &self,
+    plugin_idx: PluginIdx,
+    hook: &'static str,
+    call: impl Future<Output = T>,
+  ) -> T {
+    if self.hook_timings.is_none() {
+      return call.await;
+    }
+    let start = Instant::now();
+    let ret = call.await;
+    self.record_hook_timing(plugin_idx, hook, start.elapsed());
+    ret
+  }
+
+  /// Same as [PluginDriver::timed], but for synchronous hooks.
+  pub(crate) fn timed_sync<T>(
+    &self,
+    plugin_idx: PluginIdx,
+    hook: &'static str,
+    call: impl FnOnce() -> T,
+  ) -> T {
+    if self.hook_timings.is_none() {
+      return call();
+    }
+    let start = Instant::now();
+    let ret = call();
+    self.record_hook_timing(plugin_idx, hook, start.elapsed());
+    ret
+  }
+
+  fn record_hook_timing(&self, plugin_idx: PluginIdx, hook: &'static str, duration: Duration) {
+    if let Some(hook_timings) = &self.hook_timings {
+      let mut timing = hook_timings.entry((plugin_idx, hook)).or_default();
+      timing.0 += 1;
+      timing.1 += duration;
+    }
+  }
+
+  /// Take the timings recorded since the last call, so each build reports its own timings.
+  pub fn take_plugin_timings(&self) -> Option<PluginTimings> {
+    let hook_timings = self.hook_timings.as_ref()?;
+    let keys = hook_timings.iter().map(|entry| *entry.key()).collect::<Vec<_>>();
+    let hooks = keys
+      .into_iter()
+      .filter_map(|key| hook_timings.remove(&key))
+      .map(|((plugin_idx, hook), (calls, duration))| PluginHookTiming {
+        plugin: self.plugins[plugin_idx].call_name().into_owned(),
+        hook,
+        calls,
+        duration,
+      })
+      .collect();
+    Some(PluginTimings::new(hooks))
+  }
+}
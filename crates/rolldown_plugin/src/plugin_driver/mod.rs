@@ -9,6 +9,8 @@ use dashmap::{DashMap, DashSet};
 use rolldown_common::{
   ModuleId, ModuleInfo, ModuleLoaderMsg, SharedFileEmitter, SharedNormalizedBundlerOptions,
 };
+use rolldown_error::BuildDiagnostic;
+use rolldown_fs::FileSystem;
 use rolldown_resolver::Resolver;
 use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
 use tokio::sync::Mutex;
@@ -16,12 +18,13 @@ use tokio::sync::Mutex;
 use crate::{
   __inner::SharedPluginable,
   plugin_context::{LoadCallback, PluginContextImpl},
-  type_aliases::{IndexPluginContext, IndexPluginable},
+  type_aliases::{HookTimings, IndexPluginContext, IndexPluginable},
   types::plugin_idx::PluginIdx,
   PluginContext, PluginHookMeta, PluginOrder,
 };
 
 mod build_hooks;
+mod hook_timings;
 mod output_hooks;
 mod watch_hooks;
 
@@ -33,22 +36,31 @@ pub struct PluginDriver {
   order_indicates: HookOrderIndicates,
   file_emitter: SharedFileEmitter,
   pub watch_files: Arc<FxDashSet<ArcStr>>,
+  /// Absolute glob patterns, e.g. from `import.meta.glob`. Adding or removing a matching file triggers a rebuild.
+  pub watch_globs: Arc<FxDashSet<ArcStr>>,
   pub modules: Arc<FxDashMap<ArcStr, Arc<ModuleInfo>>>,
   pub context_load_modules: Arc<FxDashMap<ArcStr, LoadCallback>>,
   pub(crate) tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ModuleLoaderMsg>>>>,
+  /// Calls and time of each hook of each plugin. Only recorded if `plugin_timings` is enabled.
+  hook_timings: Option<HookTimings>,
+  /// Logs reported by plugins through `PluginContext`, taken by each build.
+  logs: Arc<std::sync::Mutex<Vec<BuildDiagnostic>>>,
 }
 
 impl PluginDriver {
   pub fn new_shared(
     plugins: Vec<SharedPluginable>,
     resolver: &Arc<Resolver>,
+    fs: &Arc<dyn FileSystem>,
     file_emitter: &SharedFileEmitter,
     options: &SharedNormalizedBundlerOptions,
   ) -> SharedPluginDriver {
     let watch_files = Arc::new(DashSet::default());
+    let watch_globs = Arc::new(DashSet::default());
     let modules = Arc::new(DashMap::default());
     let context_load_modules = Arc::new(DashMap::default());
     let tx = Arc::new(Mutex::new(None));
+    let logs = Arc::new(std::sync::Mutex::new(vec![]));
 
     Arc::new_cyclic(|plugin_driver| {
       let mut index_plugins = IndexPluginable::with_capacity(plugins.len());
@@ -62,12 +74,15 @@ impl PluginDriver {
             plugin_idx,
             plugin_driver: Weak::clone(plugin_driver),
             resolver: Arc::clone(resolver),
+            fs: Arc::clone(fs),
             file_emitter: Arc::clone(file_emitter),
             modules: Arc::clone(&modules),
             options: Arc::clone(options),
             watch_files: Arc::clone(&watch_files),
+            watch_globs: Arc::clone(&watch_globs),
             context_load_modules: Arc::clone(&context_load_modules),
             tx: Arc::clone(&tx),
+            logs: Arc::clone(&logs),
           }
           .into(),
         );
@@ -79,19 +94,36 @@ impl PluginDriver {
         contexts: index_contexts,
         file_emitter: Arc::clone(file_emitter),
         watch_files,
+        watch_globs,
         modules,
         context_load_modules,
         tx,
+        hook_timings: options.plugin_timings.then(FxDashMap::default),
+        logs,
       }
     })
   }
 
   pub fn clear(&self) {
     self.watch_files.clear();
+    self.watch_globs.clear();
     self.modules.clear();
     self.file_emitter.clear();
   }
 
+  pub fn file_emitter(&self) -> &SharedFileEmitter {
+    &self.file_emitter
+  }
+
+  /// Take the logs reported by plugins since the last call.
+  pub fn take_logs(&self) -> Vec<BuildDiagnostic> {
+    std::mem::take(&mut *self.logs.lock().expect("logs lock poisoned"))
+  }
+
+  pub(crate) fn plugin_name(&self, plugin_idx: PluginIdx) -> String {
+    self.plugins[plugin_idx].call_name().into_owned()
+  }
+
   pub fn set_module_info(&self, module_id: &ModuleId, module_info: Arc<ModuleInfo>) {
     self.modules.insert(module_id.resource_id().into(), module_info);
   }
@@ -145,6 +177,9 @@ pub struct HookOrderIndicates {
   pub order_by_footer_meta: Vec<PluginIdx>,
   pub order_by_intro_meta: Vec<PluginIdx>,
   pub order_by_outro_meta: Vec<PluginIdx>,
+  pub order_by_render_dynamic_import_meta: Vec<PluginIdx>,
+  pub order_by_resolve_file_url_meta: Vec<PluginIdx>,
+  pub order_by_resolve_import_meta_meta: Vec<PluginIdx>,
   pub order_by_render_chunk_meta: Vec<PluginIdx>,
   pub order_by_augment_chunk_hash_meta: Vec<PluginIdx>,
   pub order_by_render_error_meta: Vec<PluginIdx>,
@@ -189,6 +224,15 @@ impl HookOrderIndicates {
       }),
       order_by_intro_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| p.call_intro_meta()),
       order_by_outro_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| p.call_outro_meta()),
+      order_by_render_dynamic_import_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_render_dynamic_import_meta()
+      }),
+      order_by_resolve_file_url_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_file_url_meta()
+      }),
+      order_by_resolve_import_meta_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_import_meta_meta()
+      }),
       order_by_render_chunk_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_chunk_meta()
       }),
@@ -219,7 +263,7 @@ impl HookOrderIndicates {
     }
   }
 
-  fn sort_plugins_by_hook_meta(
+  pub(crate) fn sort_plugins_by_hook_meta(
     index_plugins: &IndexPluginable,
     get_hook_meta: impl Fn(&SharedPluginable) -> Option<PluginHookMeta>,
   ) -> Vec<PluginIdx> {
@@ -1,26 +1,110 @@
 use crate::types::hook_render_error::HookRenderErrorArgs;
-use crate::{HookAddonArgs, PluginDriver};
+use crate::{
+  HookAddonArgs, HookRenderDynamicImportArgs, HookRenderDynamicImportReturn,
+  HookResolveFileUrlArgs, HookResolveImportMetaArgs, PluginDriver,
+};
 use crate::{HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs};
+use crate::{
+  __inner::SharedPluginable, plugin_driver::HookOrderIndicates, type_aliases::IndexPluginable,
+};
 use anyhow::{Ok, Result};
-use rolldown_common::{Output, RollupRenderedChunk, SharedNormalizedBundlerOptions};
+use rolldown_common::{Output, OutputOptions, RollupRenderedChunk, SharedNormalizedBundlerOptions};
 use rolldown_sourcemap::SourceMap;
 
 impl PluginDriver {
+  /// Calls the `output_options` hook of `plugins` in order. Like [PluginDriver::options], it's called before
+  /// the `PluginDriver` is created.
+  pub async fn output_options(
+    plugins: &[SharedPluginable],
+    options: &mut OutputOptions,
+  ) -> Result<bool> {
+    let index_plugins = IndexPluginable::from_vec(plugins.to_vec());
+    let mut replaced = false;
+    for plugin_idx in HookOrderIndicates::sort_plugins_by_hook_meta(&index_plugins, |p| {
+      p.call_output_options_meta()
+    }) {
+      if let Some(patched) = index_plugins[plugin_idx].call_output_options(options).await? {
+        *options = patched;
+        replaced = true;
+      }
+    }
+    Ok(replaced)
+  }
+
   pub async fn render_start(&self, opts: &SharedNormalizedBundlerOptions) -> HookNoopReturn {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_render_start_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_start_meta)
     {
-      plugin.call_render_start(ctx, &crate::HookRenderStartArgs { options: opts }).await?;
+      self
+        .timed(
+          plugin_idx,
+          "render_start",
+          plugin.call_render_start(ctx, &crate::HookRenderStartArgs { options: opts }),
+        )
+        .await?;
     }
     Ok(())
   }
 
+  pub async fn render_dynamic_import(
+    &self,
+    args: &HookRenderDynamicImportArgs<'_>,
+  ) -> HookRenderDynamicImportReturn {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_dynamic_import_meta)
+    {
+      if let Some(r) = self
+        .timed(plugin_idx, "render_dynamic_import", plugin.call_render_dynamic_import(ctx, args))
+        .await?
+      {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
+  pub async fn resolve_file_url(
+    &self,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> Result<Option<String>> {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_file_url_meta)
+    {
+      if let Some(r) =
+        self.timed(plugin_idx, "resolve_file_url", plugin.call_resolve_file_url(ctx, args)).await?
+      {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
+  pub async fn resolve_import_meta(
+    &self,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> Result<Option<String>> {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_import_meta_meta)
+    {
+      if let Some(r) = self
+        .timed(plugin_idx, "resolve_import_meta", plugin.call_resolve_import_meta(ctx, args))
+        .await?
+      {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn banner(
     &self,
     args: HookAddonArgs<'_>,
     mut banner: String,
   ) -> Result<Option<String>> {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_banner_meta) {
-      if let Some(r) = plugin.call_banner(ctx, &args).await? {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_banner_meta)
+    {
+      if let Some(r) = self.timed(plugin_idx, "banner", plugin.call_banner(ctx, &args)).await? {
         banner.push('\n');
         banner.push_str(r.as_str());
       }
@@ -36,8 +120,10 @@ impl PluginDriver {
     args: HookAddonArgs<'_>,
     mut footer: String,
   ) -> Result<Option<String>> {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_footer_meta) {
-      if let Some(r) = plugin.call_footer(ctx, &args).await? {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_footer_meta)
+    {
+      if let Some(r) = self.timed(plugin_idx, "footer", plugin.call_footer(ctx, &args)).await? {
         footer.push('\n');
         footer.push_str(r.as_str());
       }
@@ -49,8 +135,10 @@ impl PluginDriver {
   }
 
   pub async fn intro(&self, args: HookAddonArgs<'_>, mut intro: String) -> Result<Option<String>> {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_intro_meta) {
-      if let Some(r) = plugin.call_intro(ctx, &args).await? {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_intro_meta)
+    {
+      if let Some(r) = self.timed(plugin_idx, "intro", plugin.call_intro(ctx, &args)).await? {
         intro.push('\n');
         intro.push_str(r.as_str());
       }
@@ -62,8 +150,10 @@ impl PluginDriver {
   }
 
   pub async fn outro(&self, args: HookAddonArgs<'_>, mut outro: String) -> Result<Option<String>> {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_outro_meta) {
-      if let Some(r) = plugin.call_outro(ctx, &args).await? {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_outro_meta)
+    {
+      if let Some(r) = self.timed(plugin_idx, "outro", plugin.call_outro(ctx, &args)).await? {
         outro.push('\n');
         outro.push_str(r.as_str());
       }
@@ -79,9 +169,12 @@ impl PluginDriver {
     mut args: HookRenderChunkArgs<'_>,
   ) -> Result<(String, Vec<SourceMap>)> {
     let mut sourcemap_chain = vec![];
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_render_chunk_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_chunk_meta)
     {
-      if let Some(r) = plugin.call_render_chunk(ctx, &args).await? {
+      if let Some(r) =
+        self.timed(plugin_idx, "render_chunk", plugin.call_render_chunk(ctx, &args)).await?
+      {
         args.code = r.code;
         if let Some(map) = r.map {
           sourcemap_chain.push(map);
@@ -96,10 +189,13 @@ impl PluginDriver {
     chunk: &RollupRenderedChunk,
   ) -> HookAugmentChunkHashReturn {
     let mut hash = None;
-    for (_, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_augment_chunk_hash_meta)
     {
-      if let Some(plugin_hash) = plugin.call_augment_chunk_hash(ctx, chunk).await? {
+      if let Some(plugin_hash) = self
+        .timed(plugin_idx, "augment_chunk_hash", plugin.call_augment_chunk_hash(ctx, chunk))
+        .await?
+      {
         hash.get_or_insert_with(String::default).push_str(&plugin_hash);
       }
     }
@@ -107,9 +203,10 @@ impl PluginDriver {
   }
 
   pub async fn render_error(&self, args: &HookRenderErrorArgs<'_>) -> HookNoopReturn {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_render_error_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_error_meta)
     {
-      plugin.call_render_error(ctx, args).await?;
+      self.timed(plugin_idx, "render_error", plugin.call_render_error(ctx, args)).await?;
     }
     Ok(())
   }
@@ -120,11 +217,13 @@ impl PluginDriver {
     is_write: bool,
     opts: &SharedNormalizedBundlerOptions,
   ) -> HookNoopReturn {
-    for (_, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_generate_bundle_meta)
     {
       let mut args = crate::HookGenerateBundleArgs { is_write, bundle, options: opts };
-      plugin.call_generate_bundle(ctx, &mut args).await?;
+      self
+        .timed(plugin_idx, "generate_bundle", plugin.call_generate_bundle(ctx, &mut args))
+        .await?;
       ctx.file_emitter.add_additional_files(bundle);
     }
     Ok(())
@@ -135,20 +234,22 @@ impl PluginDriver {
     bundle: &mut Vec<Output>,
     opts: &SharedNormalizedBundlerOptions,
   ) -> HookNoopReturn {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_write_bundle_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_write_bundle_meta)
     {
       let mut args = crate::HookWriteBundleArgs { bundle, options: opts };
 
-      plugin.call_write_bundle(ctx, &mut args).await?;
+      self.timed(plugin_idx, "write_bundle", plugin.call_write_bundle(ctx, &mut args)).await?;
       ctx.file_emitter.add_additional_files(bundle);
     }
     Ok(())
   }
 
   pub async fn close_bundle(&self) -> HookNoopReturn {
-    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_close_bundle_meta)
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_close_bundle_meta)
     {
-      plugin.call_close_bundle(ctx).await?;
+      self.timed(plugin_idx, "close_bundle", plugin.call_close_bundle(ctx)).await?;
     }
     Ok(())
   }
@@ -8,16 +8,22 @@ use crate::{
     hook_write_bundle_args::HookWriteBundleArgs,
   },
   HookAddonArgs, HookBuildEndArgs, HookBuildStartArgs, HookGenerateBundleArgs,
-  HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs, HookRenderStartArgs,
-  HookResolveIdArgs, HookTransformArgs, Plugin, SharedTransformPluginContext,
+  HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs, HookRenderDynamicImportArgs,
+  HookRenderStartArgs, HookResolveFileUrlArgs, HookResolveIdArgs, HookResolveImportMetaArgs,
+  HookTransformArgs, Plugin, SharedTransformPluginContext,
 };
 use anyhow::Ok;
-use rolldown_common::{ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{
+  BundlerOptions, ModuleInfo, OutputOptions, RollupRenderedChunk, WatcherChangeKind,
+};
 
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
 pub use crate::plugin::HookNoopReturn;
+pub use crate::plugin::HookOptionsReturn;
+pub use crate::plugin::HookOutputOptionsReturn;
 pub use crate::plugin::HookRenderChunkReturn;
+pub use crate::plugin::HookRenderDynamicImportReturn;
 pub use crate::plugin::HookResolveIdReturn;
 pub use crate::plugin::HookTransformAstReturn;
 pub use crate::plugin::HookTransformReturn;
@@ -38,6 +44,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   // --- Build hooks ---
 
+  async fn call_options(&self, _options: &BundlerOptions) -> HookOptionsReturn;
+
+  fn call_options_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_output_options(&self, _options: &OutputOptions) -> HookOutputOptionsReturn;
+
+  fn call_output_options_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_build_start(
     &self,
     _ctx: &PluginContext,
@@ -143,6 +157,30 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_outro_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderDynamicImportArgs,
+  ) -> HookRenderDynamicImportReturn;
+
+  fn call_render_dynamic_import_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs,
+  ) -> HookInjectionOutputReturn;
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs,
+  ) -> HookInjectionOutputReturn;
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_render_chunk(
     &self,
     _ctx: &PluginContext,
@@ -215,6 +253,22 @@ impl<T: Plugin> Pluginable for T {
     Plugin::name(self)
   }
 
+  async fn call_options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    Plugin::options(self, options).await
+  }
+
+  fn call_options_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::options_meta(self)
+  }
+
+  async fn call_output_options(&self, options: &OutputOptions) -> HookOutputOptionsReturn {
+    Plugin::output_options(self, options).await
+  }
+
+  fn call_output_options_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::output_options_meta(self)
+  }
+
   async fn call_build_start(
     &self,
     ctx: &PluginContext,
@@ -356,6 +410,42 @@ impl<T: Plugin> Pluginable for T {
     Plugin::outro_meta(self)
   }
 
+  async fn call_render_dynamic_import(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderDynamicImportArgs,
+  ) -> HookRenderDynamicImportReturn {
+    Plugin::render_dynamic_import(self, ctx, args).await
+  }
+
+  fn call_render_dynamic_import_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::render_dynamic_import_meta(self)
+  }
+
+  async fn call_resolve_file_url(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs,
+  ) -> HookInjectionOutputReturn {
+    Plugin::resolve_file_url(self, ctx, args).await
+  }
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_file_url_meta(self)
+  }
+
+  async fn call_resolve_import_meta(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs,
+  ) -> HookInjectionOutputReturn {
+    Plugin::resolve_import_meta(self, ctx, args).await
+  }
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_import_meta_meta(self)
+  }
+
   async fn call_render_chunk(
     &self,
     ctx: &PluginContext,
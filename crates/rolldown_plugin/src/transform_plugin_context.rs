@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
-use crate::PluginContext;
+use crate::{LogPosition, PluginContext, PluginContextLog};
 use arcstr::ArcStr;
+use rolldown_error::Severity;
 use rolldown_sourcemap::{collapse_sourcemaps, SourceMap};
 use rolldown_utils::unique_arc::WeakRef;
 use string_wizard::{MagicString, SourceMapOptions};
@@ -11,6 +12,7 @@ use string_wizard::{MagicString, SourceMapOptions};
 pub struct TransformPluginContext {
   pub inner: PluginContext,
   sourcemap_chain: WeakRef<Vec<SourceMap>>,
+  /// The code passed to the `transform` hook.
   original_code: ArcStr,
   id: ArcStr,
 }
@@ -25,6 +27,75 @@ impl TransformPluginContext {
     Self { inner, sourcemap_chain, original_code, id }
   }
 
+  /// Same as [PluginContext::warn], but `id` defaults to the transformed module. `pos` refers to the
+  /// code passed to the `transform` hook, and is mapped back to the original source for the code frame
+  /// if previous plugins have transformed the module.
+  pub fn warn(&self, log: impl Into<PluginContextLog>) {
+    let (log, source) = self.with_transformed_module(log.into());
+    self.inner.add_log(Severity::Warning, log, source);
+  }
+
+  pub fn info(&self, log: impl Into<PluginContextLog>) {
+    let (log, source) = self.with_transformed_module(log.into());
+    self.inner.add_log(Severity::Info, log, source);
+  }
+
+  pub fn debug(&self, log: impl Into<PluginContextLog>) {
+    let (log, source) = self.with_transformed_module(log.into());
+    self.inner.add_log(Severity::Debug, log, source);
+  }
+
+  pub fn error(&self, log: impl Into<PluginContextLog>) -> anyhow::Error {
+    let (log, source) = self.with_transformed_module(log.into());
+    self.inner.create_log(Severity::Error, log, source).into()
+  }
+
+  fn with_transformed_module(
+    &self,
+    mut log: PluginContextLog,
+  ) -> (PluginContextLog, Option<ArcStr>) {
+    let id = log.id.get_or_insert_with(|| self.id.to_string());
+    if id.as_str() != self.id.as_str() {
+      return (log, None);
+    }
+    if let Some((pos, source)) = log.pos.and_then(|pos| self.map_to_original_source(pos)) {
+      log.pos = Some(pos);
+      return (log, Some(source));
+    }
+    (log, Some(self.original_code.clone()))
+  }
+
+  /// Map `pos` in the code passed to the `transform` hook to the source that the sourcemap chain starts
+  /// from. Returns `None` if the code hasn't been transformed yet, or the position can't be mapped.
+  fn map_to_original_source(&self, pos: LogPosition) -> Option<(LogPosition, ArcStr)> {
+    if self.sourcemap_chain.with_inner(Vec::is_empty) {
+      return None;
+    }
+    let (line, column) = match pos {
+      LogPosition::Offset(offset) => {
+        let before = &self.original_code.as_bytes()[..offset.min(self.original_code.len())];
+        let line_start =
+          before.iter().rposition(|byte| *byte == b'\n').map_or(0, |index| index + 1);
+        (before.split(|byte| *byte == b'\n').count() - 1, before.len() - line_start)
+      }
+      LogPosition::LineColumn { line, column } => (line.checked_sub(1)?, column),
+    };
+    let sourcemap = self.get_combined_sourcemap();
+    let lookup_table = sourcemap.generate_lookup_table();
+    let token = sourcemap.lookup_token(
+      &lookup_table,
+      u32::try_from(line).ok()?,
+      u32::try_from(column).ok()?,
+    )?;
+    let source = sourcemap.get_source_content(token.get_source_id()?)?;
+    // Tokens only mark the start of mapped segments, so the distance from the start is kept.
+    let column = token.get_src_col() as usize + (column - token.get_dst_col() as usize);
+    Some((
+      LogPosition::LineColumn { line: token.get_src_line() as usize + 1, column },
+      source.into(),
+    ))
+  }
+
   pub fn get_combined_sourcemap(&self) -> SourceMap {
     self.sourcemap_chain.with_inner(|sourcemap_chain| {
       if sourcemap_chain.is_empty() {
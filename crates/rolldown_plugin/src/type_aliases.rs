@@ -1,6 +1,11 @@
+use std::time::Duration;
+
 use oxc_index::IndexVec;
+use rolldown_utils::dashmap::FxDashMap;
 
 use crate::{__inner::SharedPluginable, types::plugin_idx::PluginIdx, PluginContext};
 
 pub type IndexPluginable = IndexVec<PluginIdx, SharedPluginable>;
 pub type IndexPluginContext = IndexVec<PluginIdx, PluginContext>;
+/// Calls and total duration of each hook of each plugin.
+pub type HookTimings = FxDashMap<(PluginIdx, &'static str), (u32, Duration)>;
@@ -0,0 +1,13 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookRenderDynamicImportArgs<'a> {
+  /// The id of the module containing the `import()` expression.
+  pub module_id: &'a str,
+  pub target_module_id: &'a str,
+  /// The preliminary filename of the chunk containing the `import()` expression.
+  pub chunk_id: &'a str,
+  /// The preliminary filename of the chunk of the imported module. `None` for external modules.
+  pub target_chunk_id: Option<&'a str>,
+  pub format: OutputFormat,
+}
@@ -0,0 +1,6 @@
+/// Replaces `import(` and `)` of a dynamic import. The quoted import path is rendered in between.
+#[derive(Debug)]
+pub struct HookRenderDynamicImportOutput {
+  pub left: String,
+  pub right: String,
+}
@@ -0,0 +1,13 @@
+use rolldown_common::OutputFormat;
+
+/// Arguments of `import.meta.ROLLUP_FILE_URL_<referenceId>`.
+#[derive(Debug)]
+pub struct HookResolveFileUrlArgs<'a> {
+  pub reference_id: &'a str,
+  pub file_name: &'a str,
+  /// The path of the referenced file relative to the chunk containing the reference.
+  pub relative_path: &'a str,
+  pub module_id: &'a str,
+  pub chunk_id: &'a str,
+  pub format: OutputFormat,
+}
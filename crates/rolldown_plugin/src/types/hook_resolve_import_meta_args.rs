@@ -0,0 +1,10 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookResolveImportMetaArgs<'a> {
+  /// The property of `import.meta.<property>`, or `None` for `import.meta` itself.
+  pub property: Option<&'a str>,
+  pub module_id: &'a str,
+  pub chunk_id: &'a str,
+  pub format: OutputFormat,
+}
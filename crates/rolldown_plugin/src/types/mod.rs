@@ -7,14 +7,21 @@ pub mod hook_load_args;
 pub mod hook_load_output;
 pub mod hook_render_chunk_args;
 pub mod hook_render_chunk_output;
+pub mod hook_render_dynamic_import_args;
+pub mod hook_render_dynamic_import_output;
 pub mod hook_render_error;
 pub mod hook_render_start_args;
+pub mod hook_resolve_file_url_args;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
+pub mod hook_resolve_import_meta_args;
 pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
 pub mod hook_transform_output;
 pub mod hook_write_bundle_args;
+pub mod plugin_context_log;
+pub mod plugin_context_parse_options;
 pub mod plugin_context_resolve_options;
 pub mod plugin_idx;
+pub mod plugin_timings;
@@ -0,0 +1,31 @@
+/// A log reported through `PluginContext::{warn, info, debug, error}`.
+#[derive(Debug, Default)]
+pub struct PluginContextLog {
+  pub message: String,
+  pub code: Option<String>,
+  /// The module the log is about. Defaults to the transformed module in the `transform` hook.
+  pub id: Option<String>,
+  /// Position in the source of `id`, shown in a code frame if the source is known. In the `transform` hook,
+  /// it's the position in the code passed to the hook.
+  pub pos: Option<LogPosition>,
+}
+
+impl From<String> for PluginContextLog {
+  fn from(message: String) -> Self {
+    Self { message, ..Default::default() }
+  }
+}
+
+impl From<&str> for PluginContextLog {
+  fn from(message: &str) -> Self {
+    message.to_string().into()
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LogPosition {
+  /// Byte offset in the source.
+  Offset(usize),
+  /// `line` is 1-based and `column` is 0-based, the same as sourcemaps.
+  LineColumn { line: usize, column: usize },
+}
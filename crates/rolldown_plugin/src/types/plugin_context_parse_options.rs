@@ -0,0 +1,8 @@
+#[derive(Debug, Default)]
+pub struct PluginContextParseOptions {
+  /// The filename shown in the diagnostics of syntax errors.
+  pub id: Option<String>,
+  pub typescript: bool,
+  /// Ignored if `jsx` is disabled in the bundler options, the same as for modules.
+  pub jsx: bool,
+}
@@ -0,0 +1,102 @@
+use std::{fmt, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+/// Plugins taking at least this share of the time spent in hooks are flagged as dominant.
+const DOMINANT_SHARE: f64 = 0.3;
+
+/// Accumulated calls and time of a hook of a plugin.
+#[derive(Debug, Clone)]
+pub struct PluginHookTiming {
+  pub plugin: String,
+  pub hook: &'static str,
+  pub calls: u32,
+  /// Wall time spent in the hook, including the time of awaiting, e.g. for calling js plugins.
+  pub duration: Duration,
+}
+
+/// Time spent in hooks of each plugin during a build. Enabled by the `plugin_timings` option.
+#[derive(Debug, Clone, Default)]
+pub struct PluginTimings {
+  /// Sorted by `duration` in descending order.
+  pub hooks: Vec<PluginHookTiming>,
+}
+
+impl PluginTimings {
+  pub fn new(mut hooks: Vec<PluginHookTiming>) -> Self {
+    hooks.sort_by(|a, b| b.duration.cmp(&a.duration).then_with(|| a.plugin.cmp(&b.plugin)));
+    Self { hooks }
+  }
+
+  pub fn total(&self) -> Duration {
+    self.hooks.iter().map(|timing| timing.duration).sum()
+  }
+
+  /// Time spent in all hooks of each plugin, sorted by duration in descending order.
+  pub fn plugins(&self) -> Vec<(&str, Duration)> {
+    let mut plugins = FxHashMap::<&str, Duration>::default();
+    for timing in &self.hooks {
+      *plugins.entry(timing.plugin.as_str()).or_default() += timing.duration;
+    }
+    let mut plugins = plugins.into_iter().collect::<Vec<_>>();
+    plugins.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    plugins
+  }
+
+  /// Plugins that take a large share of the time spent in hooks. A single plugin never dominates.
+  pub fn dominant_plugins(&self) -> Vec<&str> {
+    let plugins = self.plugins();
+    if plugins.len() < 2 {
+      return vec![];
+    }
+    let total = self.total();
+    plugins
+      .into_iter()
+      .filter(|(_, duration)| share(*duration, total) >= DOMINANT_SHARE)
+      .map(|(plugin, _)| plugin)
+      .collect()
+  }
+}
+
+fn share(duration: Duration, total: Duration) -> f64 {
+  if total.is_zero() {
+    0.0
+  } else {
+    duration.as_secs_f64() / total.as_secs_f64()
+  }
+}
+
+/// Prints a table of plugins and their hooks.
+impl fmt::Display for PluginTimings {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let total = self.total();
+    let dominant_plugins = self.dominant_plugins();
+    writeln!(f, "Plugin timings ({:.2}ms in hooks)", total.as_secs_f64() * 1000.0)?;
+    writeln!(f, "{:<40} {:>8} {:>12} {:>7}", "Plugin / Hook", "Calls", "Time", "Share")?;
+    for (plugin, duration) in self.plugins() {
+      let name = if dominant_plugins.contains(&plugin) {
+        format!("{plugin} (dominant)")
+      } else {
+        plugin.to_string()
+      };
+      writeln!(
+        f,
+        "{name:<40} {:>8} {:>10.2}ms {:>6.1}%",
+        "",
+        duration.as_secs_f64() * 1000.0,
+        share(duration, total) * 100.0
+      )?;
+      for timing in self.hooks.iter().filter(|timing| timing.plugin == plugin) {
+        writeln!(
+          f,
+          "  {:<38} {:>8} {:>10.2}ms {:>6.1}%",
+          timing.hook,
+          timing.calls,
+          timing.duration.as_secs_f64() * 1000.0,
+          share(timing.duration, total) * 100.0
+        )?;
+      }
+    }
+    Ok(())
+  }
+}
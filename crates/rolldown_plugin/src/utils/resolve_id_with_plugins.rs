@@ -92,6 +92,7 @@ pub async fn resolve_id_check_external(
 }
 
 #[allow(clippy::too_many_arguments)]
+#[tracing::instrument(level = "trace", skip_all, fields(specifier = request, importer = importer.unwrap_or_default()))]
 pub async fn resolve_id_with_plugins(
   resolver: &Resolver,
   plugin_driver: &PluginDriver,
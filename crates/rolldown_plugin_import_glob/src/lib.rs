@@ -1,17 +1,19 @@
-use glob::glob;
+use glob_match::glob_match;
 use oxc::{
-  allocator::Vec,
+  allocator::{Allocator, Vec},
   ast::{
     ast::{
       Argument, ArrayExpressionElement, Expression, FormalParameterKind, ImportOrExportKind,
-      ObjectPropertyKind, PropertyKey, PropertyKind, Statement,
+      ObjectPropertyKind, Program, PropertyKey, PropertyKind, Statement,
     },
     visit::walk_mut,
     AstBuilder, VisitMut, NONE,
   },
   span::{Span, SPAN},
 };
+use rolldown_fs::FileSystem;
 use rolldown_plugin::{HookTransformAstArgs, HookTransformAstReturn, Plugin, PluginContext};
+use rolldown_utils::glob_base_dir::glob_base_dir;
 use rustc_hash::FxHashMap;
 use std::{
   borrow::Cow,
@@ -39,31 +41,60 @@ impl Plugin for ImportGlobPlugin {
 
   fn transform_ast(
     &self,
-    _ctx: &PluginContext,
+    ctx: &PluginContext,
     mut args: HookTransformAstArgs,
   ) -> HookTransformAstReturn {
-    args.ast.program.with_mut(|fields| {
-      let ast_builder = AstBuilder::new(fields.allocator);
-      let normalized_path = args.cwd.join(args.id);
-      let normalized_id = normalized_path.to_slash_lossy();
-      let root = self.config.root.as_ref().map(PathBuf::from);
-      let mut visitor = GlobImportVisit {
-        root: root.as_ref().unwrap_or(args.cwd),
-        import_decls: ast_builder.vec(),
-        ast_builder,
-        current: 0,
-        restore_query_extension: self.config.restore_query_extension,
-        id: &normalized_id,
-      };
-      visitor.visit_program(fields.program);
-      if !visitor.import_decls.is_empty() {
-        fields.program.body.extend(visitor.import_decls);
-      }
+    let root = self.config.root.as_ref().map(PathBuf::from);
+    let id = args.cwd.join(args.id);
+    let watch_globs = args.ast.program.with_mut(|fields| {
+      rewrite_import_glob(
+        fields.allocator,
+        fields.program,
+        ctx.fs(),
+        root.as_ref().unwrap_or(args.cwd),
+        &id,
+        self.config.restore_query_extension,
+      )
     });
+    for pattern in &watch_globs {
+      ctx.add_watch_glob(pattern);
+    }
     Ok(args.ast)
   }
 }
 
+/// Rewrite `import.meta.glob(...)` calls of the module `id` into objects of imports, evaluating the
+/// globs with `fs`. Returns the absolute glob patterns, so files added or removed later could be
+/// watched.
+///
+/// The scan stage of rolldown calls this for every module, so the plugin is only needed for its options.
+pub fn rewrite_import_glob<'ast, F: FileSystem + ?Sized>(
+  allocator: &'ast Allocator,
+  program: &mut Program<'ast>,
+  fs: &F,
+  root: &Path,
+  id: &Path,
+  restore_query_extension: bool,
+) -> std::vec::Vec<String> {
+  let ast_builder = AstBuilder::new(allocator);
+  let normalized_id = id.to_slash_lossy();
+  let mut visitor = GlobImportVisit {
+    fs,
+    root,
+    import_decls: ast_builder.vec(),
+    ast_builder,
+    current: 0,
+    restore_query_extension,
+    id: &normalized_id,
+    watch_globs: vec![],
+  };
+  visitor.visit_program(program);
+  if !visitor.import_decls.is_empty() {
+    program.body.extend(visitor.import_decls);
+  }
+  visitor.watch_globs
+}
+
 #[derive(Debug, Default)]
 pub struct ImportGlobOptions {
   import: Option<String>,
@@ -71,16 +102,18 @@ pub struct ImportGlobOptions {
   query: Option<String>,
 }
 
-pub struct GlobImportVisit<'ast, 'a> {
-  root: &'a PathBuf,
+struct GlobImportVisit<'ast, 'a, F: FileSystem + ?Sized> {
+  fs: &'a F,
+  root: &'a Path,
   ast_builder: AstBuilder<'ast>,
   import_decls: Vec<'ast, Statement<'ast>>,
   current: usize,
   restore_query_extension: bool,
   id: &'a str,
+  watch_globs: std::vec::Vec<String>,
 }
 
-impl<'ast> VisitMut<'ast> for GlobImportVisit<'ast, '_> {
+impl<'ast, F: FileSystem + ?Sized> VisitMut<'ast> for GlobImportVisit<'ast, '_, F> {
   fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
     if let Expression::CallExpression(call_expr) = expr {
       match &call_expr.callee {
@@ -206,7 +239,7 @@ fn extract_import_glob_options(arg: &Argument, opts: &mut ImportGlobOptions) {
   }
 }
 
-impl<'ast> GlobImportVisit<'ast, '_> {
+impl<'ast, F: FileSystem + ?Sized> GlobImportVisit<'ast, '_, F> {
   fn eval_glob_expr(&mut self, arg: &Argument, files: &mut std::vec::Vec<String>) {
     let mut glob_exprs = vec![];
     match arg {
@@ -223,19 +256,34 @@ impl<'ast> GlobImportVisit<'ast, '_> {
       _ => {}
     }
 
+    let root = self.root.to_slash_lossy();
+    let mut patterns = vec![];
+    let mut negative_patterns = vec![];
     for glob_expr in glob_exprs {
       let processed_glob_expr = preprocess_glob_expr(glob_expr);
-      let root = &self.root.to_slash_lossy();
-      let (absolute_glob, mut dir) = to_absolute_glob(&processed_glob_expr, root, self.id).unwrap();
-      if dir == "" {
-        dir = Cow::Borrowed(root);
+      // TODO: emit a warning for invalid patterns
+      let Ok(absolute_glob) = to_absolute_glob(&processed_glob_expr, &root, self.id) else {
+        continue;
+      };
+      if let Some(negative_glob) = absolute_glob.strip_prefix('!') {
+        negative_patterns.push(negative_glob.to_string());
+      } else {
+        patterns.push(absolute_glob);
       }
-      // TODO handle error
-      for file in glob(&absolute_glob).unwrap() {
-        let file = file.unwrap().as_path().relative(dir.as_ref()).to_slash_lossy().to_string();
-        files.push(format!("./{file}"));
+    }
+
+    let dir =
+      Path::new(self.id).parent().map_or(Cow::Borrowed(root.as_ref()), |dir| dir.to_slash_lossy());
+    for pattern in &patterns {
+      for file in glob_files(self.fs, pattern, &negative_patterns) {
+        let relative = Path::new(&file).relative(dir.as_ref()).to_slash_lossy().into_owned();
+        let file = if relative.starts_with("../") { relative } else { format!("./{relative}") };
+        if !files.contains(&file) {
+          files.push(file);
+        }
       }
     }
+    self.watch_globs.extend(patterns);
   }
 
   #[allow(clippy::too_many_lines, clippy::cast_possible_truncation)]
@@ -428,21 +476,14 @@ fn preprocess_glob_expr(glob_expr: &str) -> String {
   new_glob_expr
 }
 
-fn to_absolute_glob<'a>(
-  mut glob: &'a str,
-  root: &'a str,
-  importer: &'a str,
-) -> anyhow::Result<(String, Cow<'a, str>)> {
+fn to_absolute_glob(mut glob: &str, root: &str, importer: &str) -> anyhow::Result<String> {
   let mut pre: Option<char> = None;
   if glob.starts_with('!') {
     pre = Some('!');
     glob = &glob[1..];
   }
 
-  let dir = {
-    let dir = Path::new(importer).parent().unwrap_or_else(|| Path::new(root));
-    dir.to_slash_lossy()
-  };
+  let dir = Path::new(importer).parent().unwrap_or_else(|| Path::new(root)).to_slash_lossy();
 
   let mut ret = if let Some(pre) = pre { String::from(pre) } else { String::new() };
 
@@ -453,11 +494,93 @@ fn to_absolute_glob<'a>(
   } else if let Some(glob) = glob.strip_prefix("../") {
     ret.push_str(&Path::new(dir.as_ref()).join(glob).to_slash_lossy());
   } else if glob.starts_with("**") {
-    ret.push_str(glob);
+    ret.push_str(&Path::new(root).join(glob).to_slash_lossy());
   } else {
     // https://github.com/rolldown/vite/blob/454c8fff9f7115ed29281c2d927366280508a0ab/packages/vite/src/node/plugins/importMetaGlob.ts#L563-L569
     // Needs to investigate if oxc resolver support this pattern
     return Err(anyhow::format_err!("Invalid glob pattern: {}", glob));
   };
-  Ok((ret, dir))
+  Ok(ret)
+}
+
+/// Find files matching the absolute `pattern` but none of the `negative_patterns`. The result is sorted.
+/// `node_modules` is skipped unless the pattern explicitly contains it.
+fn glob_files<F: FileSystem + ?Sized>(
+  fs: &F,
+  pattern: &str,
+  negative_patterns: &[String],
+) -> std::vec::Vec<String> {
+  let base_dir = glob_base_dir(pattern);
+  let max_depth = if pattern.contains("**") {
+    usize::MAX
+  } else {
+    pattern[base_dir.len()..].matches('/').count()
+  };
+  let include_node_modules = pattern.contains("node_modules");
+  let mut files = vec![];
+  let mut stack = vec![(PathBuf::from(base_dir), 1)];
+  while let Some((dir, depth)) = stack.pop() {
+    let Ok(entries) = fs.read_dir(&dir) else { continue };
+    for entry in entries {
+      if fs.is_dir(&entry) {
+        let is_node_modules = entry.file_name().is_some_and(|name| name == "node_modules");
+        if depth < max_depth && (include_node_modules || !is_node_modules) {
+          stack.push((entry, depth + 1));
+        }
+        continue;
+      }
+      let path = entry.to_slash_lossy();
+      if glob_match(pattern, &path)
+        && !negative_patterns.iter().any(|negative| glob_match(negative, &path))
+      {
+        files.push(path.into_owned());
+      }
+    }
+  }
+  files.sort_unstable();
+  files
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use oxc::{allocator::Allocator, codegen::CodeGenerator, parser::Parser, span::SourceType};
+  use rolldown_fs::{FileSystem, MemoryFileSystem};
+
+  use crate::rewrite_import_glob;
+
+  #[test]
+  fn evaluate_globs_with_the_given_file_system() {
+    let fs = MemoryFileSystem::default();
+    for dir in ["/project", "/project/routes", "/project/routes/nested"] {
+      fs.create_dir_all(Path::new(dir)).unwrap();
+    }
+    for file in
+      ["/project/routes/a.js", "/project/routes/_private.js", "/project/routes/nested/b.js"]
+    {
+      fs.write(Path::new(file), b"export default 1").unwrap();
+    }
+
+    let allocator = Allocator::default();
+    let source = "export const routes = import.meta.glob(['./routes/**/*.js', '!**/_*.js'])";
+    let mut program = Parser::new(&allocator, source, SourceType::mjs()).parse().program;
+    let watch_globs = rewrite_import_glob(
+      &allocator,
+      &mut program,
+      &fs,
+      Path::new("/project"),
+      Path::new("/project/main.js"),
+      false,
+    );
+
+    assert_eq!(watch_globs, ["/project/routes/**/*.js"]);
+    let code = CodeGenerator::new().build(&program).code;
+    assert!(code.contains(r#""./routes/a.js": () => import("./routes/a.js")"#), "{code}");
+    assert!(
+      code.contains(r#""./routes/nested/b.js": () => import("./routes/nested/b.js")"#),
+      "{code}"
+    );
+    assert!(!code.contains("_private"), "{code}");
+  }
 }
@@ -1,9 +1,16 @@
 // An wrapper around the `oxc_resolver` crate to provide a more rolldown-specific API.
 
 pub mod error;
+mod package_exports;
+mod pnp;
 mod resolver;
+mod tsconfig;
 
-pub use crate::resolver::{ResolveReturn, Resolver};
+pub use crate::{
+  package_exports::PackageExportsHint,
+  resolver::{ResolveReturn, Resolver},
+  tsconfig::TsconfigCompilerOptions,
+};
 
 pub use oxc_resolver::ResolveError;
 pub use rolldown_common::bundler_options::ResolveOptions;
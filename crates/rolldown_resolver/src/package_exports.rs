@@ -0,0 +1,145 @@
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+/// Explains why a subpath is not exported by the `exports` field of a package, so the resolve error
+/// could tell users what has been tried instead of a bare "not exported" message.
+#[derive(Debug)]
+pub struct PackageExportsHint {
+  /// The requested subpath, e.g. `./feature`.
+  pub subpath: String,
+  /// Conditions that were active while resolving.
+  pub active_conditions: Vec<String>,
+  /// The key in `exports` matched by `subpath`, if any.
+  pub matched_key: Option<String>,
+  /// Condition paths and targets found under the matched key. A condition path looks like
+  /// `node > import`. An empty condition path means the target is a plain string.
+  pub tried_targets: Vec<(String, String)>,
+  /// The exported subpath that is the closest to the requested one.
+  pub closest_subpath: Option<String>,
+}
+
+impl PackageExportsHint {
+  pub fn new(exports: &Value, subpath: &str, active_conditions: &[String]) -> Self {
+    let subpath =
+      if subpath.starts_with('.') { subpath.to_string() } else { format!("./{subpath}") };
+    let subpath_map = normalize_exports(exports);
+
+    let matched = subpath_map
+      .iter()
+      .find(|(key, _)| *key == subpath)
+      .or_else(|| subpath_map.iter().find(|(key, _)| matches_pattern_key(key, &subpath)));
+
+    let mut tried_targets = vec![];
+    if let Some((_, target)) = matched {
+      collect_targets(target, &mut vec![], &mut tried_targets);
+    }
+
+    let closest_subpath = if matched.is_some() {
+      None
+    } else {
+      subpath_map
+        .iter()
+        .map(|(key, _)| *key)
+        .filter(|key| !key.contains('*'))
+        .map(|key| (levenshtein(key, &subpath), key))
+        .filter(|(distance, key)| *distance <= key.len().max(subpath.len()) / 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, key)| key.to_string())
+    };
+
+    Self {
+      subpath,
+      active_conditions: active_conditions.to_vec(),
+      matched_key: matched.map(|(key, _)| (*key).to_string()),
+      tried_targets,
+      closest_subpath,
+    }
+  }
+
+  pub fn to_help_message(&self) -> String {
+    let mut help = String::new();
+    match &self.matched_key {
+      Some(key) if self.tried_targets.is_empty() => {
+        let _ = write!(help, "\"exports[{key:?}]\" doesn't have any target. ");
+      }
+      Some(key) => {
+        let targets = self
+          .tried_targets
+          .iter()
+          .map(|(conditions, target)| {
+            if conditions.is_empty() {
+              target.clone()
+            } else {
+              format!("{conditions} -> {target}")
+            }
+          })
+          .collect::<Vec<_>>()
+          .join(", ");
+        let _ = write!(help, "Tried \"exports[{key:?}]\": {targets}. ");
+      }
+      None => {
+        let _ = write!(help, "No key of \"exports\" matches {:?}. ", self.subpath);
+      }
+    }
+    let _ = write!(help, "Active conditions: {}.", self.active_conditions.join(", "));
+    if let Some(closest) = &self.closest_subpath {
+      let _ = write!(help, " Did you mean {closest:?}?");
+    }
+    help
+  }
+}
+
+/// Normalize `exports` to a list of `(subpath, target)`. `"exports": "./index.js"` and conditional
+/// exports without subpath keys are treated as `{ ".": ... }`.
+fn normalize_exports(exports: &Value) -> Vec<(&str, &Value)> {
+  match exports {
+    Value::Object(map) if map.keys().any(|key| key.starts_with('.')) => {
+      map.iter().map(|(key, value)| (key.as_str(), value)).collect()
+    }
+    _ => vec![(".", exports)],
+  }
+}
+
+fn matches_pattern_key(key: &str, subpath: &str) -> bool {
+  if let Some((prefix, suffix)) = key.split_once('*') {
+    subpath.len() >= key.len() && subpath.starts_with(prefix) && subpath.ends_with(suffix)
+  } else {
+    key.ends_with('/') && subpath.starts_with(key)
+  }
+}
+
+fn collect_targets<'a>(
+  target: &'a Value,
+  conditions: &mut Vec<&'a str>,
+  out: &mut Vec<(String, String)>,
+) {
+  match target {
+    Value::String(path) => out.push((conditions.join(" > "), path.clone())),
+    Value::Array(items) => items.iter().for_each(|item| collect_targets(item, conditions, out)),
+    Value::Object(map) => {
+      for (condition, value) in map {
+        conditions.push(condition);
+        collect_targets(value, conditions, out);
+        conditions.pop();
+      }
+    }
+    Value::Null => out.push((conditions.join(" > "), "null".to_string())),
+    Value::Bool(_) | Value::Number(_) => {}
+  }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut prev = (0..=b.len()).collect::<Vec<_>>();
+  let mut cur = vec![0; b.len() + 1];
+  for (i, a_char) in a.chars().enumerate() {
+    cur[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let cost = usize::from(a_char != *b_char);
+      cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+    }
+    std::mem::swap(&mut prev, &mut cur);
+  }
+  prev[b.len()]
+}
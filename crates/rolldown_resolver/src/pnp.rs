@@ -0,0 +1,238 @@
+// A minimal implementation of Yarn Plug'n'Play resolution.
+// - https://yarnpkg.com/advanced/pnp-spec
+//
+// Only packages that live on disk (workspaces, `unplugged` packages, or packages installed with
+// `pnpMode: loose`) are supported. Packages stored in zip archives are not resolved here, and the
+// caller should fall back to the `node_modules` resolution.
+
+use std::path::{Path, PathBuf};
+
+use rolldown_fs::FileSystem;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+use sugar_path::SugarPath;
+
+/// `(name, reference)`. The top-level locator is `(None, None)`.
+type Locator = (Option<String>, Option<String>);
+
+#[derive(Debug)]
+struct PackageInfo {
+  /// Absolute path of the package directory
+  location: PathBuf,
+  dependencies: FxHashMap<String, Option<Locator>>,
+}
+
+#[derive(Debug)]
+pub struct PnpManifest {
+  enable_top_level_fallback: bool,
+  fallback_exclusion_list: Vec<Locator>,
+  fallback_pool: FxHashMap<String, Option<Locator>>,
+  packages: FxHashMap<Locator, PackageInfo>,
+  /// Package locations mapped to the package stored there, so finding the owner of a path only
+  /// needs a lookup per ancestor directory instead of a scan of every package.
+  locators_by_location: FxHashMap<PathBuf, Locator>,
+}
+
+/// The result of mapping a bare specifier to a directory on disk.
+#[derive(Debug)]
+pub struct PnpResolution {
+  /// Directory of the resolved package.
+  pub package_dir: PathBuf,
+  /// The rest of the specifier, e.g. `fp/map` for `lodash/fp/map`.
+  pub subpath: Option<String>,
+}
+
+impl PnpManifest {
+  /// Find `.pnp.data.json` or `.pnp.cjs` in `cwd` or its ancestors and load it.
+  pub fn find<F: FileSystem>(fs: &F, cwd: &Path) -> Option<Self> {
+    cwd.ancestors().find_map(|dir| {
+      if let Ok(content) = fs.read_to_string(&dir.join(".pnp.data.json")) {
+        return Self::parse(&content, dir);
+      }
+      let content = fs.read_to_string(&dir.join(".pnp.cjs")).ok()?;
+      Self::parse(&extract_raw_runtime_state(&content)?, dir)
+    })
+  }
+
+  fn parse(content: &str, base_dir: &Path) -> Option<Self> {
+    let data: Value = serde_json::from_str(content).ok()?;
+
+    let mut packages = FxHashMap::default();
+    let mut locators_by_location = FxHashMap::default();
+    for entry in data.get("packageRegistryData")?.as_array()? {
+      let [name, references] = entry.as_array()?.as_slice() else { return None };
+      let name = name.as_str().map(ToString::to_string);
+      for reference_entry in references.as_array()? {
+        let [reference, info] = reference_entry.as_array()?.as_slice() else { return None };
+        let location = info.get("packageLocation")?.as_str()?;
+        let dependencies = info
+          .get("packageDependencies")
+          .and_then(Value::as_array)
+          .map(|deps| {
+            deps
+              .iter()
+              .filter_map(|dep| {
+                let [dep_name, dep_reference] = dep.as_array()?.as_slice() else { return None };
+                let dep_name = dep_name.as_str()?.to_string();
+                let locator = parse_dependency_target(&dep_name, dep_reference);
+                Some((dep_name, locator))
+              })
+              .collect::<FxHashMap<_, _>>()
+          })
+          .unwrap_or_default();
+        let locator = (name.clone(), reference.as_str().map(ToString::to_string));
+        let location = base_dir.join(location).normalize();
+        // The top-level locator shares its location with the root workspace, which owns the files there.
+        if name.is_none() {
+          locators_by_location.entry(location.clone()).or_insert_with(|| locator.clone());
+        } else {
+          locators_by_location.insert(location.clone(), locator.clone());
+        }
+        packages.insert(locator, PackageInfo { location, dependencies });
+      }
+    }
+
+    let fallback_pool = data
+      .get("fallbackPool")
+      .and_then(Value::as_array)
+      .map(|pool| {
+        pool
+          .iter()
+          .filter_map(|item| {
+            let [name, reference] = item.as_array()?.as_slice() else { return None };
+            let name = name.as_str()?.to_string();
+            let locator = parse_dependency_target(&name, reference);
+            Some((name, locator))
+          })
+          .collect::<FxHashMap<_, _>>()
+      })
+      .unwrap_or_default();
+
+    let fallback_exclusion_list = data
+      .get("fallbackExclusionList")
+      .and_then(Value::as_array)
+      .map(|list| {
+        list
+          .iter()
+          .filter_map(|item| {
+            let [name, references] = item.as_array()?.as_slice() else { return None };
+            let name = name.as_str()?.to_string();
+            Some(references.as_array()?.iter().filter_map(move |reference| {
+              Some((Some(name.clone()), Some(reference.as_str()?.to_string())))
+            }))
+          })
+          .flatten()
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+
+    Some(Self {
+      enable_top_level_fallback: data
+        .get("enableTopLevelFallback")
+        .and_then(Value::as_bool)
+        .unwrap_or(false),
+      fallback_exclusion_list,
+      fallback_pool,
+      packages,
+      locators_by_location,
+    })
+  }
+
+  /// Map a bare specifier imported by `importer` to the directory of the package that provides it.
+  /// Returns `None` if the specifier should be resolved in the normal way.
+  pub fn resolve(&self, specifier: &str, importer: &Path) -> Option<PnpResolution> {
+    let (ident, subpath) = parse_bare_specifier(specifier)?;
+    let (parent_locator, parent) = self.find_locator(importer)?;
+
+    let dependency = match parent.dependencies.get(ident) {
+      Some(dependency) => dependency.clone(),
+      None => {
+        if !self.enable_top_level_fallback
+          || self.fallback_exclusion_list.iter().any(|excluded| excluded == parent_locator)
+        {
+          return None;
+        }
+        self
+          .packages
+          .get(&(None, None))
+          .and_then(|top_level| top_level.dependencies.get(ident))
+          .or_else(|| self.fallback_pool.get(ident))?
+          .clone()
+      }
+    };
+
+    // `None` means a missing peer dependency.
+    let package = self.packages.get(&dependency?)?;
+    // Zip archives are not supported.
+    if package
+      .location
+      .components()
+      .any(|component| Path::new(component.as_os_str()).extension() == Some("zip".as_ref()))
+    {
+      return None;
+    }
+    Some(PnpResolution {
+      package_dir: package.location.clone(),
+      subpath: subpath.map(ToString::to_string),
+    })
+  }
+
+  /// Find the package that owns `path`, which is the one with the longest matched location.
+  fn find_locator(&self, path: &Path) -> Option<(&Locator, &PackageInfo)> {
+    path.ancestors().find_map(|dir| {
+      let locator = self.locators_by_location.get(dir)?;
+      Some((locator, self.packages.get(locator)?))
+    })
+  }
+}
+
+fn parse_dependency_target(name: &str, target: &Value) -> Option<Locator> {
+  match target {
+    Value::String(reference) => Some((Some(name.to_string()), Some(reference.clone()))),
+    // Aliased dependency, e.g. `["foo", ["bar", "npm:1.0.0"]]`
+    Value::Array(alias) => match alias.as_slice() {
+      [Value::String(alias_name), Value::String(reference)] => {
+        Some((Some(alias_name.clone()), Some(reference.clone())))
+      }
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Split `@scope/name/sub/path` into `("@scope/name", Some("sub/path"))`.
+fn parse_bare_specifier(specifier: &str) -> Option<(&str, Option<&str>)> {
+  if specifier.is_empty()
+    || specifier.starts_with(['.', '/', '#'])
+    || specifier.contains(':')
+    || Path::new(specifier).is_absolute()
+  {
+    return None;
+  }
+  let ident_len = if specifier.starts_with('@') {
+    let scope_end = specifier.find('/')?;
+    specifier[scope_end + 1..].find('/').map_or(specifier.len(), |i| scope_end + 1 + i)
+  } else {
+    specifier.find('/').unwrap_or(specifier.len())
+  };
+  let (ident, rest) = specifier.split_at(ident_len);
+  let subpath = rest.strip_prefix('/').filter(|s| !s.is_empty());
+  Some((ident, subpath))
+}
+
+/// `.pnp.cjs` inlines the data as `const RAW_RUNTIME_STATE =\n'{...}';`.
+fn extract_raw_runtime_state(content: &str) -> Option<String> {
+  let start = content.find("RAW_RUNTIME_STATE")?;
+  let rest = &content[start..];
+  let quote_start = rest.find('\'')? + 1;
+  let mut result = String::new();
+  let mut chars = rest[quote_start..].chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => result.push(chars.next()?),
+      '\'' => return Some(result),
+      _ => result.push(c),
+    }
+  }
+  None
+}
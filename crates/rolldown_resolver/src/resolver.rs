@@ -10,9 +10,14 @@ use std::{
 };
 use sugar_path::SugarPath;
 
+use crate::{
+  package_exports::PackageExportsHint,
+  pnp::{PnpManifest, PnpResolution},
+  tsconfig::{extends_specifiers, parse_tsconfig_json, TsconfigCompilerOptions},
+};
 use oxc_resolver::{
   EnforceExtension, PackageJson as OxcPackageJson, Resolution, ResolveError,
-  ResolveOptions as OxcResolverOptions, ResolverGeneric, TsconfigOptions,
+  ResolveOptions as OxcResolverOptions, ResolverGeneric, TsconfigOptions, TsconfigReferences,
 };
 
 #[derive(Debug)]
@@ -29,9 +34,19 @@ pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
   // Resolver for `new URL(..., import.meta.url)`
   new_url_resolver: ResolverGeneric<T>,
   package_json_cache: FxDashMap<PathBuf, Arc<PackageJson>>,
+  // Manifest of Yarn Plug'n'Play, only loaded if `ResolveOptions#yarn_pnp` is enabled
+  pnp_manifest: Option<PnpManifest>,
+  // Find the nearest `tsconfig.json` of each importer, enabled by `ResolveOptions#tsconfig_auto`
+  tsconfig_auto: bool,
+  // Directory -> the nearest `tsconfig.json`
+  tsconfig_lookup_cache: FxDashMap<PathBuf, Option<PathBuf>>,
+  // Resolvers that apply `paths` of a specific tsconfig
+  tsconfig_resolvers: FxDashMap<(PathBuf, ImportKind), Arc<ResolverGeneric<T>>>,
+  tsconfig_compiler_options_cache: FxDashMap<PathBuf, Arc<TsconfigCompilerOptions>>,
+  fs: T,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
   #[allow(clippy::too_many_lines)]
   pub fn new(raw_resolve: ResolveOptions, platform: Platform, cwd: PathBuf, fs: F) -> Self {
     let mut default_conditions = vec!["default".to_string()];
@@ -72,6 +87,12 @@ impl<F: FileSystem + Default> Resolver<F> {
       Platform::Browser | Platform::Neutral => false,
     };
 
+    // An explicit `tsconfig_filename` takes precedence over `tsconfig_auto`.
+    let tsconfig_auto =
+      raw_resolve.tsconfig_auto.unwrap_or(false) && raw_resolve.tsconfig_filename.is_none();
+    let pnp_manifest =
+      raw_resolve.yarn_pnp.unwrap_or(false).then(|| PnpManifest::find(&fs, &cwd)).flatten();
+
     let mut extension_alias = raw_resolve.extension_alias.clone().unwrap_or_default();
     impl_rewritten_file_extensions_via_extension_alias(&mut extension_alias);
 
@@ -80,7 +101,7 @@ impl<F: FileSystem + Default> Resolver<F> {
         let path = PathBuf::from(&p);
         TsconfigOptions {
           config_file: if path.is_relative() { cwd.join(path) } else { path },
-          references: oxc_resolver::TsconfigReferences::Disabled,
+          references: TsconfigReferences::Disabled,
         }
       }),
       alias: raw_resolve
@@ -139,7 +160,7 @@ impl<F: FileSystem + Default> Resolver<F> {
     };
 
     let default_resolver =
-      ResolverGeneric::new_with_file_system(fs, resolve_options_with_default_conditions);
+      ResolverGeneric::new_with_file_system(fs.clone(), resolve_options_with_default_conditions);
     let import_resolver =
       default_resolver.clone_with_options(resolve_options_with_import_conditions);
     let require_resolver =
@@ -155,6 +176,12 @@ impl<F: FileSystem + Default> Resolver<F> {
       css_resolver,
       new_url_resolver,
       package_json_cache: DashMap::default(),
+      pnp_manifest,
+      tsconfig_auto,
+      tsconfig_lookup_cache: DashMap::default(),
+      tsconfig_resolvers: DashMap::default(),
+      tsconfig_compiler_options_cache: DashMap::default(),
+      fs,
     }
   }
 
@@ -170,7 +197,7 @@ pub struct ResolveReturn {
   pub package_json: Option<Arc<PackageJson>>,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
   pub fn resolve(
     &self,
     importer: Option<&Path>,
@@ -178,13 +205,6 @@ impl<F: FileSystem + Default> Resolver<F> {
     import_kind: ImportKind,
     is_user_defined_entry: bool,
   ) -> anyhow::Result<Result<ResolveReturn, ResolveError>> {
-    let selected_resolver = match import_kind {
-      ImportKind::Import | ImportKind::DynamicImport => &self.import_resolver,
-      ImportKind::NewUrl => &self.new_url_resolver,
-      ImportKind::Require => &self.require_resolver,
-      ImportKind::AtImport | ImportKind::UrlImport => &self.css_resolver,
-    };
-
     let importer_dir = importer.and_then(|importer| importer.parent()).and_then(|inner| {
       if inner.components().next().is_none() {
         // Empty path `Path::new("")`
@@ -196,7 +216,17 @@ impl<F: FileSystem + Default> Resolver<F> {
 
     let context_dir = importer_dir.unwrap_or(self.cwd.as_path());
 
-    let mut resolution = selected_resolver.resolve(context_dir, specifier);
+    let tsconfig_resolver = self
+      .tsconfig_auto
+      .then(|| self.find_tsconfig(&self.cwd.join(context_dir)))
+      .flatten()
+      .map(|tsconfig_path| self.tsconfig_resolver(tsconfig_path, import_kind));
+    let selected_resolver =
+      tsconfig_resolver.as_deref().unwrap_or_else(|| self.select_resolver(import_kind));
+
+    let mut resolution = self
+      .resolve_with_pnp(selected_resolver, importer, specifier)
+      .unwrap_or_else(|| selected_resolver.resolve(context_dir, specifier));
 
     if resolution.is_err() && is_user_defined_entry {
       let is_specifier_path_like = specifier.starts_with('.') || specifier.starts_with('/');
@@ -231,6 +261,177 @@ impl<F: FileSystem + Default> Resolver<F> {
     }
   }
 
+  /// Resolve bare specifiers via the Yarn PnP manifest. `None` means the specifier isn't handled
+  /// by PnP and should be resolved from `node_modules`.
+  fn resolve_with_pnp(
+    &self,
+    selected_resolver: &ResolverGeneric<F>,
+    importer: Option<&Path>,
+    specifier: &str,
+  ) -> Option<Result<Resolution, ResolveError>> {
+    let pnp_manifest = self.pnp_manifest.as_ref()?;
+    let importer = importer.map_or_else(|| self.cwd.clone(), |importer| self.cwd.join(importer));
+    let PnpResolution { package_dir, subpath } = pnp_manifest.resolve(specifier, &importer)?;
+
+    // Packages laid out as `<dir>/node_modules/<name>` (e.g. `.yarn/unplugged/*`) are resolved from
+    // `<dir>` so `exports` of the package is respected.
+    if let Some(node_modules_dir) = package_dir
+      .ancestors()
+      .skip(1)
+      .take(2)
+      .find(|dir| dir.file_name().is_some_and(|name| name == "node_modules"))
+    {
+      let name = package_dir.strip_prefix(node_modules_dir).ok()?.to_slash()?;
+      let request = match &subpath {
+        Some(subpath) => format!("{name}/{subpath}"),
+        None => name.into_owned(),
+      };
+      return Some(selected_resolver.resolve(node_modules_dir.parent()?, &request));
+    }
+
+    let target = match subpath {
+      Some(subpath) => package_dir.join(subpath),
+      None => package_dir,
+    };
+    Some(selected_resolver.resolve(&self.cwd, target.to_str()?))
+  }
+
+  /// Explain why `subpath` is not exported by the package, which is reported by
+  /// `ResolveError::PackagePathNotExported`.
+  pub fn package_exports_hint(
+    &self,
+    subpath: &str,
+    package_json_path: &Path,
+    import_kind: ImportKind,
+  ) -> Option<PackageExportsHint> {
+    let selected_resolver = self.select_resolver(import_kind);
+    let content = self.fs.read_to_string(package_json_path).ok()?;
+    let package_json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let exports = selected_resolver.options().exports_fields.iter().find_map(|field_path| {
+      field_path.iter().try_fold(&package_json, |value, key| value.get(key))
+    })?;
+    Some(PackageExportsHint::new(exports, subpath, &selected_resolver.options().condition_names))
+  }
+
+  /// `compilerOptions` of the nearest tsconfig of the module, which are only available if
+  /// `ResolveOptions#tsconfig_auto` is enabled.
+  pub fn tsconfig_compiler_options(&self, path: &Path) -> Option<Arc<TsconfigCompilerOptions>> {
+    if !self.tsconfig_auto {
+      return None;
+    }
+    let tsconfig_path = self.find_tsconfig(self.cwd.join(path).parent()?)?;
+    Some(self.load_tsconfig_compiler_options(&tsconfig_path, 0))
+  }
+
+  /// Find `tsconfig.json` in `dir` or its ancestors. Modules in `node_modules` are not affected by tsconfig.
+  fn find_tsconfig(&self, dir: &Path) -> Option<PathBuf> {
+    if let Some(cached) = self.tsconfig_lookup_cache.get(dir) {
+      return cached.value().clone();
+    }
+    let found = if dir.components().any(|component| component.as_os_str() == "node_modules") {
+      None
+    } else {
+      let tsconfig_path = dir.join("tsconfig.json");
+      if self.fs.exists(&tsconfig_path) {
+        Some(tsconfig_path)
+      } else {
+        dir.parent().and_then(|parent| self.find_tsconfig(parent))
+      }
+    };
+    self.tsconfig_lookup_cache.insert(dir.to_path_buf(), found.clone());
+    found
+  }
+
+  /// A resolver that applies `paths` of the tsconfig, including the ones of its `extends` and `references`.
+  /// The underlying cache is shared with other resolvers, so each tsconfig is only parsed once.
+  fn tsconfig_resolver(
+    &self,
+    tsconfig_path: PathBuf,
+    import_kind: ImportKind,
+  ) -> Arc<ResolverGeneric<F>> {
+    let key = (tsconfig_path, import_kind);
+    if let Some(resolver) = self.tsconfig_resolvers.get(&key) {
+      return Arc::clone(resolver.value());
+    }
+    let base_resolver = self.select_resolver(import_kind);
+    let resolver = Arc::new(base_resolver.clone_with_options(OxcResolverOptions {
+      tsconfig: Some(TsconfigOptions {
+        config_file: key.0.clone(),
+        references: TsconfigReferences::Auto,
+      }),
+      ..base_resolver.options().clone()
+    }));
+    self.tsconfig_resolvers.insert(key, Arc::clone(&resolver));
+    resolver
+  }
+
+  fn load_tsconfig_compiler_options(
+    &self,
+    tsconfig_path: &Path,
+    depth: usize,
+  ) -> Arc<TsconfigCompilerOptions> {
+    if let Some(cached) = self.tsconfig_compiler_options_cache.get(tsconfig_path) {
+      return Arc::clone(cached.value());
+    }
+    let tsconfig = self
+      .fs
+      .read_to_string(tsconfig_path)
+      .ok()
+      .and_then(|content| parse_tsconfig_json(&content))
+      .unwrap_or_default();
+    let mut compiler_options =
+      tsconfig.get("compilerOptions").map(TsconfigCompilerOptions::from_json).unwrap_or_default();
+    // Guard against circular `extends`
+    if depth < 32 {
+      // Options of later `extends` override the earlier ones.
+      for specifier in extends_specifiers(&tsconfig).into_iter().rev() {
+        if let Some(extended_path) = self.resolve_tsconfig_extends(tsconfig_path, specifier) {
+          compiler_options.extend(&self.load_tsconfig_compiler_options(&extended_path, depth + 1));
+        }
+      }
+    }
+    let compiler_options = Arc::new(compiler_options);
+    self
+      .tsconfig_compiler_options_cache
+      .insert(tsconfig_path.to_path_buf(), Arc::clone(&compiler_options));
+    compiler_options
+  }
+
+  fn resolve_tsconfig_extends(&self, tsconfig_path: &Path, specifier: &str) -> Option<PathBuf> {
+    let tsconfig_dir = tsconfig_path.parent()?;
+    if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+      let path = tsconfig_dir.join(specifier).normalize();
+      if !self.fs.exists(&path) {
+        // `./tsconfig.base` refers to `./tsconfig.base.json`.
+        let mut path_with_json = path.clone().into_os_string();
+        path_with_json.push(".json");
+        return Some(PathBuf::from(path_with_json));
+      }
+      return Some(path);
+    }
+    // A package, e.g. `@tsconfig/node20/tsconfig.json` or `@tsconfig/node20`
+    self
+      .default_resolver
+      .clone_with_options(OxcResolverOptions {
+        description_files: vec![],
+        extensions: vec![".json".to_string()],
+        main_files: vec!["tsconfig.json".to_string()],
+        ..OxcResolverOptions::default()
+      })
+      .resolve(tsconfig_dir, specifier)
+      .ok()
+      .map(Resolution::into_path_buf)
+  }
+
+  fn select_resolver(&self, import_kind: ImportKind) -> &ResolverGeneric<F> {
+    match import_kind {
+      ImportKind::Import | ImportKind::DynamicImport => &self.import_resolver,
+      ImportKind::NewUrl => &self.new_url_resolver,
+      ImportKind::Require => &self.require_resolver,
+      ImportKind::AtImport | ImportKind::UrlImport => &self.css_resolver,
+    }
+  }
+
   fn cached_package_json(&self, oxc_pkg_json: &OxcPackageJson) -> Arc<PackageJson> {
     if let Some(v) = self.package_json_cache.get(&oxc_pkg_json.realpath) {
       Arc::clone(v.value())
@@ -0,0 +1,58 @@
+// `oxc_resolver` only keeps `baseUrl` and `paths` of a tsconfig. The `compilerOptions` that affect
+// how a file is transformed are read here, following `extends` in the same way as `tsc`.
+
+use serde_json::Value;
+
+/// `compilerOptions` of a tsconfig that are relevant to the TypeScript and JSX transforms.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TsconfigCompilerOptions {
+  /// `react`, `react-jsx`, `react-jsxdev`, `react-native` or `preserve`.
+  pub jsx: Option<String>,
+  pub jsx_factory: Option<String>,
+  pub jsx_fragment_factory: Option<String>,
+  pub jsx_import_source: Option<String>,
+  pub experimental_decorators: Option<bool>,
+}
+
+impl TsconfigCompilerOptions {
+  pub(crate) fn from_json(compiler_options: &Value) -> Self {
+    let get_string =
+      |key: &str| compiler_options.get(key).and_then(Value::as_str).map(ToString::to_string);
+    Self {
+      jsx: get_string("jsx").map(|jsx| jsx.to_ascii_lowercase()),
+      jsx_factory: get_string("jsxFactory"),
+      jsx_fragment_factory: get_string("jsxFragmentFactory"),
+      jsx_import_source: get_string("jsxImportSource"),
+      experimental_decorators: compiler_options
+        .get("experimentalDecorators")
+        .and_then(Value::as_bool),
+    }
+  }
+
+  /// Fill the options that are not set in this tsconfig with the ones of the extended tsconfig.
+  pub(crate) fn extend(&mut self, base: &Self) {
+    self.jsx = self.jsx.take().or_else(|| base.jsx.clone());
+    self.jsx_factory = self.jsx_factory.take().or_else(|| base.jsx_factory.clone());
+    self.jsx_fragment_factory =
+      self.jsx_fragment_factory.take().or_else(|| base.jsx_fragment_factory.clone());
+    self.jsx_import_source =
+      self.jsx_import_source.take().or_else(|| base.jsx_import_source.clone());
+    self.experimental_decorators = self.experimental_decorators.or(base.experimental_decorators);
+  }
+}
+
+/// Parse the content of a tsconfig, which allows comments and trailing commas.
+pub(crate) fn parse_tsconfig_json(content: &str) -> Option<Value> {
+  let mut content = content.to_string();
+  json_strip_comments::strip(&mut content).ok()?;
+  serde_json::from_str(&content).ok()
+}
+
+/// `extends` could be a single specifier or a list of specifiers since TypeScript 5.0.
+pub(crate) fn extends_specifiers(tsconfig: &Value) -> Vec<&str> {
+  match tsconfig.get("extends") {
+    Some(Value::String(specifier)) => vec![specifier.as_str()],
+    Some(Value::Array(specifiers)) => specifiers.iter().filter_map(Value::as_str).collect(),
+    _ => vec![],
+  }
+}
@@ -0,0 +1,69 @@
+// Executes an output chunk and prints what could be observed from outside as json, so the behavior
+// of different formats could be compared.
+//
+// Usage: node cross_format_runner.mjs <esm|cjs|iife> <entry> [iife-name] [...<global>=<external>]
+import fs from 'node:fs'
+import { createRequire } from 'node:module'
+import { pathToFileURL } from 'node:url'
+import util from 'node:util'
+import vm from 'node:vm'
+
+const [format, entry, iifeName, ...iifeGlobals] = process.argv.slice(2)
+
+const logs = []
+for (const method of ['log', 'info', 'warn', 'error', 'debug']) {
+  console[method] = (...args) => logs.push(`${method}: ${util.format(...args)}`)
+}
+
+function describe(value) {
+  if (typeof value === 'function') {
+    const kind = /^class[\s{]/.test(Function.prototype.toString.call(value))
+      ? 'class'
+      : 'function'
+    return `[${kind}]`
+  }
+  return util.inspect(value, { depth: 4, sorted: true, getters: true })
+}
+
+function describeExports(exports) {
+  if (exports == null) {
+    return {}
+  }
+  const described = {}
+  for (const key of Object.keys(exports).sort()) {
+    // The marker is an implementation detail of interop between formats.
+    if (key === '__esModule') continue
+    described[key] = describe(exports[key])
+  }
+  return described
+}
+
+let exports
+let error
+try {
+  switch (format) {
+    case 'esm':
+      exports = await import(pathToFileURL(entry).href)
+      break
+    case 'cjs':
+      exports = createRequire(entry)(entry)
+      break
+    case 'iife':
+      globalThis.require = createRequire(entry)
+      for (const pair of iifeGlobals) {
+        const [name, id] = pair.split(/=(.*)/s)
+        globalThis[name] = globalThis.require(id)
+      }
+      vm.runInThisContext(fs.readFileSync(entry, 'utf8'), { filename: entry })
+      exports = globalThis[iifeName]
+      break
+    default:
+      throw new Error(`Unknown format ${format}`)
+  }
+} catch (e) {
+  error = e instanceof Error ? `${e.name}: ${e.message}` : `thrown: ${describe(e)}`
+}
+
+process.stdout.write(
+  JSON.stringify({ exports: describeExports(exports), logs, error }, null, 2),
+)
@@ -0,0 +1,131 @@
+use std::{
+  fmt::Write as _,
+  path::Path,
+  process::Command,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, OutputFormat};
+use rolldown_common::{GlobalsOutputOption, Output};
+use rolldown_utils::ecmascript::legitimize_identifier_name;
+
+use crate::{integration_test::IntegrationTest, workspace};
+
+/// Formats that are expected to behave the same when being executed.
+const CROSS_FORMATS: [OutputFormat; 3] = [OutputFormat::Esm, OutputFormat::Cjs, OutputFormat::Iife];
+
+/// Used as `name` of iife outputs, so the runner could find their exports.
+const IIFE_NAME: &str = "__rolldown_cross_format_exports__";
+
+impl IntegrationTest {
+  /// Build `options` in every format of [CROSS_FORMATS], execute the entries and compare what
+  /// could be observed from outside: the exports, console output and thrown errors.
+  ///
+  /// Panics if the formats disagree with each other. Otherwise the observed behavior is recorded in
+  /// `behavior.snap`. The snapshot is taken from rolldown's own outputs, so it only catches changes
+  /// of the behavior. It doesn't tell whether the behavior matches esbuild or Rollup.
+  ///
+  /// The outputs are written to the target directory instead of the fixture.
+  pub async fn run_cross_format_execution(&self, options: BundlerOptions, test_folder_path: &Path) {
+    let out_dir = workspace::root_dir()
+      .join("target/cross_format_execution")
+      .join(test_folder_path.strip_prefix(workspace::root_dir()).unwrap_or(test_folder_path));
+    let mut observations = Vec::with_capacity(CROSS_FORMATS.len());
+    for format in CROSS_FORMATS {
+      let mut options = options.clone();
+      self.apply_test_defaults(&mut options);
+      options.format = Some(format);
+      options.file = None;
+      options.dir = Some(out_dir.join(format.to_string()).to_string_lossy().into_owned());
+      let externals = Arc::new(Mutex::new(vec![]));
+      if matches!(format, OutputFormat::Iife) {
+        options.name = Some(IIFE_NAME.to_string());
+        // Iife outputs read externals from globals. Record them, so the runner could provide them.
+        let externals = Arc::clone(&externals);
+        options.globals = Some(GlobalsOutputOption::Fn(Arc::new(move |id| {
+          externals.lock().expect("Failed to lock externals").push(id.to_string());
+          let name = legitimize_identifier_name(id).into_owned();
+          Box::pin(async move { Ok(name) })
+        })));
+      }
+      observations.push((format, Self::observe(options, &externals).await));
+    }
+
+    let (_, expected) = &observations[0];
+    if observations.iter().any(|(_, observed)| observed != expected) {
+      let report = observations
+        .iter()
+        .map(|(format, observed)| format!("⬇️⬇️ {format} ⬇️⬇️\n{observed}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+      panic!("Outputs behave differently between formats\n{report}⬆️⬆️ end ⬆️⬆️");
+    }
+
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path(test_folder_path);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.remove_input_file();
+    settings.set_omit_expression(true);
+    settings.bind(|| {
+      insta::assert_snapshot!("behavior", expected);
+    });
+  }
+
+  async fn observe(options: BundlerOptions, externals: &Mutex<Vec<String>>) -> String {
+    let format = options.format.expect("format should be set");
+    let mut bundler = Bundler::new(options);
+    let dist_folder = bundler.options().cwd.join(&bundler.options().out_dir);
+    if dist_folder.is_dir() {
+      std::fs::remove_dir_all(&dist_folder).expect("Failed to clean the output directory");
+    }
+    let output = bundler
+      .write()
+      .await
+      .unwrap_or_else(|errs| panic!("Failed to build in {format} format: {errs:#?}"));
+
+    // Make sure `.js` files are loaded in the expected module system regardless of the fixture.
+    let package_type = if matches!(format, OutputFormat::Esm) { "module" } else { "commonjs" };
+    std::fs::write(dist_folder.join("package.json"), format!("{{ \"type\": \"{package_type}\" }}"))
+      .expect("Failed to write package.json");
+
+    let runner = workspace::crate_dir("rolldown_testing").join("cross_format_runner.mjs");
+    let mut entries = output
+      .assets
+      .iter()
+      .filter_map(|output| match output {
+        Output::Chunk(chunk) if chunk.is_entry => Some(chunk.filename.as_str()),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    entries.sort_unstable();
+
+    let mut iife_globals = externals
+      .lock()
+      .expect("Failed to lock externals")
+      .iter()
+      .map(|id| format!("{}={id}", legitimize_identifier_name(id)))
+      .collect::<Vec<_>>();
+    iife_globals.sort_unstable();
+    iife_globals.dedup();
+
+    let mut observed = String::new();
+    for entry in entries {
+      let output = Command::new("node")
+        .arg(&runner)
+        .arg(format.to_string())
+        .arg(dist_folder.join(entry))
+        .arg(IIFE_NAME)
+        .args(&iife_globals)
+        .output()
+        .expect("Failed to run node");
+      let stdout = String::from_utf8_lossy(&output.stdout);
+      assert!(
+        output.status.success(),
+        "Failed to execute {entry} in {format} format\n{}",
+        String::from_utf8_lossy(&output.stderr)
+      );
+      writeln!(observed, "## {entry}\n\n```json\n{stdout}\n```").unwrap();
+    }
+    observed
+  }
+}
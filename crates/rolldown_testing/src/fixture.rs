@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use crate::{
   integration_test::{IntegrationTest, NamedBundlerOptions},
-  test_config::read_test_config,
+  test_config::read_test_config_json,
 };
 
 use rolldown_testing_config::TestConfig;
@@ -25,21 +25,30 @@ impl Fixture {
   }
 
   async fn run_inner(self) {
-    let TestConfig { config: mut options, meta, config_variants } =
-      read_test_config(&self.config_path);
+    let config_json = read_test_config_json(&self.config_path);
+    let TestConfig { config: options, meta, config_variants } =
+      serde_json::from_value(config_json.clone()).expect("Failed to parse test config file");
+    let raw_options = config_json.get("config").cloned().unwrap_or_default();
 
-    if options.cwd.is_none() {
-      options.cwd = Some(self.fixture_path.clone());
-    }
-
-    let configs = [NamedBundlerOptions { options: options.clone(), name: None }]
+    let mut configs = [NamedBundlerOptions { options, name: None }]
       .into_iter()
       .chain(config_variants.into_iter().map(|variant| NamedBundlerOptions {
-        options: variant.apply(&options),
+        options: variant.apply(&raw_options),
         name: Some(variant.to_string()),
       }))
       .collect::<Vec<_>>();
 
-    IntegrationTest::new(meta).run_multiple(configs, &self.fixture_path).await;
+    for NamedBundlerOptions { options, .. } in &mut configs {
+      if options.cwd.is_none() {
+        options.cwd = Some(self.fixture_path.clone());
+      }
+    }
+
+    let cross_format_options = meta.cross_format_execution.then(|| configs[0].options.clone());
+    let integration_test = IntegrationTest::new(meta);
+    integration_test.run_multiple(configs, &self.fixture_path).await;
+    if let Some(options) = cross_format_options {
+      integration_test.run_cross_format_execution(options, &self.fixture_path).await;
+    }
   }
 }
@@ -9,8 +9,8 @@ use std::{
 
 use anyhow::Context;
 use rolldown::{
-  plugin::__inner::SharedPluginable, BundleOutput, Bundler, BundlerOptions, IsExternal,
-  OutputFormat, Platform, SourceMapType,
+  plugin::__inner::SharedPluginable, BundleOutput, Bundler, BundlerBuilder, BundlerOptions,
+  IsExternal, OutputFormat, Platform, SourceMapType,
 };
 use rolldown_common::Output;
 use rolldown_error::{BuildDiagnostic, BuildResult, DiagnosticOptions};
@@ -67,8 +67,25 @@ impl IntegrationTest {
     plugins: Vec<SharedPluginable>,
   ) {
     self.apply_test_defaults(&mut options);
-
-    let mut bundler = Bundler::with_plugins(options, plugins);
+    let cwd = options.cwd.clone();
+
+    let mut bundler = match BundlerBuilder::default()
+      .with_options(options)
+      .with_plugins(plugins)
+      .build_async()
+      .await
+    {
+      Ok(bundler) => bundler,
+      Err(errs) => {
+        assert!(
+          self.test_meta.expect_error,
+          "Expected the bundling to be success, but got diagnosable errors: {errs:#?}"
+        );
+        let cwd = cwd.unwrap_or_else(|| std::env::current_dir().unwrap());
+        self.snapshot_bundle_output(BundleOutput::default(), errs.into_vec(), &cwd);
+        return;
+      }
+    };
 
     let cwd = bundler.options().cwd.clone();
 
@@ -186,7 +203,7 @@ impl IntegrationTest {
     });
   }
 
-  fn apply_test_defaults(&self, options: &mut BundlerOptions) {
+  pub(crate) fn apply_test_defaults(&self, options: &mut BundlerOptions) {
     if options.external.is_none() {
       options.external = Some(IsExternal::from_vec(vec!["node:assert".to_string()]));
     }
@@ -1,4 +1,5 @@
 pub mod bundler_options_presets;
+pub mod cross_format_execution;
 pub mod fixture;
 pub mod integration_test;
 pub mod test_config;
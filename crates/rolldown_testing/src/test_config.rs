@@ -24,6 +24,13 @@ static COMPILED_SCHEMA: LazyLock<Validator> = LazyLock::new(|| {
 });
 
 pub fn read_test_config(config_path: &std::path::Path) -> TestConfig {
+  serde_json::from_value(read_test_config_json(config_path))
+    .expect("Failed to parse test config file")
+}
+
+/// Read and validate the test config without deserializing it, which is needed to apply
+/// `configVariants`.
+pub fn read_test_config_json(config_path: &std::path::Path) -> serde_json::Value {
   let mut config_str = fs::read_to_string(config_path)
     .unwrap_or_else(|e| panic!("Failed to read config file in {config_path:?}. Got {e:?}"));
 
@@ -40,5 +47,5 @@ pub fn read_test_config(config_path: &std::path::Path) -> TestConfig {
   }
   assert!(msg.is_empty(), "Failed to validate test config {config_path:?}. Got {msg}");
 
-  serde_json::from_value(config_json).expect("Failed to parse test config file")
+  config_json
 }
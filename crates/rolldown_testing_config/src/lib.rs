@@ -3,50 +3,54 @@ use std::fmt::Display;
 use rolldown_common::{BundlerOptions, OutputExports, OutputFormat};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 
+/// A partial `BundlerOptions` that overrides the main config.
 #[derive(Deserialize, JsonSchema, Default)]
-#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[serde(transparent)]
 pub struct ConfigVariant {
-  pub format: Option<OutputFormat>,
-  pub extend: Option<bool>,
-  pub name: Option<String>,
-  pub exports: Option<OutputExports>,
+  #[schemars(with = "BundlerOptions")]
+  pub overrides: Map<String, Value>,
 }
 
 impl ConfigVariant {
-  pub fn apply(&self, config: &rolldown_common::BundlerOptions) -> BundlerOptions {
-    let mut config = config.clone();
-    if let Some(format) = &self.format {
-      config.format = Some(*format);
-    }
-    if let Some(exports) = &self.exports {
-      config.exports = Some(*exports);
+  /// Deep-merge the variant into `config`, which is the raw json of the main config.
+  ///
+  /// Objects are merged recursively, other values including arrays are replaced, and `null` resets
+  /// the option to its default value.
+  pub fn apply(&self, config: &Value) -> BundlerOptions {
+    let mut merged = config.clone();
+    if !merged.is_object() {
+      merged = Value::Object(Map::default());
     }
-    if let Some(extend) = &self.extend {
-      config.extend = Some(*extend);
-    }
-    if let Some(name) = &self.name {
-      config.name = Some(name.to_string());
+    deep_merge(&mut merged, &self.overrides);
+    serde_json::from_value(merged)
+      .unwrap_or_else(|e| panic!("Failed to apply config variant {self}. Got {e:?}"))
+  }
+}
+
+fn deep_merge(target: &mut Value, overrides: &Map<String, Value>) {
+  let Value::Object(target) = target else { unreachable!("target should be an object") };
+  for (key, value) in overrides {
+    match (target.get_mut(key), value) {
+      (_, Value::Null) => {
+        target.remove(key);
+      }
+      (Some(existing @ Value::Object(_)), Value::Object(value)) => deep_merge(existing, value),
+      _ => {
+        target.insert(key.clone(), value.clone());
+      }
     }
-    config
   }
 }
 
 impl Display for ConfigVariant {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut fields = vec![];
-    if let Some(format) = &self.format {
-      fields.push(format!("format: {format:?}"));
-    }
-    if let Some(extend) = &self.extend {
-      fields.push(format!("extend: {extend:?}"));
-    }
-    if let Some(name) = &self.name {
-      fields.push(format!("name: {name:?}"));
-    }
-    if let Some(exports) = &self.exports {
-      fields.push(format!("exports: {exports:?}"));
-    }
+    let mut fields = self
+      .overrides
+      .iter()
+      .map(|(key, value)| format!("{key}: {}", display_value(key, value)))
+      .collect::<Vec<_>>();
     fields.sort();
     if fields.is_empty() {
       write!(f, "()")
@@ -56,6 +60,21 @@ impl Display for ConfigVariant {
   }
 }
 
+/// `format` and `exports` are printed with `Debug`, e.g. `format: Cjs`, which keeps the names of the
+/// variants written before they could override any option.
+fn display_value(key: &str, value: &Value) -> String {
+  let debug = match key {
+    "format" => {
+      serde_json::from_value::<OutputFormat>(value.clone()).map(|v| format!("{v:?}")).ok()
+    }
+    "exports" => {
+      serde_json::from_value::<OutputExports>(value.clone()).map(|v| format!("{v:?}")).ok()
+    }
+    _ => None,
+  };
+  debug.unwrap_or_else(|| value.to_string())
+}
+
 #[derive(Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 #[allow(clippy::struct_excessive_bools, clippy::pub_underscore_fields)]
@@ -63,7 +82,8 @@ pub struct TestConfig {
   #[serde(default)]
   pub config: rolldown_common::BundlerOptions,
   #[serde(default)]
-  // Each config variant will be extended into the main config and executed.
+  /// Each config variant will be deep-merged into the main config and executed. The output of every
+  /// variant is appended to the snapshot.
   pub config_variants: Vec<ConfigVariant>,
   #[serde(default, flatten)]
   pub meta: TestMeta,
@@ -100,6 +120,12 @@ pub struct TestMeta {
   /// If `true`, the bundle will be called with `write()` instead of `generate()`.
   #[serde(default = "true_by_default")]
   pub write_to_disk: bool,
+  /// If `true`, the fixture is also built in `esm`, `cjs` and `iife` formats and each entry is executed.
+  /// The exports, console output and thrown errors must be the same for every format. They are
+  /// recorded in `behavior.snap` from rolldown's own outputs, which only catches regressions rather
+  /// than checking the behavior against esbuild or Rollup.
+  #[serde(default)]
+  pub cross_format_execution: bool,
 }
 
 impl Default for TestMeta {
@@ -0,0 +1,189 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::{LookupSpan, SpanRef};
+use tracing_subscriber::Layer;
+
+type SharedWriter = Arc<Mutex<BufWriter<File>>>;
+
+/// Writes a json object per line for every closed span and every event.
+///
+/// A span record looks like
+///
+/// ```json
+/// {"type":"span","name":"call_transform","target":"rolldown_plugin::plugin_driver::build_hooks","id":12,"parent_id":3,"parent":"transform_source","fields":{"plugin":"builtin:json","module_id":"/src/data.json"},"start_us":1043,"duration_us":120,"busy_us":98}
+/// ```
+///
+/// - `start_us` is relative to the creation of the layer.
+/// - `duration_us` is the time between creating and closing the span, including the time of awaiting.
+/// - `busy_us` is the time spent inside of the span.
+pub struct JsonLayer {
+  writer: SharedWriter,
+  started_at: Instant,
+}
+
+/// Flushes the buffered records of [JsonLayer] into the output file once dropped.
+pub struct JsonFlushGuard {
+  writer: SharedWriter,
+}
+
+impl JsonFlushGuard {
+  pub fn flush(&self) {
+    if let Ok(mut writer) = self.writer.lock() {
+      let _ = writer.flush();
+    }
+  }
+}
+
+impl Drop for JsonFlushGuard {
+  fn drop(&mut self) {
+    self.flush();
+  }
+}
+
+impl JsonLayer {
+  pub fn new(path: &Path) -> std::io::Result<(Self, JsonFlushGuard)> {
+    let writer = Arc::new(Mutex::new(BufWriter::new(File::create(path)?)));
+    Ok((
+      Self { writer: Arc::clone(&writer), started_at: Instant::now() },
+      JsonFlushGuard { writer },
+    ))
+  }
+
+  /// The default output file `./rolldown-trace-{timestamp}.jsonl`.
+  pub fn default_output_path() -> String {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_micros();
+    format!("./rolldown-trace-{timestamp}.jsonl")
+  }
+
+  fn write(&self, record: &Value) {
+    if let Ok(mut writer) = self.writer.lock() {
+      let _ = writeln!(writer, "{record}");
+    }
+  }
+}
+
+/// Stored in the extensions of every span to measure its durations.
+struct SpanTimings {
+  created_at: Instant,
+  entered_at: Option<Instant>,
+  busy: Duration,
+  fields: Map<String, Value>,
+}
+
+impl<S> Layer<S> for JsonLayer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else { return };
+    let mut fields = Map::new();
+    attrs.record(&mut JsonVisitor(&mut fields));
+    span.extensions_mut().insert(SpanTimings {
+      created_at: Instant::now(),
+      entered_at: None,
+      busy: Duration::ZERO,
+      fields,
+    });
+  }
+
+  fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else { return };
+    let mut extensions = span.extensions_mut();
+    if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+      values.record(&mut JsonVisitor(&mut timings.fields));
+    }
+  }
+
+  fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else { return };
+    let mut extensions = span.extensions_mut();
+    if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+      timings.entered_at = Some(Instant::now());
+    }
+  }
+
+  fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else { return };
+    let mut extensions = span.extensions_mut();
+    if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+      if let Some(entered_at) = timings.entered_at.take() {
+        timings.busy += entered_at.elapsed();
+      }
+    }
+  }
+
+  fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else { return };
+    let Some(timings) = span.extensions_mut().remove::<SpanTimings>() else { return };
+    let parent = span.parent();
+    self.write(&json!({
+      "type": "span",
+      "name": span.name(),
+      "target": span.metadata().target(),
+      "id": id.into_u64(),
+      "parent_id": parent.as_ref().map(|parent| parent.id().into_u64()),
+      "parent": parent.as_ref().map(SpanRef::name),
+      "fields": timings.fields,
+      "start_us": micros(timings.created_at.saturating_duration_since(self.started_at)),
+      "duration_us": micros(timings.created_at.elapsed()),
+      "busy_us": micros(timings.busy),
+    }));
+  }
+
+  fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+    let mut fields = Map::new();
+    event.record(&mut JsonVisitor(&mut fields));
+    let parent = ctx.event_span(event);
+    self.write(&json!({
+      "type": "event",
+      "level": event.metadata().level().as_str(),
+      "target": event.metadata().target(),
+      "parent_id": parent.as_ref().map(|parent| parent.id().into_u64()),
+      "parent": parent.as_ref().map(SpanRef::name),
+      "fields": fields,
+      "time_us": micros(self.started_at.elapsed()),
+    }));
+  }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn micros(duration: Duration) -> u64 {
+  duration.as_micros() as u64
+}
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+  fn record_f64(&mut self, field: &Field, value: f64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_i64(&mut self, field: &Field, value: i64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_u64(&mut self, field: &Field, value: u64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_bool(&mut self, field: &Field, value: bool) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+    self.0.insert(field.name().to_string(), json!(format!("{value:?}")));
+  }
+}
@@ -4,8 +4,18 @@
 ///   - https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html#directives
 /// - Using `RD_LOG=trace RD_LOG_OUTPUT=chrome-json` to collect tracing events into a json file.
 ///   - Using `RD_LOG_OUTPUT_STYLE=async` to record traces as a group of asynchronous operations.
+/// - Using `RD_LOG=trace RD_LOG_OUTPUT=json` to write closed spans and events as json lines, which
+///   could be used to build reports, e.g. which plugin transforms which files slowly.
+///   - Using `RD_LOG_OUTPUT_FILE=trace.jsonl` to change the output file. Defaults to `./rolldown-trace-{timestamp}.jsonl`.
+///   - Per module spans are `resolve_id_with_plugins`, `load_source`, `transform_source`,
+///     `parse_to_ecma_ast` and `scan_ast`. Hooks of each plugin are recorded as `call_resolve_id`,
+///     `call_load` and `call_transform` with a `plugin` field.
+///   - Stages are recorded as `scan`, `link` and `generate` spans under `rolldown::stages`.
+mod json_layer;
+
 use std::sync::atomic::AtomicBool;
 
+pub use json_layer::{JsonFlushGuard, JsonLayer};
 use tracing_chrome::ChromeLayerBuilder;
 use tracing_chrome::FlushGuard;
 use tracing_chrome::TraceStyle;
@@ -16,10 +26,26 @@ use tracing_subscriber::EnvFilter;
 
 static LOG_ENV_NAME: &str = "RD_LOG";
 static LOG_OUTPUT_ENV_NAME: &str = "RD_LOG_OUTPUT";
+static LOG_OUTPUT_FILE_ENV_NAME: &str = "RD_LOG_OUTPUT_FILE";
 
 static IS_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
-pub fn try_init_tracing() -> Option<FlushGuard> {
+/// Keeps the output file of the tracing subscriber being written until dropped.
+pub enum TracingGuard {
+  Chrome(FlushGuard),
+  Json(JsonFlushGuard),
+}
+
+impl TracingGuard {
+  pub fn flush(&self) {
+    match self {
+      Self::Chrome(guard) => guard.flush(),
+      Self::Json(guard) => guard.flush(),
+    }
+  }
+}
+
+pub fn try_init_tracing() -> Option<TracingGuard> {
   if std::env::var(LOG_ENV_NAME).is_err() {
     // tracing will slow down the bundling process, so we only enable it when `LOG` is set.
     return None;
@@ -38,19 +64,37 @@ pub fn try_init_tracing() -> Option<FlushGuard> {
         if output_mode == "chrome-json" { TraceStyle::Async } else { TraceStyle::Threaded };
       let (chrome_layer, guard) = ChromeLayerBuilder::new().trace_style(trace_style).build();
       tracing_subscriber::registry().with(env_filter).with(chrome_layer).init();
-      Some(guard)
+      Some(TracingGuard::Chrome(guard))
     }
     "json" => {
-      // We gonna use this feature to implement something like https://github.com/antfu-collective/vite-plugin-inspect
-      unimplemented!()
+      let path = std::env::var(LOG_OUTPUT_FILE_ENV_NAME)
+        .unwrap_or_else(|_| JsonLayer::default_output_path());
+      match JsonLayer::new(path.as_ref()) {
+        Ok((json_layer, guard)) => {
+          tracing_subscriber::registry().with(env_filter).with(json_layer).init();
+          Some(TracingGuard::Json(guard))
+        }
+        Err(err) => {
+          // Failing to write the debug output shouldn't stop bundling.
+          eprintln!(
+            "Failed to create tracing output file {path}: {err}. Tracing to stdout instead."
+          );
+          init_stdout_tracing(env_filter);
+          None
+        }
+      }
     }
     _ => {
-      tracing_subscriber::registry()
-        .with(env_filter)
-        .with(fmt::layer().pretty().with_span_events(FmtSpan::CLOSE | FmtSpan::ENTER))
-        .init();
-      tracing::debug!("Tracing initialized");
+      init_stdout_tracing(env_filter);
       None
     }
   }
 }
+
+fn init_stdout_tracing(env_filter: EnvFilter) {
+  tracing_subscriber::registry()
+    .with(env_filter)
+    .with(fmt::layer().pretty().with_span_events(FmtSpan::CLOSE | FmtSpan::ENTER))
+    .init();
+  tracing::debug!("Tracing initialized");
+}
@@ -0,0 +1,47 @@
+use rolldown_tracing::JsonLayer;
+use serde_json::Value;
+use tracing_subscriber::prelude::*;
+
+#[test]
+fn writes_spans_and_events_as_json_lines() {
+  let path = std::env::temp_dir().join(format!("rolldown_tracing_{}.jsonl", std::process::id()));
+  let (layer, guard) = JsonLayer::new(&path).unwrap();
+  tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+    let outer = tracing::info_span!("transform_source", module_id = "/src/main.js");
+    let _outer = outer.enter();
+    let inner = tracing::info_span!("call_transform", plugin = "builtin:json");
+    inner.in_scope(|| tracing::info!(cached = false, "transformed"));
+  });
+  drop(guard);
+
+  let records = std::fs::read_to_string(&path)
+    .unwrap()
+    .lines()
+    .map(|line| serde_json::from_str::<Value>(line).unwrap())
+    .collect::<Vec<_>>();
+  std::fs::remove_file(&path).unwrap();
+
+  let [event, inner, outer] = records.as_slice() else {
+    panic!("Expected an event and two spans, got {records:#?}");
+  };
+
+  assert_eq!(event["type"], "event");
+  assert_eq!(event["level"], "INFO");
+  assert_eq!(event["parent"], "call_transform");
+  assert_eq!(event["fields"]["message"], "transformed");
+  assert_eq!(event["fields"]["cached"], false);
+  assert!(event["time_us"].is_u64());
+
+  assert_eq!(inner["type"], "span");
+  assert_eq!(inner["name"], "call_transform");
+  assert_eq!(inner["fields"]["plugin"], "builtin:json");
+  assert_eq!(inner["parent"], "transform_source");
+  assert_eq!(inner["parent_id"], outer["id"]);
+  for key in ["start_us", "duration_us", "busy_us"] {
+    assert!(inner[key].is_u64(), "`{key}` should be a number");
+  }
+
+  assert_eq!(outer["name"], "transform_source");
+  assert_eq!(outer["fields"]["module_id"], "/src/main.js");
+  assert_eq!(outer["parent"], Value::Null);
+}
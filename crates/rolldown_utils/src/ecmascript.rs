@@ -56,6 +56,24 @@ pub fn property_access_str(obj: &str, prop: &str) -> String {
   }
 }
 
+const BASE54_CHARS: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+/// The `n`-th shortest identifier name, used for mangling. Reserved words are not filtered out.
+/// Port from https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1041-L1051
+pub fn base54(n: usize) -> String {
+  let mut num = n;
+  let mut ret = String::new();
+  // Digits are not allowed as the first character
+  ret.push(BASE54_CHARS[num % 54] as char);
+  num /= 54;
+  while num > 0 {
+    num -= 1;
+    ret.push(BASE54_CHARS[num % 64] as char);
+    num /= 64;
+  }
+  ret
+}
+
 #[test]
 fn test_is_validate_identifier_name() {
   assert!(is_validate_identifier_name("foo"));
@@ -71,6 +89,14 @@ fn test_legitimize_identifier_name() {
   assert_eq!(legitimize_identifier_name("111a"), "_111a");
 }
 
+#[test]
+fn test_base54() {
+  assert_eq!(base54(0), "a");
+  assert_eq!(base54(53), "_");
+  assert_eq!(base54(54), "aa");
+  assert_eq!(base54(55), "ba");
+}
+
 pub fn is_relative_specifier(specifier: &str) -> bool {
   // `Path::is_relative` is not used here because it consider implicit relative path as relative path. such
   // as `Path::new("foo.txt")` is considered as a relative path.
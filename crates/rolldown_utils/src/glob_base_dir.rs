@@ -0,0 +1,6 @@
+/// The directory to start walking from, which is the part of the absolute `pattern` before the first
+/// glob syntax, e.g. `/root/src` for `/root/src/**/*.js`.
+pub fn glob_base_dir(pattern: &str) -> &str {
+  let static_part = &pattern[..pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len())];
+  static_part.rfind('/').map_or("", |index| &pattern[..index.max(1)])
+}
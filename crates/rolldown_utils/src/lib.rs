@@ -7,6 +7,7 @@ pub mod dataurl;
 pub mod debug;
 pub mod ecmascript;
 pub mod futures;
+pub mod glob_base_dir;
 pub mod global_reference;
 pub mod indexmap;
 pub mod light_guess;
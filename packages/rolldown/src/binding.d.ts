@@ -63,9 +63,10 @@ export declare class BindingNormalizedOptions {
   get globals(): Record<string, string> | undefined
   get hashCharacters(): 'base64' | 'base36' | 'hex'
   get sourcemapDebugIds(): boolean
-  get minify(): boolean
+  get minify(): boolean | BindingMinifyOptions
   get polyfillRequire(): boolean
   get comments(): 'none' | 'preserve-legal'
+  get legalComments(): 'none' | 'inline' | 'eof' | 'linked' | 'external'
 }
 
 export declare class BindingOutputAsset {
@@ -97,6 +98,7 @@ export declare class BindingOutputChunk {
 export declare class BindingOutputs {
   get chunks(): Array<BindingOutputChunk>
   get assets(): Array<BindingOutputAsset>
+  get pluginTimings(): BindingPluginTimings | null
   get errors(): Array<Error | BindingError>
 }
 
@@ -104,10 +106,13 @@ export declare class BindingPluginContext {
   load(specifier: string, sideEffects: BindingHookSideEffects | undefined, fn: () => void): Promise<void>
   resolve(specifier: string, importer?: string | undefined | null, extraOptions?: BindingPluginContextResolveOptions | undefined | null): Promise<BindingPluginContextResolvedId | null>
   emitFile(file: BindingEmittedAsset): string
+  emitChunk(file: BindingEmittedChunk): string
   getFileName(referenceId: string): string
   getModuleInfo(moduleId: string): BindingModuleInfo | null
   getModuleIds(): Array<string>
   addWatchFile(file: string): void
+  /** Returns the ESTree-compatible AST as JSON. Positions are utf16 indices of `code`, like in js strings. */
+  parse(code: string, options?: BindingPluginContextParseOptions | undefined | null): string
 }
 
 export declare class BindingRenderedModule {
@@ -236,7 +241,22 @@ export interface BindingBundlerOptions {
 }
 
 export interface BindingChecksOptions {
-  circularDependency?: boolean
+  circularDependency?: boolean | BindingCircularDependencyOptions
+  sizeBudgets?: BindingSizeBudgetsOptions
+}
+
+export type BindingCircularDependencyMode =  'warn'|
+'errorOnUnsafe'|
+'error';
+
+export interface BindingCircularDependencyOptions {
+  ignore?: Array<string>
+  mode?: BindingCircularDependencyMode
+}
+
+export interface BindingChunkSizeBudget {
+  pattern: string
+  limit: BindingSizeLimit
 }
 
 export interface BindingEmittedAsset {
@@ -246,8 +266,17 @@ export interface BindingEmittedAsset {
   source: BindingAssetSource
 }
 
+export interface BindingEmittedChunk {
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+  preserveSignature?: 'strict' | 'allow-extension' | 'exports-only' | 'false'
+}
+
 export interface BindingExperimentalOptions {
   strictExecutionOrder?: boolean
+  lazySharedModules?: boolean
   disableLiveBindings?: boolean
   viteMode?: boolean
   resolveNewUrlToAsset?: boolean
@@ -291,6 +320,28 @@ export interface BindingHookRenderChunkOutput {
   map?: BindingSourcemap
 }
 
+export interface BindingHookRenderDynamicImportArgs {
+  moduleId: string
+  targetModuleId: string
+  chunkId: string
+  targetChunkId?: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+}
+
+export interface BindingHookRenderDynamicImportOutput {
+  left: string
+  right: string
+}
+
+export interface BindingHookResolveFileUrlArgs {
+  referenceId: string
+  fileName: string
+  relativePath: string
+  moduleId: string
+  chunkId: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+}
+
 export interface BindingHookResolveIdExtraArgs {
   custom?: number
   isEntry: boolean
@@ -303,6 +354,13 @@ export interface BindingHookResolveIdOutput {
   sideEffects?: BindingHookSideEffects
 }
 
+export interface BindingHookResolveImportMetaArgs {
+  property?: string
+  moduleId: string
+  chunkId: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+}
+
 export declare enum BindingHookSideEffects {
   True = 0,
   False = 1,
@@ -355,6 +413,7 @@ export interface BindingInputOptions {
   watch?: BindingWatchOption
   keepNames?: boolean
   checks?: BindingChecksOptions
+  pluginTimings?: boolean
 }
 
 export interface BindingJsonPluginConfig {
@@ -413,6 +472,18 @@ export interface BindingModules {
   idToIndex: Record<string, number>
 }
 
+export interface BindingMinifyOptions {
+  compress?: boolean
+  mangle?: boolean
+  whitespace?: boolean
+  dropConsole?: boolean
+  dropDebugger?: boolean
+  pureFuncs?: Array<string>
+  keepFnames?: boolean
+  keepClassnames?: boolean
+  target?: string
+}
+
 export interface BindingModuleSideEffectsRule {
   test?: RegExp | undefined
   sideEffects: boolean
@@ -450,9 +521,10 @@ export interface BindingOutputOptions {
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapDebugIds?: boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
-  minify?: boolean
+  minify?: boolean | BindingMinifyOptions
   advancedChunks?: BindingAdvancedChunksOptions
   comments?: 'none' | 'preserve-legal'
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external'
   polyfillRequire?: boolean
   target?: string
 }
@@ -462,6 +534,11 @@ export interface BindingPluginContextResolvedId {
   external: boolean
 }
 
+export interface BindingPluginContextParseOptions {
+  typescript?: boolean
+  jsx?: boolean
+}
+
 export interface BindingPluginContextResolveOptions {
   importKind?: 'import' | 'dynamic-import' | 'require-call'
   skipSelf?: boolean
@@ -472,6 +549,14 @@ export interface BindingPluginHookMeta {
   order?: BindingPluginOrder
 }
 
+export interface BindingPluginHookTiming {
+  plugin: string
+  hook: string
+  calls: number
+  /** In milliseconds. */
+  duration: number
+}
+
 export interface BindingPluginOptions {
   name: string
   buildStart?: (ctx: BindingPluginContext, opts: BindingNormalizedOptions) => MaybePromise<VoidNullable>
@@ -491,6 +576,12 @@ export interface BindingPluginOptions {
   moduleParsedMeta?: BindingPluginHookMeta
   buildEnd?: (ctx: BindingPluginContext, error?: (Error | BindingError)[]) => MaybePromise<VoidNullable>
   buildEndMeta?: BindingPluginHookMeta
+  renderDynamicImport?: (ctx: BindingPluginContext, args: BindingHookRenderDynamicImportArgs) => MaybePromise<VoidNullable<BindingHookRenderDynamicImportOutput>>
+  renderDynamicImportMeta?: BindingPluginHookMeta
+  resolveFileUrl?: (ctx: BindingPluginContext, args: BindingHookResolveFileUrlArgs) => MaybePromise<VoidNullable<string>>
+  resolveFileUrlMeta?: BindingPluginHookMeta
+  resolveImportMeta?: (ctx: BindingPluginContext, args: BindingHookResolveImportMetaArgs) => MaybePromise<VoidNullable<string>>
+  resolveImportMetaMeta?: BindingPluginHookMeta
   renderChunk?: (ctx: BindingPluginContext, code: string, chunk: RenderedChunk, opts: BindingNormalizedOptions) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>
   renderChunkMeta?: BindingPluginHookMeta
   augmentChunkHash?: (ctx: BindingPluginContext, chunk: RenderedChunk) => MaybePromise<void | string>
@@ -524,6 +615,14 @@ export declare enum BindingPluginOrder {
   Post = 1
 }
 
+export interface BindingPluginTimings {
+  /** Sorted by `duration` in descending order. */
+  hooks: Array<BindingPluginHookTiming>
+  dominantPlugins: Array<string>
+  /** The timings formatted as a table. */
+  summary: string
+}
+
 export interface BindingPluginWithIndex {
   index: number
   plugin: BindingPluginOptions
@@ -549,6 +648,25 @@ export interface BindingResolveOptions {
   modules?: Array<string>
   symlinks?: boolean
   tsconfigFilename?: string
+  tsconfigAuto?: boolean
+  yarnPnp?: boolean
+}
+
+export interface BindingSizeBudgetsOptions {
+  entry?: BindingSizeLimit
+  chunks?: Array<BindingChunkSizeBudget>
+  initial?: BindingSizeLimit
+  severity?: BindingSizeBudgetSeverity
+}
+
+export type BindingSizeBudgetSeverity =  'warning'|
+'error';
+
+/** Sizes are in bytes. */
+export interface BindingSizeLimit {
+  raw?: number
+  gzip?: number
+  brotli?: number
 }
 
 export interface BindingSourcemap {
@@ -30,6 +30,10 @@ export function dynamicImportVarsPlugin(): BuiltinPlugin {
   return new BuiltinPlugin('builtin:dynamic-import-vars')
 }
 
+/**
+ * `import.meta.glob` is evaluated without this plugin. It's only needed to change `root` or
+ * `restoreQueryExtension`.
+ */
 export function importGlobPlugin(
   config?: BindingGlobImportPluginConfig,
 ): BuiltinPlugin {
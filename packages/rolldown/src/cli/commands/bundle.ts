@@ -160,6 +160,12 @@ async function bundleInner(
   result.forEach(printBundleOutputPretty)
   logger.log(``)
 
+  for (const output of result) {
+    if (output.pluginTimings) {
+      logger.log(output.pluginTimings.summary)
+    }
+  }
+
   const endTime = performance.now()
   const duration = endTime - startTime
   // If the build time is more than 1s, we should display it in seconds.
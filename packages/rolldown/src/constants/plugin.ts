@@ -21,6 +21,9 @@ export const ENUMERATED_OUTPUT_PLUGIN_HOOK_NAMES = [
   'renderChunk',
   'renderStart',
   'renderError',
+  'renderDynamicImport',
+  'resolveFileUrl',
+  'resolveImportMeta',
   'writeBundle',
   'generateBundle',
 ] as const
@@ -103,4 +106,7 @@ export const DEFINED_HOOK_NAMES: DefinedHookNames = {
   [ENUMERATED_PLUGIN_HOOK_NAMES[20]]: ENUMERATED_PLUGIN_HOOK_NAMES[20],
   [ENUMERATED_PLUGIN_HOOK_NAMES[21]]: ENUMERATED_PLUGIN_HOOK_NAMES[21],
   [ENUMERATED_PLUGIN_HOOK_NAMES[22]]: ENUMERATED_PLUGIN_HOOK_NAMES[22],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[23]]: ENUMERATED_PLUGIN_HOOK_NAMES[23],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[24]]: ENUMERATED_PLUGIN_HOOK_NAMES[24],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[25]]: ENUMERATED_PLUGIN_HOOK_NAMES[25],
 } as const
@@ -1,5 +1,7 @@
 import {
   RolldownOutput,
+  PluginHookTiming,
+  PluginTimings,
   OutputAsset,
   OutputChunk,
   RenderedChunk,
@@ -11,7 +13,11 @@ import type {
   ExternalOption,
   JsxOptions,
 } from './options/input-options'
-import type { ModuleFormat, OutputOptions } from './options/output-options'
+import type {
+  MinifyOptions,
+  ModuleFormat,
+  OutputOptions,
+} from './options/output-options'
 import type { RolldownOptions } from './types/rolldown-options'
 import type {
   AsyncPluginHooks,
@@ -25,8 +31,11 @@ import type {
   ParallelPluginHooks,
   PartialResolvedId,
   Plugin,
+  RenderDynamicImportOptions,
   RolldownPlugin,
+  ResolveFileUrlOptions,
   ResolveIdResult,
+  ResolveImportMetaOptions,
   ResolvedId,
   SourceDescription,
   TransformResult,
@@ -39,6 +48,7 @@ import { ConfigExport } from './types/config-export'
 import { RolldownBuild } from './api/rolldown/rolldown-build'
 import {
   EmittedAsset,
+  EmittedChunk,
   EmittedFile,
   PluginContext,
 } from './plugin/plugin-context'
@@ -67,11 +77,14 @@ export type {
   OutputChunk,
   RolldownOptions,
   RolldownOutput,
+  PluginTimings,
+  PluginHookTiming,
   RolldownBuild,
   InputOptions,
   NormalizedInputOptions,
   OutputOptions,
   NormalizedOutputOptions,
+  MinifyOptions,
   Plugin,
   RolldownPlugin,
   DefineParallelPluginResult,
@@ -85,6 +98,9 @@ export type {
   LoadResult,
   TransformResult,
   ResolveIdResult,
+  RenderDynamicImportOptions,
+  ResolveFileUrlOptions,
+  ResolveImportMetaOptions,
   PluginContext,
   TransformPluginContext,
   ObjectHook,
@@ -99,6 +115,7 @@ export type {
   MinimalPluginContext,
   EmittedFile,
   EmittedAsset,
+  EmittedChunk,
   CustomPluginOptions,
   AsyncPluginHooks,
   ParallelPluginHooks,
@@ -27,6 +27,8 @@ export type ModuleTypes = Record<
   | 'ts'
   | 'tsx'
   | 'json'
+  | 'yaml'
+  | 'toml'
   | 'text'
   | 'base64'
   | 'dataurl'
@@ -58,10 +60,57 @@ export interface WatchOptions {
 
 export interface ChecksOptions {
   /**
-   * Wether to emit warnings when detecting circular dependencies.
+   * Wether to emit warnings when detecting circular dependencies. Use the object form to ignore cycles or to fail the build.
    * @default false
    */
-  circularDependency?: boolean
+  circularDependency?: boolean | CircularDependencyOptions
+  /**
+   * Report output files exceeding the given sizes. Each report contains the size, the limit and the largest modules of the output.
+   */
+  sizeBudgets?: SizeBudgetsOptions
+}
+
+export interface CircularDependencyOptions {
+  /**
+   * Cycles containing a module whose stable id matches one of these globs are ignored, e.g. `node_modules/**`.
+   */
+  ignore?: string[]
+  /**
+   * - `warn`: report every cycle as a warning.
+   * - `errorOnUnsafe`: fail the build for cycles that cross chunk boundaries or contain modules that are wrapped or use top-level await. Other cycles are reported as warnings.
+   * - `error`: fail the build for every cycle.
+   * @default 'warn'
+   */
+  mode?: 'warn' | 'errorOnUnsafe' | 'error'
+}
+
+/**
+ * Sizes are in bytes. Compressed sizes are only computed if they have a limit.
+ */
+export interface SizeLimit {
+  raw?: number
+  gzip?: number
+  brotli?: number
+}
+
+export interface SizeBudgetsOptions {
+  /**
+   * Limits of every entry chunk.
+   */
+  entry?: SizeLimit
+  /**
+   * Limits of output files whose filename matches the glob `pattern`, e.g. `vendor-*.js`.
+   */
+  chunks?: { pattern: string; limit: SizeLimit }[]
+  /**
+   * Limits of the initial load of every entry chunk, which is the entry chunk and all chunks it imports statically.
+   */
+  initial?: SizeLimit
+  /**
+   * Whether to report breaches as warnings, or to fail the build.
+   * @default 'warning'
+   */
+  severity?: 'warning' | 'error'
 }
 
 export interface InputOptions {
@@ -86,6 +135,18 @@ export interface InputOptions {
     modules?: string[]
     symlinks?: boolean
     tsconfigFilename?: string
+    /**
+     * Use the nearest `tsconfig.json` of each importer, following its `extends` and `references`,
+     * instead of a single `tsconfigFilename` for the whole build. Ignored if `tsconfigFilename` is set.
+     *
+     * `paths` and `baseUrl` are applied when resolving, and `jsx`, `jsxFactory`, `jsxFragmentFactory`,
+     * `jsxImportSource` and `experimentalDecorators` are applied when transforming the module.
+     */
+    tsconfigAuto?: boolean
+    /**
+     * Resolve bare specifiers through the Yarn Plug'n'Play manifest (`.pnp.data.json` or `.pnp.cjs`).
+     */
+    yarnPnp?: boolean
   }
   cwd?: string
   /**
@@ -117,6 +178,7 @@ export interface InputOptions {
   experimental?: {
     enableComposingJsPlugins?: boolean
     strictExecutionOrder?: boolean
+    lazySharedModules?: boolean
     disableLiveBindings?: boolean
     viteMode?: boolean
     resolveNewUrlToAsset?: boolean
@@ -160,6 +222,10 @@ export interface InputOptions {
   dropLabels?: string[]
   keepNames?: boolean
   checks?: ChecksOptions
+  /**
+   * Record calls and time of each hook of each plugin. The summary is available as `pluginTimings` of the output.
+   */
+  pluginTimings?: boolean
 }
 
 interface OverwriteInputOptionsForCli {
@@ -1,5 +1,5 @@
 import { unsupported } from '../utils/misc'
-import type { BindingNormalizedOptions } from '../binding'
+import type { BindingMinifyOptions, BindingNormalizedOptions } from '../binding'
 import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
@@ -37,8 +37,9 @@ export interface NormalizedOutputOptions {
   sourcemapDebugIds: boolean
   sourcemapIgnoreList: SourcemapIgnoreListOption | undefined
   sourcemapPathTransform: SourcemapPathTransformOption | undefined
-  minify: boolean
+  minify: false | BindingMinifyOptions
   comments: 'none' | 'preserve-legal'
+  legalComments: 'none' | 'inline' | 'eof' | 'linked' | 'external'
   polyfillRequire: boolean
 }
 
@@ -165,7 +166,7 @@ export class NormalizedOutputOptionsImpl implements NormalizedOutputOptions {
     return mapFunctionOption(void 0, 'sourcemapPathTransform')
   }
 
-  get minify(): boolean {
+  get minify(): false | BindingMinifyOptions {
     return this.inner.minify
   }
 
@@ -173,6 +174,10 @@ export class NormalizedOutputOptionsImpl implements NormalizedOutputOptions {
     return this.inner.comments
   }
 
+  get legalComments(): 'none' | 'inline' | 'eof' | 'linked' | 'external' {
+    return this.inner.legalComments
+  }
+
   get polyfillRequire(): boolean {
     return this.inner.polyfillRequire
   }
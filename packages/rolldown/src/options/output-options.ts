@@ -37,6 +37,44 @@ export type ESTarget =
   | 'es2024'
   | 'esnext'
 
+export interface MinifyOptions {
+  /** @default true */
+  compress?: boolean
+  /** @default true */
+  mangle?: boolean
+  /**
+   * Remove whitespace and comments.
+   * @default true
+   */
+  whitespace?: boolean
+  /**
+   * Remove `console.*` calls whose result is unused. Only works with `compress`.
+   * @default false
+   */
+  dropConsole?: boolean
+  /**
+   * Remove `debugger;` statements. Only works with `compress`.
+   * @default true
+   */
+  dropDebugger?: boolean
+  /**
+   * Calls of these functions are considered side effect free and removed if their result is unused, e.g. `['console.info', 'assert']`. Only works with `compress`.
+   */
+  pureFuncs?: string[]
+  /**
+   * Preserve the `name` of functions. Defaults to `keepNames`.
+   */
+  keepFnames?: boolean
+  /**
+   * Preserve the `name` of classes. Defaults to `keepNames`.
+   */
+  keepClassnames?: boolean
+  /**
+   * The syntax level the minifier is allowed to output. Modules are lowered to it if it is lower than `target`. Defaults to `target`.
+   */
+  target?: ESTarget
+}
+
 export interface OutputOptions {
   dir?: string
   file?: string
@@ -66,7 +104,11 @@ export interface OutputOptions {
   chunkFileNames?: string | ChunkFileNamesFunction
   cssEntryFileNames?: string | ChunkFileNamesFunction
   cssChunkFileNames?: string | ChunkFileNamesFunction
-  minify?: boolean
+  /**
+   * `true` enables all the steps of the minifier. Use an object to control them separately.
+   * Modules are minified before chunks are rendered, so code added by `renderChunk` hooks is not minified.
+   */
+  minify?: boolean | MinifyOptions
   name?: string
   globals?: Record<string, string> | GlobalsFunction
   externalLiveBindings?: boolean
@@ -89,6 +131,20 @@ export interface OutputOptions {
    * - `preserve-legal`: preserve comments that contain `@license`, `@preserve` or starts with `//!` `/*!`
    */
   comments?: 'none' | 'preserve-legal'
+  /**
+   * Control where legal comments go. Legal comments contain `@license`, `@preserve` or start with `//!` `/*!`.
+   *
+   * - `none`: remove legal comments
+   * - `inline`: keep legal comments in place, if `comments` allows it
+   * - `eof`: move the legal comments of all modules to the end of the chunk
+   * - `linked`: move legal comments to `[chunk].LEGAL.txt` and link to it with a comment at the end of the chunk
+   * - `external`: move legal comments to `[chunk].LEGAL.txt` without linking to it
+   *
+   * Extracted legal comments are deduplicated per chunk.
+   *
+   * @default 'inline'
+   */
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external'
   plugins?: RolldownOutputPluginOption
   polyfillRequire?: boolean
   target?: ESTarget
@@ -114,6 +114,88 @@ export function bindingifyAugmentChunkHash(
   }
 }
 
+export function bindingifyRenderDynamicImport(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['renderDynamicImport']> {
+  const hook = args.plugin.renderDynamicImport
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, options) => {
+      const ret = await handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        options,
+      )
+      return ret ?? undefined
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
+export function bindingifyResolveFileUrl(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['resolveFileUrl']> {
+  const hook = args.plugin.resolveFileUrl
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, options) => {
+      const ret = await handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        options,
+      )
+      return ret ?? undefined
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
+export function bindingifyResolveImportMeta(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['resolveImportMeta']> {
+  const hook = args.plugin.resolveImportMeta
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, { property, ...options }) => {
+      const ret = await handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        property ?? null,
+        options,
+      )
+      return ret ?? undefined
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
 export function bindingifyRenderError(
   args: BindingifyPluginArgs,
 ): PluginHookWithBindingExt<BindingPluginOptions['renderError']> {
@@ -16,6 +16,9 @@ import {
   bindingifyWriteBundle,
   bindingifyRenderError,
   bindingifyAugmentChunkHash,
+  bindingifyRenderDynamicImport,
+  bindingifyResolveFileUrl,
+  bindingifyResolveImportMeta,
   bindingifyBanner,
   bindingifyFooter,
   bindingifyIntro,
@@ -96,6 +99,15 @@ export function bindingifyPlugin(
   const { plugin: augmentChunkHash, meta: augmentChunkHashMeta } =
     bindingifyAugmentChunkHash(args)
 
+  const { plugin: renderDynamicImport, meta: renderDynamicImportMeta } =
+    bindingifyRenderDynamicImport(args)
+
+  const { plugin: resolveFileUrl, meta: resolveFileUrlMeta } =
+    bindingifyResolveFileUrl(args)
+
+  const { plugin: resolveImportMeta, meta: resolveImportMetaMeta } =
+    bindingifyResolveImportMeta(args)
+
   const { plugin: renderStart, meta: renderStartMeta } =
     bindingifyRenderStart(args)
 
@@ -150,6 +162,12 @@ export function bindingifyPlugin(
     renderChunkMeta,
     augmentChunkHash,
     augmentChunkHashMeta,
+    renderDynamicImport,
+    renderDynamicImportMeta,
+    resolveFileUrl,
+    resolveFileUrlMeta,
+    resolveImportMeta,
+    resolveImportMetaMeta,
     renderStart,
     renderStartMeta,
     renderError,
@@ -187,6 +205,9 @@ function wrapHandlers(plugin: BindingPluginOptions): BindingPluginOptions {
     'load',
     'renderChunk',
     'augmentChunkHash',
+    'renderDynamicImport',
+    'resolveFileUrl',
+    'resolveImportMeta',
     'renderStart',
     'renderError',
     'generateBundle',
@@ -1,5 +1,8 @@
 import type {
+  BindingHookRenderDynamicImportArgs,
+  BindingHookResolveFileUrlArgs,
   BindingHookResolveIdExtraArgs,
+  BindingHookResolveImportMetaArgs,
   BindingTransformHookExtraArgs,
 } from '../binding'
 import type { NormalizedInputOptions } from '../options/normalized-input-options'
@@ -36,6 +39,8 @@ export type ModuleType =
   | 'ts'
   | 'tsx'
   | 'json'
+  | 'yaml'
+  | 'toml'
   | 'text'
   | 'base64'
   | 'dataurl'
@@ -86,6 +91,15 @@ export type LoadResult = NullValue | string | SourceDescription
 
 export type TransformResult = NullValue | string | Partial<SourceDescription>
 
+export type RenderDynamicImportOptions = BindingHookRenderDynamicImportArgs
+
+export type ResolveFileUrlOptions = BindingHookResolveFileUrlArgs
+
+export type ResolveImportMetaOptions = Omit<
+  BindingHookResolveImportMetaArgs,
+  'property'
+>
+
 export interface FunctionPluginHooks {
   [DEFINED_HOOK_NAMES.onLog]: (
     this: MinimalPluginContext,
@@ -173,6 +187,22 @@ export interface FunctionPluginHooks {
     chunk: RenderedChunk,
   ) => string | void
 
+  [DEFINED_HOOK_NAMES.renderDynamicImport]: (
+    this: PluginContext,
+    options: RenderDynamicImportOptions,
+  ) => NullValue | { left: string; right: string }
+
+  [DEFINED_HOOK_NAMES.resolveFileUrl]: (
+    this: PluginContext,
+    options: ResolveFileUrlOptions,
+  ) => NullValue | string
+
+  [DEFINED_HOOK_NAMES.resolveImportMeta]: (
+    this: PluginContext,
+    property: string | null,
+    options: ResolveImportMetaOptions,
+  ) => NullValue | string
+
   [DEFINED_HOOK_NAMES.renderError]: (this: PluginContext, error: Error) => void
 
   [DEFINED_HOOK_NAMES.generateBundle]: (
@@ -222,11 +252,11 @@ export type AsyncPluginHooks = Exclude<
 
 export type FirstPluginHooks = DefinedHookNames[
   | 'load'
-  // | 'renderDynamicImport'
+  | 'renderDynamicImport'
   | 'resolveDynamicImport'
-  // | 'resolveFileUrl'
-  | 'resolveId']
-// | 'resolveImportMeta'
+  | 'resolveFileUrl'
+  | 'resolveId'
+  | 'resolveImportMeta']
 // | 'shouldTransformCachedModule'
 
 export type SequentialPluginHooks = DefinedHookNames[
@@ -249,11 +279,11 @@ export type OutputPluginHooks = DefinedHookNames[
   | 'generateBundle'
   | 'outputOptions'
   | 'renderChunk'
-  // | 'renderDynamicImport'
+  | 'renderDynamicImport'
   | 'renderError'
   | 'renderStart'
-  // | 'resolveFileUrl'
-  // | 'resolveImportMeta'
+  | 'resolveFileUrl'
+  | 'resolveImportMeta'
   | 'writeBundle']
 
 export type ParallelPluginHooks = Exclude<
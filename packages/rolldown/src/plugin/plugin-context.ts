@@ -7,7 +7,6 @@ import type {
 } from './index'
 import { MinimalPluginContext } from '../plugin/minimal-plugin-context'
 import { AssetSource, bindingAssetSource } from '../utils/asset-source'
-import { unimplemented, unsupported } from '../utils/misc'
 import { ModuleInfo } from '../types/module-info'
 import { PluginContextData } from './plugin-context-data'
 import { SYMBOL_FOR_RESOLVE_CALLER_THAT_SKIP_SELF } from '../constants/plugin-context'
@@ -25,13 +24,27 @@ export interface EmittedAsset {
   source: AssetSource
 }
 
-export type EmittedFile = EmittedAsset
+export interface EmittedChunk {
+  type: 'chunk'
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+  preserveSignature?: 'strict' | 'allow-extension' | 'exports-only' | false
+}
+
+export type EmittedFile = EmittedAsset | EmittedChunk
 
 export interface PluginContextResolveOptions {
   skipSelf?: boolean
   custom?: CustomPluginOptions
 }
 
+export interface PluginContextParseOptions {
+  typescript?: boolean
+  jsx?: boolean
+}
+
 export interface PrivatePluginContextResolveOptions
   extends PluginContextResolveOptions {
   [SYMBOL_FOR_RESOLVE_CALLER_THAT_SKIP_SELF]?: symbol
@@ -126,11 +139,16 @@ export class PluginContext extends MinimalPluginContext {
     return { ...res, ...info }
   }
 
-  public emitFile(file: EmittedAsset): string {
-    if (file.type !== 'asset') {
-      return unimplemented(
-        'PluginContext.emitFile: only asset type is supported',
-      )
+  public emitFile(file: EmittedFile): string {
+    if (file.type === 'chunk') {
+      return this.context.emitChunk({
+        name: file.name,
+        fileName: file.fileName,
+        id: file.id,
+        importer: file.importer,
+        preserveSignature:
+          file.preserveSignature === false ? 'false' : file.preserveSignature,
+      })
     }
     return this.context.emitFile({
       ...file,
@@ -156,9 +174,10 @@ export class PluginContext extends MinimalPluginContext {
   }
 
   /**
-   * @deprecated This rollup API won't be supported by rolldown. Using this API will cause runtime error.
+   * Parse `input` as an ES module and return the ESTree-compatible `Program` node.
+   * `jsx` is ignored if jsx is disabled by the `jsx` option, the same as for modules.
    */
-  public parse(_input: string, _options?: any): any {
-    unsupported('`PluginContext#parse` is not supported by rolldown.')
+  public parse(input: string, options?: PluginContextParseOptions): any {
+    return JSON.parse(this.context.parse(input, options))
   }
 }
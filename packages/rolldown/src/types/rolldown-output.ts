@@ -64,6 +64,25 @@ export interface OutputChunk {
   preliminaryFileName: string
 }
 
+export interface PluginHookTiming {
+  plugin: string
+  hook: string
+  calls: number
+  /** Wall time spent in the hook in milliseconds. */
+  duration: number
+}
+
+export interface PluginTimings {
+  /** Sorted by `duration` in descending order. */
+  hooks: PluginHookTiming[]
+  /** Plugins that take a large share of the time spent in hooks. */
+  dominantPlugins: string[]
+  /** The timings formatted as a table. */
+  summary: string
+}
+
 export interface RolldownOutput {
   output: [OutputChunk, ...(OutputChunk | OutputAsset)[]]
+  /** Only available if `pluginTimings` is enabled. */
+  pluginTimings?: PluginTimings
 }
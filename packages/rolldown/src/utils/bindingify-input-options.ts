@@ -64,6 +64,7 @@ export function bindingifyInputOptions(
     inject: bindingifyInject(inputOptions.inject),
     experimental: {
       strictExecutionOrder: inputOptions.experimental?.strictExecutionOrder,
+      lazySharedModules: inputOptions.experimental?.lazySharedModules,
       disableLiveBindings: inputOptions.experimental?.disableLiveBindings,
       viteMode: inputOptions.experimental?.viteMode,
       resolveNewUrlToAsset: inputOptions.experimental?.resolveNewUrlToAsset,
@@ -74,6 +75,7 @@ export function bindingifyInputOptions(
     dropLabels: inputOptions.dropLabels,
     keepNames: inputOptions.keepNames,
     checks: inputOptions.checks,
+    pluginTimings: inputOptions.pluginTimings,
   }
 }
 
@@ -58,6 +58,7 @@ export function bindingifyOutputOptions(
     externalLiveBindings: outputOptions.externalLiveBindings,
     inlineDynamicImports: outputOptions.inlineDynamicImports,
     advancedChunks: outputOptions.advancedChunks,
+    legalComments: outputOptions.legalComments,
     polyfillRequire: outputOptions.polyfillRequire,
     target: outputOptions.target,
   }
@@ -26,9 +26,12 @@ const unsupportedHookName = [
   'onLog',
   'options',
   'outputOptions',
+  'renderDynamicImport',
   'renderError',
   'renderStart',
   'resolveDynamicImport',
+  'resolveFileUrl',
+  'resolveImportMeta',
   'writeBundle',
 ] as const
 const unsupportedHooks: Set<string> = new Set(unsupportedHookName)
@@ -124,12 +124,13 @@ export function transformToRollupOutput(
   changed?: ChangedOutputs,
 ): RolldownOutput {
   handleOutputErrors(output)
-  const { chunks, assets } = output
+  const { chunks, assets, pluginTimings } = output
   return {
     output: [
       ...chunks.map((chunk) => transformToRollupOutputChunk(chunk, changed)),
       ...assets.map((asset) => transformToRollupOutputAsset(asset, changed)),
     ],
+    pluginTimings: pluginTimings ?? undefined,
   } as RolldownOutput
 }
 
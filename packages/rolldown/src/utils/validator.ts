@@ -55,8 +55,10 @@ const ModuleTypesSchema = v.record(
     v.literal('json'),
     v.literal('jsx'),
     v.literal('text'),
+    v.literal('toml'),
     v.literal('ts'),
     v.literal('tsx'),
+    v.literal('yaml'),
   ]),
 )
 
@@ -118,13 +120,42 @@ const WatchOptionsSchema = v.strictObject({
   ),
 })
 
+const SizeLimitSchema = v.strictObject({
+  raw: v.optional(v.number()),
+  gzip: v.optional(v.number()),
+  brotli: v.optional(v.number()),
+})
+
+const SizeBudgetsOptionsSchema = v.strictObject({
+  entry: v.optional(SizeLimitSchema),
+  chunks: v.optional(
+    v.array(
+      v.strictObject({
+        pattern: v.string(),
+        limit: SizeLimitSchema,
+      }),
+    ),
+  ),
+  initial: v.optional(SizeLimitSchema),
+  severity: v.optional(v.picklist(['warning', 'error'])),
+})
+
+const CircularDependencyOptionsSchema = v.strictObject({
+  ignore: v.optional(v.array(v.string())),
+  mode: v.optional(v.picklist(['warn', 'errorOnUnsafe', 'error'])),
+})
+
 const ChecksOptionsSchema = v.strictObject({
   circularDependency: v.pipe(
-    v.optional(v.boolean()),
+    v.optional(v.union([v.boolean(), CircularDependencyOptionsSchema])),
     v.description(
       'Wether to emit warnings when detecting circular dependencies',
     ),
   ),
+  sizeBudgets: v.pipe(
+    v.optional(SizeBudgetsOptionsSchema),
+    v.description('Report output files exceeding the given sizes'),
+  ),
 })
 
 const ResolveOptionsSchema = v.strictObject({
@@ -141,6 +172,8 @@ const ResolveOptionsSchema = v.strictObject({
   modules: v.optional(v.array(v.string())),
   symlinks: v.optional(v.boolean()),
   tsconfigFilename: v.optional(v.string()),
+  tsconfigAuto: v.optional(v.boolean()),
+  yarnPnp: v.optional(v.boolean()),
 })
 
 const TreeshakingOptionsSchema = v.union([
@@ -220,6 +253,7 @@ const InputOptionsSchema = v.strictObject({
     v.strictObject({
       disableLiveBindings: v.optional(v.boolean()),
       enableComposingJsPlugins: v.optional(v.boolean()),
+      lazySharedModules: v.optional(v.boolean()),
       resolveNewUrlToAsset: v.optional(v.boolean()),
       strictExecutionOrder: v.optional(v.boolean()),
     }),
@@ -242,6 +276,10 @@ const InputOptionsSchema = v.strictObject({
     v.description('Remove labeled statements with these label names'),
   ),
   checks: v.optional(ChecksOptionsSchema),
+  pluginTimings: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Print the time spent in hooks of each plugin'),
+  ),
 })
 
 const InputCliOverrideSchema = v.strictObject({
@@ -347,6 +385,18 @@ const AdvancedChunksSchema = v.strictObject({
   ),
 })
 
+const MinifyOptionsSchema = v.strictObject({
+  compress: v.optional(v.boolean()),
+  mangle: v.optional(v.boolean()),
+  whitespace: v.optional(v.boolean()),
+  dropConsole: v.optional(v.boolean()),
+  dropDebugger: v.optional(v.boolean()),
+  pureFuncs: v.optional(v.array(v.string())),
+  keepFnames: v.optional(v.boolean()),
+  keepClassnames: v.optional(v.boolean()),
+  target: v.optional(v.enum(ESTarget)),
+})
+
 const OutputOptionsSchema = v.strictObject({
   dir: v.pipe(
     v.optional(v.string()),
@@ -413,7 +463,7 @@ const OutputOptionsSchema = v.strictObject({
   cssEntryFileNames: v.optional(ChunkFileNamesSchema),
   cssChunkFileNames: v.optional(ChunkFileNamesSchema),
   minify: v.pipe(
-    v.optional(v.boolean()),
+    v.optional(v.union([v.boolean(), MinifyOptionsSchema])),
     v.description('Minify the bundled file'),
   ),
   name: v.pipe(
@@ -441,6 +491,18 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.union([v.literal('none'), v.literal('preserve-legal')])),
     v.description('Control comments in the output'),
   ),
+  legalComments: v.pipe(
+    v.optional(
+      v.union([
+        v.literal('none'),
+        v.literal('inline'),
+        v.literal('eof'),
+        v.literal('linked'),
+        v.literal('external'),
+      ]),
+    ),
+    v.description('Control where legal comments go'),
+  ),
   target: v.pipe(
     v.optional(v.enum(ESTarget)),
     v.description('The JavaScript target environment'),
@@ -488,6 +550,7 @@ const OutputCliOverrideSchema = v.strictObject({
     v.optional(v.string()),
     v.description(getAddonDescription('bottom', 'inside')),
   ),
+  minify: v.pipe(v.optional(v.boolean()), v.description('Minify the bundled file')),
   // It is hard to handle the union type in json schema, so use this first.
   esModule: v.pipe(
     v.optional(v.boolean()),
@@ -0,0 +1,45 @@
+import { defineTest } from '@tests'
+import { getOutputFileNames } from '@tests/utils'
+import { expect } from 'vitest'
+import path from 'node:path'
+
+let workerReferenceId: string
+let namedReferenceId: string
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart() {
+          workerReferenceId = this.emitFile({
+            type: 'chunk',
+            id: path.join(__dirname, 'worker.js'),
+            fileName: 'worker.js',
+          })
+        },
+        load(id) {
+          if (id.endsWith('main.js')) {
+            namedReferenceId = this.emitFile({
+              type: 'chunk',
+              id: './worker.js',
+              importer: id,
+              name: 'named-worker',
+            })
+          }
+        },
+        generateBundle() {
+          // Emitting the same module twice creates a single chunk
+          expect(this.getFileName(workerReferenceId)).toBe('worker.js')
+          expect(this.getFileName(namedReferenceId)).toBe('worker.js')
+          expect(() =>
+            this.emitFile({ type: 'chunk', id: './main.js' }),
+          ).toThrow('Cannot emit chunks after module loading has finished.')
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(getOutputFileNames(output)).toStrictEqual(['main.js', 'worker.js'])
+  },
+})
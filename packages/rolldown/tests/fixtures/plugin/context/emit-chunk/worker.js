@@ -0,0 +1 @@
+self.onmessage = () => self.postMessage('worker')
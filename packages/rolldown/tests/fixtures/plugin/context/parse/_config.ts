@@ -0,0 +1,35 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart(this) {
+          const code = `const greeting = '👋 hello'; export { greeting }`
+          const ast = this.parse(code)
+          expect(ast.type).toBe('Program')
+          expect(ast.sourceType).toBe('module')
+          const [declaration, exportNamed] = ast.body
+          expect(declaration.type).toBe('VariableDeclaration')
+          // Positions are indices of the js string, even after non-ascii characters.
+          expect(code.slice(exportNamed.start, exportNamed.end)).toBe(
+            'export { greeting }',
+          )
+
+          const typed = this.parse('let a: number = 1', { typescript: true })
+          expect(typed.body[0].declarations[0].id.name).toBe('a')
+
+          expect(() => this.parse('let a: number = 1')).toThrow()
+          fn()
+        },
+      },
+    ],
+  },
+  afterTest: () => {
+    expect(fn).toHaveBeenCalledTimes(1)
+  },
+})
@@ -0,0 +1,31 @@
+import { defineTest } from '@tests'
+import fs from 'node:fs'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'load-as-js',
+        load(id) {
+          if (id.endsWith('data.json')) {
+            return {
+              code: fs.readFileSync(id, 'utf-8'),
+              moduleType: 'js',
+            }
+          }
+        },
+      },
+    ],
+  },
+  catchError(e: any) {
+    expect(e.errors).toEqual([
+      expect.objectContaining({
+        kind: 'IMPORT_ATTRIBUTE_TYPE_CONFLICT',
+        message: expect.stringContaining(
+          '"data.json" is imported as module type `json` by "main.js", but it is loaded as module type `js`.',
+        ),
+      }),
+    ])
+  },
+})
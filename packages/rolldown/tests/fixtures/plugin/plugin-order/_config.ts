@@ -13,14 +13,14 @@ const hooks = [
   'moduleParsed',
   'options',
   'outputOptions',
-  // 'renderDynamicImport',
+  'renderDynamicImport',
   // 'renderError'
   'renderChunk',
   'renderStart',
   'resolveDynamicImport',
   // 'resolveFileUrl',
   'resolveId',
-  // 'resolveImportMeta',
+  'resolveImportMeta',
   // 'shouldTransformCachedModule',
   'transform',
   'banner',
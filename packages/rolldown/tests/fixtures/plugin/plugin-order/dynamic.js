@@ -1 +1,2 @@
 console.log(1)
+console.log(import.meta.url)
@@ -0,0 +1,44 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+
+export default defineTest({
+  config: {
+    pluginTimings: true,
+    plugins: [
+      {
+        name: 'slow-plugin',
+        async transform(code) {
+          await new Promise((resolve) => setTimeout(resolve, 20))
+          return code
+        },
+      },
+      {
+        name: 'fast-plugin',
+        transform(code) {
+          return code
+        },
+        renderChunk(code) {
+          return code
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const timings = output.pluginTimings!
+    expect(timings.hooks[0]).toMatchObject({
+      plugin: 'slow-plugin',
+      hook: 'transform',
+      calls: 2,
+    })
+    expect(timings.hooks[0].duration).toBeGreaterThanOrEqual(30)
+    expect(timings.hooks).toContainEqual(
+      expect.objectContaining({
+        plugin: 'fast-plugin',
+        hook: 'render_chunk',
+        calls: 1,
+      }),
+    )
+    expect(timings.dominantPlugins).toStrictEqual(['slow-plugin'])
+    expect(timings.summary).toContain('slow-plugin (dominant)')
+  },
+})
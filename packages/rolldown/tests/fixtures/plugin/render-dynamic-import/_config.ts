@@ -0,0 +1,27 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from '@tests'
+
+const renderDynamicImportFn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: path.join(__dirname, './main.js'),
+    plugins: [
+      {
+        name: 'test-plugin',
+        renderDynamicImport: (options) => {
+          renderDynamicImportFn()
+          expect(options.targetModuleId).toBe(path.join(__dirname, './foo.js'))
+          expect(options.chunkId).toBe('main.js')
+          expect(options.targetChunkId).toBe('foo.js')
+          return { left: 'loadChunk(', right: ')' }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(renderDynamicImportFn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain('loadChunk("./foo.js")')
+  },
+})
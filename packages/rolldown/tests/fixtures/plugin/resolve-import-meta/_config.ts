@@ -0,0 +1,30 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from '@tests'
+
+const entry = path.join(__dirname, './main.js')
+
+const resolveImportMetaFn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: entry,
+    plugins: [
+      {
+        name: 'test-plugin',
+        resolveImportMeta: (property, options) => {
+          resolveImportMetaFn()
+          expect(property).toBe('env')
+          expect(options.moduleId).toBe(entry)
+          expect(options.chunkId).toBe('main.js')
+          expect(options.format).toBe('es')
+          return '{ MODE: "production" }'
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(resolveImportMetaFn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain('{ MODE: "production" }.MODE')
+  },
+})